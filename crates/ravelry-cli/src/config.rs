@@ -3,6 +3,7 @@
 //! Stores authentication profiles in `~/.config/ravelry/config.toml`.
 
 use directories::ProjectDirs;
+use ravelry::auth::{BoxFuture, TokenStore};
 use ravelry::{OAuth2Token, RavelryError};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -138,6 +139,52 @@ impl Config {
     }
 }
 
+/// A [`TokenStore`] that keeps an OAuth2 profile's token in the config file.
+pub struct ProfileTokenStore {
+    profile: String,
+    client_id: String,
+    client_secret: String,
+}
+
+impl ProfileTokenStore {
+    /// Create a store for the named OAuth2 profile and its client credentials.
+    pub fn new(
+        profile: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            profile: profile.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+        }
+    }
+}
+
+impl TokenStore for ProfileTokenStore {
+    fn load(&self) -> BoxFuture<'_, Option<OAuth2Token>> {
+        Box::pin(async move {
+            match Config::load().ok()?.get_profile(&self.profile)? {
+                Profile::OAuth2 { token, .. } => Some(token.clone()),
+                Profile::Basic { .. } => None,
+            }
+        })
+    }
+
+    fn save<'a>(&'a self, token: &'a OAuth2Token) -> BoxFuture<'a, Result<(), RavelryError>> {
+        Box::pin(async move {
+            // Reload so changes made since the client was built aren't lost.
+            let mut config = Config::load().map_err(std::io::Error::other)?;
+            config.set_profile(
+                &self.profile,
+                Profile::oauth2(&self.client_id, &self.client_secret, token.clone()),
+            );
+            config.save().map_err(std::io::Error::other)?;
+            Ok(())
+        })
+    }
+}
+
 /// Errors that can occur during config operations.
 #[derive(Debug)]
 pub enum ConfigError {
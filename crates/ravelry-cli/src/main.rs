@@ -1,9 +1,10 @@
 //! Ravelry CLI - Command-line interface for the Ravelry API.
 
 mod config;
+mod tabular;
 
-use clap::{Parser, Subcommand};
-use config::{Config, ConfigError, Profile};
+use clap::{CommandFactory, Parser, Subcommand};
+use config::{Config, ConfigError, Profile, ProfileTokenStore};
 use ravelry::{
     api::{
         bundles::BundlesListParams,
@@ -15,12 +16,14 @@ use ravelry::{
         stash::StashListParams,
         yarns::YarnSearchParams,
     },
-    auth::{BasicAuth, OAuth2Auth},
+    auth::{BasicAuth, CallbackParams, RefreshingOAuth2Auth},
     pagination::collect_all_pages,
     types::{BookmarkPost, BundlePost, MessagePost, ProjectPost, StashPost, UploadFile},
     RavelryClient, RavelryError, RavelryOAuth2Client,
 };
+use std::io::{IsTerminal, Write};
 use std::time::Duration;
+use tabular::{render_table, write_csv, TableRow};
 
 #[derive(Parser)]
 #[command(name = "ravelry")]
@@ -39,17 +42,47 @@ struct Cli {
     profile: Option<String>,
 
     /// Output as JSON
-    #[arg(long, global = true, conflicts_with = "json_pretty")]
+    #[arg(long, global = true, conflicts_with_all = ["json_pretty", "csv"])]
     json: bool,
 
     /// Output as pretty-printed JSON
-    #[arg(long, global = true, conflicts_with = "json")]
+    #[arg(long, global = true, conflicts_with_all = ["json", "csv"])]
     json_pretty: bool,
 
+    /// Output list commands as CSV
+    #[arg(long, global = true, conflicts_with_all = ["json", "json_pretty"])]
+    csv: bool,
+
+    /// Output list commands as aligned tables (ignored with --json)
+    #[arg(long, global = true, conflicts_with = "csv")]
+    table: bool,
+
+    /// Write JSON, CSV, or table output to this file instead of stdout
+    /// (requires one of those formats)
+    #[arg(long, short = 'o', global = true, value_name = "PATH")]
+    output: Option<std::path::PathBuf>,
+
     /// Enable debug mode (adds debug info to API responses)
     #[arg(long, global = true)]
     debug: bool,
 
+    /// Don't ask for confirmation before destructive commands
+    #[arg(long, short = 'y', global = true)]
+    yes: bool,
+
+    /// Print the API calls mutating commands would make instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Refresh OAuth2 tokens this many seconds before they expire
+    #[arg(
+        long,
+        env = "RAVELRY_REFRESH_SKEW",
+        global = true,
+        default_value_t = 300
+    )]
+    refresh_skew: u64,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -98,6 +131,55 @@ enum Commands {
     /// Friend commands
     #[command(subcommand)]
     Friends(FriendCommands),
+
+    /// Print a shell completion script to stdout
+    #[command(hide = true)]
+    Completions {
+        /// Shell to generate completions for
+        shell: clap_complete::Shell,
+    },
+}
+
+impl Commands {
+    /// Returns `true` if this command can print rows with `--csv` or `--table`.
+    fn supports_table(&self) -> bool {
+        matches!(
+            self,
+            Commands::Patterns(PatternCommands::Search { .. } | PatternCommands::Projects { .. })
+                | Commands::Yarns(YarnCommands::Search { .. })
+                | Commands::Projects(ProjectCommands::List { .. })
+                | Commands::Stash(StashCommands::List { .. })
+                | Commands::Messages(MessageCommands::List { .. })
+                | Commands::Favorites(FavoriteCommands::List { .. })
+        )
+    }
+
+    /// Returns `true` if this command prints its result with `--json`.
+    ///
+    /// Commands that only report success in plain text have nothing to
+    /// write to an `--output` file.
+    fn supports_json(&self) -> bool {
+        !matches!(
+            self,
+            Commands::Auth(
+                AuthCommands::Login { .. }
+                    | AuthCommands::Basic { .. }
+                    | AuthCommands::Profiles
+                    | AuthCommands::Use { .. }
+                    | AuthCommands::Refresh { .. }
+                    | AuthCommands::Delete { .. }
+            ) | Commands::Messages(
+                MessageCommands::MarkRead { .. }
+                    | MessageCommands::MarkUnread { .. }
+                    | MessageCommands::Archive { .. }
+                    | MessageCommands::Unarchive { .. }
+                    | MessageCommands::Delete { .. }
+            ) | Commands::Favorites(FavoriteCommands::Delete { .. })
+                | Commands::Bundles(BundleCommands::Delete { .. })
+                | Commands::Friends(FriendCommands::Remove { .. })
+                | Commands::Completions { .. }
+        )
+    }
 }
 
 #[derive(Subcommand)]
@@ -238,6 +320,12 @@ enum YarnCommands {
         /// Yarn ID
         id: u64,
     },
+
+    /// List shops that carry a yarn
+    Shops {
+        /// Yarn ID
+        id: u64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -289,7 +377,7 @@ enum ProjectCommands {
         #[arg(long)]
         status_id: Option<u64>,
 
-        /// Craft ID (1=knitting, 2=crochet, etc.)
+        /// Craft ID (1=crochet, 2=knitting, 3=machine knitting, 4=loom knitting, 5=weaving, 6=spinning)
         #[arg(long)]
         craft_id: Option<u64>,
     },
@@ -319,6 +407,16 @@ enum ProjectCommands {
         #[arg(long)]
         notes: Option<String>,
     },
+
+    /// Delete a project
+    Delete {
+        /// Username (uses current user if not specified)
+        #[arg(long)]
+        user: Option<String>,
+
+        /// Project ID
+        id: u64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -396,6 +494,16 @@ enum StashCommands {
         #[arg(long)]
         notes: Option<String>,
     },
+
+    /// Delete a stash entry
+    Delete {
+        /// Username (uses current user if not specified)
+        #[arg(long)]
+        user: Option<String>,
+
+        /// Stash ID
+        id: u64,
+    },
 }
 
 #[derive(Subcommand)]
@@ -651,6 +759,35 @@ enum FriendCommands {
 }
 
 impl Cli {
+    /// Returns `true` if a mutating API call should be sent.
+    ///
+    /// Under `--dry-run`, prints the call instead and returns `false`.
+    fn should_send(&self, method: &str, path: &str) -> bool {
+        if self.dry_run {
+            println!("Dry run: {method} {path}");
+            return false;
+        }
+        true
+    }
+
+    /// Like [`should_send`](Self::should_send), but also asks `prompt`
+    /// unless `--yes` was given.
+    fn should_send_destructive(
+        &self,
+        method: &str,
+        path: &str,
+        prompt: &str,
+    ) -> Result<bool, CliError> {
+        if !self.should_send(method, path) {
+            return Ok(false);
+        }
+        if !self.yes && !confirm(prompt)? {
+            println!("Aborted.");
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
     /// Build a Ravelry client from CLI arguments or config.
     async fn build_client(&self) -> Result<RavelryClient, CliError> {
         // First, try CLI args (backward compatible)
@@ -668,8 +805,8 @@ impl Cli {
             .as_deref()
             .or(config.current_profile.as_deref());
 
-        let profile = profile_name
-            .and_then(|name| config.get_profile(name))
+        let (profile_name, profile) = profile_name
+            .and_then(|name| Some((name, config.get_profile(name)?)))
             .ok_or(CliError::MissingCredentials(
                 "No credentials. Use --access-key/--personal-key, --profile, or run 'ravelry auth basic'",
             ))?;
@@ -687,35 +824,27 @@ impl Cli {
                 client_secret,
                 token,
             } => {
-                // Check if token needs refresh
-                let mut token = token.clone();
-                if token.is_expired(Duration::from_secs(300)) {
-                    if let Some(refresh_token) = &token.refresh_token {
-                        eprintln!("Token expired, refreshing...");
-                        let oauth_client = RavelryOAuth2Client::new(
-                            client_id,
-                            client_secret,
-                            "https://localhost:8080/callback",
-                        )?;
-                        token = oauth_client.refresh(refresh_token).await?;
-
-                        // Save updated token
-                        let mut config = Config::load()?;
-                        if let Some(name) = profile_name {
-                            config.set_profile(
-                                name,
-                                Profile::oauth2(client_id, client_secret, token.clone()),
-                            );
-                            config.save()?;
-                        }
-                    } else {
-                        return Err(CliError::MissingCredentials(
-                            "OAuth2 token expired and no refresh token available. Please re-login.",
-                        ));
-                    }
+                let skew = Duration::from_secs(self.refresh_skew);
+                if token.is_expired(skew) && token.refresh_token.is_none() {
+                    return Err(CliError::MissingCredentials(
+                        "OAuth2 token expired and no refresh token available. Please re-login.",
+                    ));
                 }
 
-                let auth = OAuth2Auth::new(&token.access_token);
+                // Refreshed tokens are saved back to the profile.
+                let oauth_client = RavelryOAuth2Client::new(
+                    client_id,
+                    client_secret,
+                    "https://localhost:8080/callback",
+                )?;
+                let auth = RefreshingOAuth2Auth::new(oauth_client, token.clone())
+                    .refresh_skew(skew)
+                    .on_refresh(|_| eprintln!("Token expired, refreshed."))
+                    .token_store(ProfileTokenStore::new(
+                        profile_name,
+                        client_id,
+                        client_secret,
+                    ));
                 Ok(RavelryClient::builder(auth).debug(self.debug).build()?)
             }
         }
@@ -733,9 +862,73 @@ impl Cli {
         } else {
             serde_json::to_string(value)?
         };
-        println!("{output}");
+        self.write_output(format!("{output}\n").as_bytes())
+    }
+
+    /// Check if table output is requested (JSON output takes precedence).
+    fn table_output(&self) -> bool {
+        self.table && !self.json_output()
+    }
+
+    /// Print list items as an aligned table.
+    fn print_table<T: TableRow>(&self, rows: &[T]) -> Result<(), CliError> {
+        self.write_output(format!("{}\n", render_table(rows)).as_bytes())
+    }
+
+    /// Print list items as CSV with a header row.
+    fn print_csv<T: TableRow>(&self, rows: &[T]) -> Result<(), CliError> {
+        let mut output = Vec::new();
+        write_csv(&mut output, rows)?;
+        self.write_output(&output)
+    }
+
+    /// Reject `--output` without a format that honours it.
+    ///
+    /// Plain human-readable output always goes to stdout, so `--output` on
+    /// its own would silently write nothing.
+    fn check_output_format(&self) -> Result<(), CliError> {
+        if self.output.is_some() && !(self.json_output() || self.csv || self.table) {
+            return Err(CliError::Other(
+                "--output requires --json, --json-pretty, --csv, or --table".to_string(),
+            ));
+        }
+        if (self.csv || self.table_output()) && !self.command.supports_table() {
+            return Err(CliError::Other(
+                "--csv and --table are only supported by list and search commands".to_string(),
+            ));
+        }
+        if self.output.is_some() && self.json_output() && !self.command.supports_json() {
+            return Err(CliError::Other(
+                "--output is not supported by this command: it prints no JSON".to_string(),
+            ));
+        }
         Ok(())
     }
+
+    /// Write rendered output to stdout, or to the `--output` file.
+    ///
+    /// The file is written to a temporary sibling and renamed into place, so
+    /// readers never see a partial file. Missing parent directories are created.
+    fn write_output(&self, content: &[u8]) -> Result<(), CliError> {
+        let Some(path) = &self.output else {
+            let mut stdout = std::io::stdout().lock();
+            stdout.write_all(content)?;
+            stdout.flush()?;
+            return Ok(());
+        };
+
+        let write = || -> std::io::Result<()> {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut tmp = path.clone().into_os_string();
+            tmp.push(".tmp");
+            std::fs::write(&tmp, content)?;
+            std::fs::rename(&tmp, path)
+        };
+        write()
+            .map_err(|e| CliError::Other(format!("Cannot write output to {}: {e}", path.display())))
+    }
 }
 
 #[derive(Debug)]
@@ -743,6 +936,7 @@ enum CliError {
     MissingCredentials(&'static str),
     Api(RavelryError),
     Json(serde_json::Error),
+    Csv(csv::Error),
     Config(ConfigError),
     Io(std::io::Error),
     Other(String),
@@ -752,8 +946,19 @@ impl std::fmt::Display for CliError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             CliError::MissingCredentials(msg) => write!(f, "{msg}"),
+            CliError::Api(RavelryError::MissingScope { required }) => write!(
+                f,
+                "API error: the current token lacks the '{required}' scope.\n\
+                 Run 'ravelry auth login --scopes \"offline {required}\"' to grant it."
+            ),
+            CliError::Api(RavelryError::Unauthorized { .. }) => write!(
+                f,
+                "API error: the API rejected your credentials.\n\
+                 Check the active profile or run 'ravelry auth login' to sign in again."
+            ),
             CliError::Api(e) => write!(f, "API error: {e}"),
             CliError::Json(e) => write!(f, "JSON error: {e}"),
+            CliError::Csv(e) => write!(f, "CSV error: {e}"),
             CliError::Config(e) => write!(f, "Config error: {e}"),
             CliError::Io(e) => write!(f, "IO error: {e}"),
             CliError::Other(msg) => write!(f, "{msg}"),
@@ -775,6 +980,12 @@ impl From<serde_json::Error> for CliError {
     }
 }
 
+impl From<csv::Error> for CliError {
+    fn from(e: csv::Error) -> Self {
+        CliError::Csv(e)
+    }
+}
+
 impl From<ConfigError> for CliError {
     fn from(e: ConfigError) -> Self {
         CliError::Config(e)
@@ -798,6 +1009,11 @@ async fn main() {
 }
 
 async fn run(cli: Cli) -> Result<(), CliError> {
+    if cli.table && cli.json_output() {
+        eprintln!("Warning: --table is ignored when JSON output is requested");
+    }
+    cli.check_output_format()?;
+
     match &cli.command {
         Commands::Whoami => {
             let client = cli.build_client().await?;
@@ -827,6 +1043,12 @@ async fn run(cli: Cli) -> Result<(), CliError> {
         Commands::Favorites(fav_cmd) => run_favorites_command(&cli, fav_cmd).await?,
         Commands::Bundles(bundle_cmd) => run_bundles_command(&cli, bundle_cmd).await?,
         Commands::Friends(friend_cmd) => run_friends_command(&cli, friend_cmd).await?,
+
+        Commands::Completions { shell } => {
+            let mut command = Cli::command();
+            let name = command.get_name().to_string();
+            clap_complete::generate(*shell, &mut command, name, &mut std::io::stdout());
+        }
     }
 
     Ok(())
@@ -849,7 +1071,10 @@ async fn run_auth_command(cli: &Cli, cmd: &AuthCommands) -> Result<(), CliError>
 
             // Generate authorization URL
             let scope_list: Vec<String> = scopes.split_whitespace().map(String::from).collect();
-            let (auth_url, _csrf_state) = oauth_client.authorize_url(scope_list);
+            if scope_list.is_empty() {
+                eprintln!("Warning: no scopes requested; the token will only read public data.");
+            }
+            let (auth_url, csrf_state) = oauth_client.authorize_url(scope_list);
 
             println!("Opening browser for authorization...");
             println!("If browser doesn't open, visit: {auth_url}");
@@ -862,12 +1087,13 @@ async fn run_auth_command(cli: &Cli, cmd: &AuthCommands) -> Result<(), CliError>
             // Start callback server
             println!("\nWaiting for callback on https://localhost:8080/callback ...");
 
-            let code = wait_for_oauth_callback().await?;
+            let callback = wait_for_oauth_callback().await?;
+            let code = callback.verify(&csrf_state)?;
 
             println!("Received authorization code, exchanging for tokens...");
 
             // Exchange code for tokens
-            let token = oauth_client.exchange_code(&code).await?;
+            let token = oauth_client.exchange_code(code).await?;
 
             // Save to config
             let mut config = Config::load()?;
@@ -1059,8 +1285,12 @@ async fn run_pattern_command(cli: &Cli, cmd: &PatternCommands) -> Result<(), Cli
                 })
                 .await?;
 
-                if cli.json_output() {
+                if cli.csv {
+                    cli.print_csv(&all_patterns)?;
+                } else if cli.json_output() {
                     cli.print_json(&all_patterns)?;
+                } else if cli.table_output() {
+                    cli.print_table(&all_patterns)?;
                 } else {
                     println!("Found {} patterns total", all_patterns.len());
                     for pattern in &all_patterns {
@@ -1088,8 +1318,12 @@ async fn run_pattern_command(cli: &Cli, cmd: &PatternCommands) -> Result<(), Cli
 
                 let response = client.patterns().search(&params).await?;
 
-                if cli.json_output() {
+                if cli.csv {
+                    cli.print_csv(&response.patterns)?;
+                } else if cli.json_output() {
                     cli.print_json(&response)?;
+                } else if cli.table_output() {
+                    cli.print_table(&response.patterns)?;
                 } else {
                     println!(
                         "Found {} patterns (page {}/{})",
@@ -1160,8 +1394,12 @@ async fn run_pattern_command(cli: &Cli, cmd: &PatternCommands) -> Result<(), Cli
                 })
                 .await?;
 
-                if cli.json_output() {
+                if cli.csv {
+                    cli.print_csv(&all_projects)?;
+                } else if cli.json_output() {
                     cli.print_json(&all_projects)?;
+                } else if cli.table_output() {
+                    cli.print_table(&all_projects)?;
                 } else {
                     println!("Found {} projects total", all_projects.len());
                     for project in &all_projects {
@@ -1175,8 +1413,12 @@ async fn run_pattern_command(cli: &Cli, cmd: &PatternCommands) -> Result<(), Cli
                     .page_size(*page_size);
                 let response = client.patterns().projects(*id, &params).await?;
 
-                if cli.json_output() {
+                if cli.csv {
+                    cli.print_csv(&response.projects)?;
+                } else if cli.json_output() {
                     cli.print_json(&response)?;
+                } else if cli.table_output() {
+                    cli.print_table(&response.projects)?;
                 } else {
                     println!(
                         "Projects for pattern {} (page {}/{})",
@@ -1224,8 +1466,12 @@ async fn run_yarn_command(cli: &Cli, cmd: &YarnCommands) -> Result<(), CliError>
                 })
                 .await?;
 
-                if cli.json_output() {
+                if cli.csv {
+                    cli.print_csv(&all_yarns)?;
+                } else if cli.json_output() {
                     cli.print_json(&all_yarns)?;
+                } else if cli.table_output() {
+                    cli.print_table(&all_yarns)?;
                 } else {
                     println!("Found {} yarns total", all_yarns.len());
                     for yarn in &all_yarns {
@@ -1242,8 +1488,12 @@ async fn run_yarn_command(cli: &Cli, cmd: &YarnCommands) -> Result<(), CliError>
 
                 let response = client.yarns().search(&params).await?;
 
-                if cli.json_output() {
+                if cli.csv {
+                    cli.print_csv(&response.yarns)?;
+                } else if cli.json_output() {
                     cli.print_json(&response)?;
+                } else if cli.table_output() {
+                    cli.print_table(&response.yarns)?;
                 } else {
                     println!(
                         "Found {} yarns (page {}/{})",
@@ -1281,6 +1531,28 @@ async fn run_yarn_command(cli: &Cli, cmd: &YarnCommands) -> Result<(), CliError>
                 }
             }
         }
+
+        YarnCommands::Shops { id } => {
+            let shops = client.yarns().shops(*id).await?;
+
+            if cli.json_output() {
+                cli.print_json(&shops)?;
+            } else if shops.is_empty() {
+                println!("No shops list yarn {id}");
+            } else {
+                println!("Shops carrying yarn {id}:");
+                for shop in &shops {
+                    let name = shop.name.as_deref().unwrap_or("Unknown");
+                    match &shop.location {
+                        Some(location) => println!("  {name} ({location})"),
+                        None => println!("  {name}"),
+                    }
+                    if let Some(url) = &shop.url {
+                        println!("    {url}");
+                    }
+                }
+            }
+        }
     }
 
     Ok(())
@@ -1291,13 +1563,7 @@ async fn resolve_username(
     client: &RavelryClient,
     user: &Option<String>,
 ) -> Result<String, CliError> {
-    match user {
-        Some(u) => Ok(u.clone()),
-        None => {
-            let response = client.root().current_user().await?;
-            Ok(response.user.username)
-        }
-    }
+    Ok(client.root().resolve_username(user.as_deref()).await?)
 }
 
 async fn run_project_command(cli: &Cli, cmd: &ProjectCommands) -> Result<(), CliError> {
@@ -1327,8 +1593,12 @@ async fn run_project_command(cli: &Cli, cmd: &ProjectCommands) -> Result<(), Cli
                 })
                 .await?;
 
-                if cli.json_output() {
+                if cli.csv {
+                    cli.print_csv(&all_projects)?;
+                } else if cli.json_output() {
                     cli.print_json(&all_projects)?;
+                } else if cli.table_output() {
+                    cli.print_table(&all_projects)?;
                 } else {
                     println!("Found {} projects total", all_projects.len());
                     for project in &all_projects {
@@ -1340,8 +1610,12 @@ async fn run_project_command(cli: &Cli, cmd: &ProjectCommands) -> Result<(), Cli
                 let params = ProjectsListParams::new().page(*page).page_size(*page_size);
                 let response = client.projects().list(&username, &params).await?;
 
-                if cli.json_output() {
+                if cli.csv {
+                    cli.print_csv(&response.projects)?;
+                } else if cli.json_output() {
                     cli.print_json(&response)?;
+                } else if cli.table_output() {
+                    cli.print_table(&response.projects)?;
                 } else {
                     println!(
                         "Found {} projects (page {}/{})",
@@ -1404,6 +1678,9 @@ async fn run_project_command(cli: &Cli, cmd: &ProjectCommands) -> Result<(), Cli
                 post.craft_id = Some(*cid);
             }
 
+            if !cli.should_send("POST", &format!("/projects/{username}/create.json")) {
+                return Ok(());
+            }
             let response = client.projects().create(&username, &post).await?;
 
             if cli.json_output() {
@@ -1441,6 +1718,9 @@ async fn run_project_command(cli: &Cli, cmd: &ProjectCommands) -> Result<(), Cli
                 post.notes = Some(n.clone());
             }
 
+            if !cli.should_send("POST", &format!("/projects/{username}/{id}.json")) {
+                return Ok(());
+            }
             let response = client.projects().update(&username, *id, &post).await?;
 
             if cli.json_output() {
@@ -1456,11 +1736,49 @@ async fn run_project_command(cli: &Cli, cmd: &ProjectCommands) -> Result<(), Cli
                 }
             }
         }
+
+        ProjectCommands::Delete { user, id } => {
+            let username = resolve_username(&client, user).await?;
+
+            if !cli.should_send_destructive(
+                "DELETE",
+                &format!("/projects/{username}/{id}.json"),
+                &format!("Delete project {id} for {username}?"),
+            )? {
+                return Ok(());
+            }
+
+            let response = client.projects().delete(&username, *id).await?;
+
+            if cli.json_output() {
+                cli.print_json(&response)?;
+            } else {
+                let project = &response.project;
+                println!("Deleted project: {} (ID: {})", project.name, project.id);
+            }
+        }
     }
 
     Ok(())
 }
 
+/// Ask a yes/no question on stdin, defaulting to no.
+///
+/// Fails when stdin isn't a terminal, so scripts must pass `--yes` explicitly.
+fn confirm(prompt: &str) -> Result<bool, CliError> {
+    if !std::io::stdin().is_terminal() {
+        return Err(CliError::Other(
+            "Refusing to continue without confirmation; pass --yes to skip the prompt".to_string(),
+        ));
+    }
+
+    print!("{prompt} [y/N] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
 async fn run_stash_command(cli: &Cli, cmd: &StashCommands) -> Result<(), CliError> {
     let client = cli.build_client().await?;
 
@@ -1494,8 +1812,12 @@ async fn run_stash_command(cli: &Cli, cmd: &StashCommands) -> Result<(), CliErro
                         all_stash.extend(resp.stash);
                     }
 
-                    if cli.json_output() {
+                    if cli.csv {
+                        cli.print_csv(&all_stash)?;
+                    } else if cli.json_output() {
                         cli.print_json(&all_stash)?;
+                    } else if cli.table_output() {
+                        cli.print_table(&all_stash)?;
                     } else {
                         println!("Found {} stash entries total", all_stash.len());
                         for entry in &all_stash {
@@ -1510,8 +1832,12 @@ async fn run_stash_command(cli: &Cli, cmd: &StashCommands) -> Result<(), CliErro
                     }
                 } else {
                     // No paginator, just return what we have
-                    if cli.json_output() {
+                    if cli.csv {
+                        cli.print_csv(&first_response.stash)?;
+                    } else if cli.json_output() {
                         cli.print_json(&first_response.stash)?;
+                    } else if cli.table_output() {
+                        cli.print_table(&first_response.stash)?;
                     } else {
                         println!("Stash entries (pagination not available, showing first page):");
                         for entry in &first_response.stash {
@@ -1529,8 +1855,12 @@ async fn run_stash_command(cli: &Cli, cmd: &StashCommands) -> Result<(), CliErro
                 let params = StashListParams::new().page(*page).page_size(*page_size);
                 let response = client.stash().list(&username, &params).await?;
 
-                if cli.json_output() {
+                if cli.csv {
+                    cli.print_csv(&response.stash)?;
+                } else if cli.json_output() {
                     cli.print_json(&response)?;
+                } else if cli.table_output() {
+                    cli.print_table(&response.stash)?;
                 } else {
                     if let Some(paginator) = &response.paginator {
                         println!(
@@ -1594,9 +1924,12 @@ async fn run_stash_command(cli: &Cli, cmd: &StashCommands) -> Result<(), CliErro
                 post = post.skeins(*s);
             }
             if let Some(n) = notes {
-                post.notes = Some(n.clone());
+                post = post.notes(n);
             }
 
+            if !cli.should_send("POST", &format!("/people/{username}/stash/create.json")) {
+                return Ok(());
+            }
             let response = client.stash().create(&username, &post).await?;
 
             if cli.json_output() {
@@ -1630,9 +1963,12 @@ async fn run_stash_command(cli: &Cli, cmd: &StashCommands) -> Result<(), CliErro
                 post = post.skeins(*s);
             }
             if let Some(n) = notes {
-                post.notes = Some(n.clone());
+                post = post.notes(n);
             }
 
+            if !cli.should_send("POST", &format!("/people/{username}/stash/{id}.json")) {
+                return Ok(());
+            }
             let response = client.stash().update(&username, *id, &post).await?;
 
             if cli.json_output() {
@@ -1651,6 +1987,26 @@ async fn run_stash_command(cli: &Cli, cmd: &StashCommands) -> Result<(), CliErro
                 }
             }
         }
+
+        StashCommands::Delete { user, id } => {
+            let username = resolve_username(&client, user).await?;
+
+            if !cli.should_send_destructive(
+                "DELETE",
+                &format!("/people/{username}/stash/{id}.json"),
+                &format!("Delete stash entry {id} for {username}?"),
+            )? {
+                return Ok(());
+            }
+
+            let response = client.stash().delete(&username, *id).await?;
+
+            if cli.json_output() {
+                cli.print_json(&response)?;
+            } else {
+                println!("Deleted stash entry: {id}");
+            }
+        }
     }
 
     Ok(())
@@ -1684,8 +2040,12 @@ async fn run_message_command(cli: &Cli, cmd: &MessageCommands) -> Result<(), Cli
 
             let response = client.messages().list(&params).await?;
 
-            if cli.json_output() {
+            if cli.csv {
+                cli.print_csv(&response.messages)?;
+            } else if cli.json_output() {
                 cli.print_json(&response)?;
+            } else if cli.table_output() {
+                cli.print_table(&response.messages)?;
             } else {
                 println!(
                     "Messages in {} (page {}/{})",
@@ -1734,21 +2094,37 @@ async fn run_message_command(cli: &Cli, cmd: &MessageCommands) -> Result<(), Cli
         }
 
         MessageCommands::MarkRead { id } => {
+            if !cli.should_send("POST", &format!("/messages/{id}/mark_read.json")) {
+                return Ok(());
+            }
             client.messages().mark_read(*id).await?;
             println!("Message {} marked as read.", id);
         }
 
         MessageCommands::MarkUnread { id } => {
+            if !cli.should_send("POST", &format!("/messages/{id}/mark_unread.json")) {
+                return Ok(());
+            }
             client.messages().mark_unread(*id).await?;
             println!("Message {} marked as unread.", id);
         }
 
         MessageCommands::Archive { id } => {
+            if !cli.should_send("POST", &format!("/messages/{id}/archive.json")) {
+                return Ok(());
+            }
             client.messages().archive(*id).await?;
             println!("Message {} archived.", id);
         }
 
         MessageCommands::Delete { id } => {
+            if !cli.should_send_destructive(
+                "DELETE",
+                &format!("/messages/{id}.json"),
+                &format!("Delete message {id}?"),
+            )? {
+                return Ok(());
+            }
             client.messages().delete(*id).await?;
             println!("Message {} deleted.", id);
         }
@@ -1763,6 +2139,9 @@ async fn run_message_command(cli: &Cli, cmd: &MessageCommands) -> Result<(), Cli
                 .subject(subject)
                 .content(content);
 
+            if !cli.should_send("POST", "/messages/create.json") {
+                return Ok(());
+            }
             let response = client.messages().create(&message).await?;
 
             if cli.json_output() {
@@ -1774,6 +2153,9 @@ async fn run_message_command(cli: &Cli, cmd: &MessageCommands) -> Result<(), Cli
 
         MessageCommands::Reply { id, content } => {
             let reply = MessagePost::new().content(content);
+            if !cli.should_send("POST", &format!("/messages/{id}/reply.json")) {
+                return Ok(());
+            }
             let response = client.messages().reply(*id, &reply).await?;
 
             if cli.json_output() {
@@ -1784,6 +2166,9 @@ async fn run_message_command(cli: &Cli, cmd: &MessageCommands) -> Result<(), Cli
         }
 
         MessageCommands::Unarchive { id } => {
+            if !cli.should_send("POST", &format!("/messages/{id}/unarchive.json")) {
+                return Ok(());
+            }
             client.messages().unarchive(*id).await?;
             println!("Message {} unarchived.", id);
         }
@@ -1797,6 +2182,11 @@ async fn run_upload_command(cli: &Cli, cmd: &UploadCommands) -> Result<(), CliEr
 
     match cmd {
         UploadCommands::Image { files } => {
+            if !cli.should_send("POST", "/upload/request_token.json") {
+                println!("Dry run: POST /upload/image.json ({} file(s))", files.len());
+                return Ok(());
+            }
+
             // Request upload token
             let token_resp = client.upload().request_token().await?;
             println!("Got upload token, uploading {} file(s)...", files.len());
@@ -1856,8 +2246,12 @@ async fn run_favorites_command(cli: &Cli, cmd: &FavoriteCommands) -> Result<(),
 
             let response = client.favorites().list(&username, &params).await?;
 
-            if cli.json_output() {
+            if cli.csv {
+                cli.print_csv(&response.favorites)?;
+            } else if cli.json_output() {
                 cli.print_json(&response)?;
+            } else if cli.table_output() {
+                cli.print_table(&response.favorites)?;
             } else {
                 if let Some(paginator) = &response.paginator {
                     println!(
@@ -1918,6 +2312,9 @@ async fn run_favorites_command(cli: &Cli, cmd: &FavoriteCommands) -> Result<(),
                 post = post.comment(c);
             }
 
+            if !cli.should_send("POST", &format!("/people/{username}/favorites/create.json")) {
+                return Ok(());
+            }
             let response = client.favorites().create(&username, &post).await?;
 
             if cli.json_output() {
@@ -1929,6 +2326,13 @@ async fn run_favorites_command(cli: &Cli, cmd: &FavoriteCommands) -> Result<(),
 
         FavoriteCommands::Delete { user, id } => {
             let username = resolve_username(&client, user).await?;
+            if !cli.should_send_destructive(
+                "DELETE",
+                &format!("/people/{username}/favorites/{id}.json"),
+                &format!("Delete favorite {id} for {username}?"),
+            )? {
+                return Ok(());
+            }
             client.favorites().delete(&username, *id).await?;
             println!("Deleted favorite: {}", id);
         }
@@ -1994,6 +2398,9 @@ async fn run_bundles_command(cli: &Cli, cmd: &BundleCommands) -> Result<(), CliE
             let username = resolve_username(&client, user).await?;
 
             let post = BundlePost::new().name(name).is_public(*public);
+            if !cli.should_send("POST", &format!("/people/{username}/bundles/create.json")) {
+                return Ok(());
+            }
             let response = client.bundles().create(&username, &post).await?;
 
             if cli.json_output() {
@@ -2005,6 +2412,13 @@ async fn run_bundles_command(cli: &Cli, cmd: &BundleCommands) -> Result<(), CliE
 
         BundleCommands::Delete { user, id } => {
             let username = resolve_username(&client, user).await?;
+            if !cli.should_send_destructive(
+                "DELETE",
+                &format!("/people/{username}/bundles/{id}.json"),
+                &format!("Delete bundle {id} for {username}?"),
+            )? {
+                return Ok(());
+            }
             client.bundles().delete(&username, *id).await?;
             println!("Deleted bundle: {}", id);
         }
@@ -2073,6 +2487,9 @@ async fn run_friends_command(cli: &Cli, cmd: &FriendCommands) -> Result<(), CliE
             friend_user_id,
         } => {
             let username = resolve_username(&client, user).await?;
+            if !cli.should_send("POST", &format!("/people/{username}/friends/create.json")) {
+                return Ok(());
+            }
             let response = client.friends().create(&username, *friend_user_id).await?;
 
             if cli.json_output() {
@@ -2087,6 +2504,13 @@ async fn run_friends_command(cli: &Cli, cmd: &FriendCommands) -> Result<(), CliE
             friendship_id,
         } => {
             let username = resolve_username(&client, user).await?;
+            if !cli.should_send_destructive(
+                "POST",
+                &format!("/people/{username}/friends/{friendship_id}/destroy.json"),
+                &format!("Remove friendship {friendship_id} for {username}?"),
+            )? {
+                return Ok(());
+            }
             client.friends().destroy(&username, *friendship_id).await?;
             println!("Removed friendship: {}", friendship_id);
         }
@@ -2095,11 +2519,11 @@ async fn run_friends_command(cli: &Cli, cmd: &FriendCommands) -> Result<(), CliE
     Ok(())
 }
 
-/// Wait for OAuth callback over HTTPS and extract the authorization code.
+/// Wait for OAuth callback over HTTPS and parse its code and state.
 ///
 /// Generates a self-signed certificate for localhost at runtime.
 /// The browser will show a security warning that users need to accept.
-async fn wait_for_oauth_callback() -> Result<String, CliError> {
+async fn wait_for_oauth_callback() -> Result<CallbackParams, CliError> {
     use rcgen::{generate_simple_self_signed, CertifiedKey};
     use rustls_pemfile::{certs, private_key};
     use std::io::Cursor;
@@ -2166,23 +2590,19 @@ async fn wait_for_oauth_callback() -> Result<String, CliError> {
     let mut request_line = String::new();
     reader.read_line(&mut request_line).await?;
 
-    // Parse the GET request to extract the code
+    // Parse the GET request to extract the code and state
     // Expected format: GET /callback?code=XXX&state=YYY HTTP/1.1
-    let code = request_line
+    let query = request_line
         .split_whitespace()
         .nth(1)
         .and_then(|path| {
             path.strip_prefix("/callback?")
                 .or_else(|| path.strip_prefix("/callback/?"))
         })
-        .and_then(|query| {
-            query
-                .split('&')
-                .find_map(|param| param.strip_prefix("code=").map(|code| code.to_string()))
-        })
         .ok_or(CliError::MissingCredentials(
             "No authorization code received in callback",
         ))?;
+    let params = CallbackParams::parse(query)?;
 
     // Send response
     let response = "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\n\r\n\
@@ -2192,5 +2612,66 @@ async fn wait_for_oauth_callback() -> Result<String, CliError> {
         </body></html>";
     writer.write_all(response.as_bytes()).await?;
 
-    Ok(code)
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_yes_and_dry_run_are_global() {
+        let cli =
+            Cli::try_parse_from(["ravelry", "projects", "delete", "5", "-y", "--dry-run"]).unwrap();
+        assert!(cli.yes);
+        assert!(cli.dry_run);
+        assert!(!cli.should_send("DELETE", "/projects/me/5.json"));
+    }
+
+    #[test]
+    fn test_output_requires_structured_format() {
+        let cli = Cli::try_parse_from(["ravelry", "whoami", "-o", "out.txt"]).unwrap();
+        assert!(cli.check_output_format().is_err());
+
+        let cli = Cli::try_parse_from(["ravelry", "whoami", "--json", "-o", "out.json"]).unwrap();
+        assert!(cli.check_output_format().is_ok());
+    }
+
+    #[test]
+    fn test_csv_and_table_require_list_command() {
+        for args in [
+            ["ravelry", "patterns", "show", "1", "--csv"],
+            ["ravelry", "messages", "read", "1", "--table"],
+        ] {
+            let cli = Cli::try_parse_from(args).unwrap();
+            assert!(cli.check_output_format().is_err());
+        }
+
+        let cli = Cli::try_parse_from(["ravelry", "patterns", "projects", "1", "--csv"]).unwrap();
+        assert!(cli.check_output_format().is_ok());
+
+        let cli = Cli::try_parse_from(["ravelry", "patterns", "projects", "1", "--csv", "-o", "f"])
+            .unwrap();
+        assert!(cli.check_output_format().is_ok());
+    }
+
+    #[test]
+    fn test_output_requires_command_with_json() {
+        for args in [
+            ["ravelry", "messages", "mark-read", "1", "--json", "-o", "f"],
+            ["ravelry", "messages", "delete", "1", "--json", "-o", "f"],
+        ] {
+            let cli = Cli::try_parse_from(args).unwrap();
+            assert!(cli.check_output_format().is_err());
+        }
+
+        let cli =
+            Cli::try_parse_from(["ravelry", "messages", "read", "1", "--json", "-o", "f"]).unwrap();
+        assert!(cli.check_output_format().is_ok());
+    }
 }
@@ -0,0 +1,223 @@
+//! CSV (`--csv`) and table (`--table`) output for list commands.
+
+use std::fmt::Display;
+use std::io::Write;
+
+use comfy_table::{presets, ContentArrangement, Table};
+use ravelry::types::{BookmarkList, MessageList, PatternList, ProjectSmall, StashSmall, YarnList};
+
+/// A list item that can be written as one CSV or table row.
+pub trait TableRow {
+    /// Column names, in the order [`TableRow::fields`] returns them.
+    const HEADERS: &'static [&'static str];
+
+    /// Returns the row's cells.
+    fn fields(&self) -> Vec<String>;
+}
+
+/// Write `rows` as CSV, with a header row, to `out`.
+pub fn write_csv<T: TableRow, W: Write>(out: W, rows: &[T]) -> Result<(), csv::Error> {
+    let mut writer = csv::Writer::from_writer(out);
+    writer.write_record(T::HEADERS)?;
+    for row in rows {
+        writer.write_record(row.fields())?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Render `rows` as an ASCII table with a header row.
+///
+/// Column widths are computed from the data and wrapped to fit the terminal
+/// width when it is known.
+pub fn render_table<T: TableRow>(rows: &[T]) -> String {
+    let mut table = Table::new();
+    table
+        .load_preset(presets::ASCII_FULL_CONDENSED)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(T::HEADERS.iter().copied());
+    for row in rows {
+        table.add_row(row.fields());
+    }
+    table.to_string()
+}
+
+/// Formats an optional value, using an empty cell for `None`.
+fn cell<T: Display>(value: &Option<T>) -> String {
+    value.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+impl TableRow for PatternList {
+    const HEADERS: &'static [&'static str] = &["id", "name", "designer_name", "free", "permalink"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.name.clone(),
+            cell(&self.designer_name),
+            cell(&self.free),
+            self.permalink.clone(),
+        ]
+    }
+}
+
+impl TableRow for YarnList {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "yarn_company_name",
+        "rating_average",
+        "rating_count",
+        "permalink",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.name.clone(),
+            cell(&self.yarn_company_name),
+            cell(&self.rating_average),
+            cell(&self.rating_count),
+            self.permalink.clone(),
+        ]
+    }
+}
+
+impl TableRow for ProjectSmall {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "pattern_name",
+        "status_name",
+        "progress",
+        "started",
+        "completed",
+        "permalink",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.name.clone(),
+            cell(&self.pattern_name),
+            cell(&self.status_name),
+            cell(&self.progress),
+            cell(&self.started),
+            cell(&self.completed),
+            self.permalink.clone(),
+        ]
+    }
+}
+
+impl TableRow for StashSmall {
+    const HEADERS: &'static [&'static str] =
+        &["id", "name", "yarn_name", "colorway_name", "permalink"];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            cell(&self.name),
+            cell(&self.yarn_name),
+            cell(&self.colorway_name),
+            self.permalink.clone(),
+        ]
+    }
+}
+
+impl TableRow for BookmarkList {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "type",
+        "favorited_id",
+        "comment",
+        "created_at",
+        "tag_names",
+    ];
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            cell(&self.type_name),
+            cell(&self.favorited_id),
+            cell(&self.comment),
+            cell(&self.created_at),
+            self.tag_names
+                .as_ref()
+                .map(|tags| tags.join(" "))
+                .unwrap_or_default(),
+        ]
+    }
+}
+
+impl TableRow for MessageList {
+    const HEADERS: &'static [&'static str] =
+        &["id", "unread", "subject", "sender", "sent_at", "folder"];
+
+    fn fields(&self) -> Vec<String> {
+        let unread = self.read_message.map(|read| !read);
+        vec![
+            self.id.to_string(),
+            cell(&unread),
+            self.subject.clone(),
+            self.sender
+                .as_ref()
+                .map(|sender| sender.username.clone())
+                .unwrap_or_default(),
+            cell(&self.sent_at),
+            cell(&self.folder_name),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_fields_are_empty_cells() {
+        let patterns: Vec<PatternList> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 1,
+                "name": "Cabled Hat, Large",
+                "permalink": "cabled-hat",
+                "designer_name": "Jane Doe",
+                "free": true
+            },
+            { "id": 2, "name": "Plain Sock", "permalink": "plain-sock" }
+        ]))
+        .unwrap();
+
+        let mut out = Vec::new();
+        write_csv(&mut out, &patterns).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "id,name,designer_name,free,permalink\n\
+             1,\"Cabled Hat, Large\",Jane Doe,true,cabled-hat\n\
+             2,Plain Sock,,,plain-sock\n"
+        );
+    }
+
+    #[test]
+    fn test_table_aligns_columns() {
+        let messages: Vec<MessageList> = serde_json::from_value(serde_json::json!([
+            {
+                "id": 7,
+                "subject": "Hello",
+                "read_message": false,
+                "sender": { "id": 1, "username": "knitter" }
+            },
+            { "id": 1234, "subject": "A much longer subject line" }
+        ]))
+        .unwrap();
+
+        let table = render_table(&messages);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert!(lines[1].contains("subject"));
+        assert!(lines[3].contains("knitter"));
+        assert!(lines
+            .iter()
+            .all(|line| line.chars().count() == lines[0].chars().count()));
+    }
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::client::RavelryClient;
 use crate::error::RavelryError;
-use crate::types::BundledItemFull;
+use crate::types::{BundledItemFull, BundledItemPost};
 
 /// Service for bundled items endpoints.
 pub struct BundledItemsApi<'a> {
@@ -29,7 +29,31 @@ impl<'a> BundledItemsApi<'a> {
     /// ```
     pub async fn show(&self, id: u64) -> Result<BundledItemShowResponse, RavelryError> {
         let path = format!("bundled_items/{}.json", id);
-        let req = self.client.get(&path);
+        let req = self.client.get(&path)?;
+        self.client.send_json(req).await
+    }
+
+    /// Add a favorite to a bundle.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::BundledItemPost;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let item = BundledItemPost::new(12345, 678).sort_order(1);
+    /// let response = client.bundled_items().create(&item).await?;
+    /// println!("Created bundled item: {}", response.bundled_item.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        data: &BundledItemPost,
+    ) -> Result<BundledItemCreateResponse, RavelryError> {
+        let req = self.client.post_data("bundled_items/create.json", data)?;
         self.client.send_json(req).await
     }
 
@@ -47,7 +71,7 @@ impl<'a> BundledItemsApi<'a> {
     /// ```
     pub async fn delete(&self, id: u64) -> Result<BundledItemDeleteResponse, RavelryError> {
         let path = format!("bundled_items/{}.json", id);
-        let req = self.client.delete(&path);
+        let req = self.client.delete(&path)?;
         self.client.send_json(req).await
     }
 }
@@ -63,6 +87,13 @@ pub struct BundledItemShowResponse {
     pub item: Option<serde_json::Value>,
 }
 
+/// Response from creating a bundled item.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BundledItemCreateResponse {
+    /// The created bundled item.
+    pub bundled_item: BundledItemFull,
+}
+
 /// Response from deleting a bundled item.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct BundledItemDeleteResponse {
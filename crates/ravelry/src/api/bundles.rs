@@ -2,12 +2,20 @@
 //!
 //! Bundles are user-created collections that can contain favorites.
 
+use futures::stream::{self, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
 use crate::client::RavelryClient;
 use crate::error::RavelryError;
-use crate::pagination::{PageParams, Paginator};
-use crate::types::{BundleFull, BundleList, BundlePost};
+use crate::pagination::{collect_all_pages, PageParams, Paginator};
+use crate::types::{BundleFull, BundleList, BundlePost, BundledItemFull, FavoritedItem};
+use crate::username::normalize_username;
+
+/// Page size used when walking a bundle's items.
+const EXPORT_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of bundled item requests in flight while exporting a bundle.
+const RESOLVE_CONCURRENCY: usize = 4;
 
 /// Service for bundles-related API endpoints.
 pub struct BundlesApi<'a> {
@@ -38,8 +46,9 @@ impl<'a> BundlesApi<'a> {
         username: &str,
         params: &BundlesListParams,
     ) -> Result<BundlesListResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/bundles/list.json", username);
-        let req = self.client.get(&path).query(params);
+        let req = self.client.get(&path)?.query(params);
         self.client.send_json(req).await
     }
 
@@ -57,11 +66,125 @@ impl<'a> BundlesApi<'a> {
     /// # }
     /// ```
     pub async fn show(&self, username: &str, id: u64) -> Result<BundleShowResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/bundles/{}.json", username, id);
-        let req = self.client.get(&path);
+        let req = self.client.get(&path)?;
+        self.client.send_json(req).await
+    }
+
+    /// List the items in a bundle.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::bundles::BundledItemsParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = BundledItemsParams::new().page_size(50);
+    /// let response = client.bundles().items("username", 12345, &params).await?;
+    /// for item in response.bundled_items {
+    ///     println!("{}: {:?}", item.id, item.item_type);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn items(
+        &self,
+        username: &str,
+        id: u64,
+        params: &BundledItemsParams,
+    ) -> Result<BundledItemsListResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/bundles/{}/bundled_items.json", username, id);
+        let req = self.client.get(&path)?.query(params);
         self.client.send_json(req).await
     }
 
+    /// Fetch a bundle together with all of its items, resolved to typed objects.
+    ///
+    /// This fetches the bundle and every page of its item list, then resolves each item to a
+    /// [`FavoritedItem`]. Items whose object isn't embedded in the list
+    /// response are fetched individually via
+    /// [`BundledItemsApi::show`](crate::api::bundled_items::BundledItemsApi::show),
+    /// a few at a time, so large bundles can take one request per item. Items
+    /// that still can't be resolved are kept as [`FavoritedItem::Other`]
+    /// holding their `bundled_item_id` and `item_type`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::FavoritedItem;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let export = client.bundles().export("username", 12345).await?;
+    /// println!("{:?}: {} items", export.bundle.name, export.items.len());
+    /// for item in &export.items {
+    ///     if let FavoritedItem::Pattern(pattern) = item {
+    ///         println!("pattern: {}", pattern.name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export(&self, username: &str, id: u64) -> Result<ResolvedBundle, RavelryError> {
+        let bundle = self.show(username, id).await?.bundle;
+        let bundled_items = collect_all_pages(EXPORT_PAGE_SIZE, None, |page| {
+            let params = BundledItemsParams { page };
+            async move {
+                let resp = self.items(username, id, &params).await?;
+                let requested = params.page.page.unwrap_or(1);
+                // Without a paginator, treat the response as the last page
+                let paginator = resp.paginator.unwrap_or(Paginator {
+                    page_count: requested,
+                    page: requested,
+                    page_size: EXPORT_PAGE_SIZE,
+                    results: 0,
+                    last_page: requested,
+                });
+                Ok((resp.bundled_items, paginator))
+            }
+        })
+        .await?;
+
+        let items = stream::iter(bundled_items)
+            .map(|bundled_item| self.resolve_item(bundled_item))
+            .buffered(RESOLVE_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        Ok(ResolvedBundle { bundle, items })
+    }
+
+    /// Resolve a bundled item to its typed object, fetching it if needed.
+    async fn resolve_item(
+        &self,
+        bundled_item: BundledItemFull,
+    ) -> Result<FavoritedItem, RavelryError> {
+        let (item_type, value) = match bundled_item.item {
+            Some(value) => (bundled_item.item_type, Some(value)),
+            None => {
+                let shown = self.client.bundled_items().show(bundled_item.id).await?;
+                let item_type = bundled_item.item_type.or(shown.bundled_item.item_type);
+                (item_type, shown.item.or(shown.bundled_item.item))
+            }
+        };
+
+        Ok(match value {
+            Some(value) => {
+                FavoritedItem::from_value(item_type.as_deref().unwrap_or_default(), value)
+            }
+            // Keep the entry so the export still lines up with the bundle
+            None => FavoritedItem::Other(serde_json::json!({
+                "bundled_item_id": bundled_item.id,
+                "item_type": item_type,
+            })),
+        })
+    }
+
     /// Create a new bundle.
     ///
     /// # Example
@@ -86,8 +209,9 @@ impl<'a> BundlesApi<'a> {
         username: &str,
         data: &BundlePost,
     ) -> Result<BundleMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/bundles/create.json", username);
-        let req = self.client.post_data(&path, data);
+        let req = self.client.post_data(&path, data)?;
         self.client.send_json(req).await
     }
 
@@ -112,8 +236,9 @@ impl<'a> BundlesApi<'a> {
         id: u64,
         data: &BundlePost,
     ) -> Result<BundleMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/bundles/{}.json", username, id);
-        let req = self.client.post_data(&path, data);
+        let req = self.client.post_data(&path, data)?;
         self.client.send_json(req).await
     }
 
@@ -134,8 +259,9 @@ impl<'a> BundlesApi<'a> {
         username: &str,
         id: u64,
     ) -> Result<BundleMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/bundles/{}.json", username, id);
-        let req = self.client.delete(&path);
+        let req = self.client.delete(&path)?;
         self.client.send_json(req).await
     }
 }
@@ -177,6 +303,33 @@ impl BundlesListParams {
     }
 }
 
+/// Parameters for listing a bundle's items.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct BundledItemsParams {
+    /// Pagination parameters.
+    #[serde(flatten)]
+    pub page: PageParams,
+}
+
+impl BundledItemsParams {
+    /// Create new params with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.page = Some(page);
+        self
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page.page_size = Some(size);
+        self
+    }
+}
+
 /// Response from listing bundles.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct BundlesListResponse {
@@ -201,3 +354,25 @@ pub struct BundleMutateResponse {
     /// The mutated bundle.
     pub bundle: BundleFull,
 }
+
+/// Response from listing a bundle's items.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BundledItemsListResponse {
+    /// The items in the bundle.
+    #[serde(default)]
+    pub bundled_items: Vec<BundledItemFull>,
+
+    /// Pagination information.
+    #[serde(default)]
+    pub paginator: Option<Paginator>,
+}
+
+/// A bundle with all of its items resolved, from [`BundlesApi::export`].
+#[derive(Serialize, Debug, Clone)]
+pub struct ResolvedBundle {
+    /// The bundle details.
+    pub bundle: BundleFull,
+
+    /// The bundle's items, in bundle order.
+    pub items: Vec<FavoritedItem>,
+}
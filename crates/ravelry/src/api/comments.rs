@@ -0,0 +1,172 @@
+//! Comment API endpoints.
+//!
+//! Comments can be left on projects, patterns, yarns, stash and more. To find
+//! the comments a user has written, see [`PeopleApi::comments`](crate::api::people::PeopleApi::comments).
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::pagination::{PageParams, Paginator};
+use crate::types::{CommentFull, CommentList, CommentParent, CommentPost};
+
+/// Service for comment-related API endpoints.
+pub struct CommentsApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> CommentsApi<'a> {
+    /// List the comments on an item.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::comments::CommentsListParams;
+    /// use ravelry::types::CommentParent;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = CommentsListParams::new().page_size(25);
+    /// let response = client
+    ///     .comments()
+    ///     .list(CommentParent::Project, 12345, &params)
+    ///     .await?;
+    /// for comment in response.comments {
+    ///     println!("{}: {:?}", comment.id, comment.body);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(
+        &self,
+        parent_type: CommentParent,
+        parent_id: u64,
+        params: &CommentsListParams,
+    ) -> Result<CommentsListResponse, RavelryError> {
+        let path = format!("comments/{}/{}/list.json", parent_type, parent_id);
+        let req = self.client.get(&path)?.query(params);
+        self.client.send_json(req).await
+    }
+
+    /// Post a comment on an item.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::{CommentParent, CommentPost};
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let post = CommentPost::new().body("Lovely colorwork!");
+    /// let response = client
+    ///     .comments()
+    ///     .create(CommentParent::Pattern, 12345, &post)
+    ///     .await?;
+    /// println!("Posted comment {}", response.comment.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        parent_type: CommentParent,
+        parent_id: u64,
+        data: &CommentPost,
+    ) -> Result<CommentResponse, RavelryError> {
+        let path = format!("comments/{}/{}/create.json", parent_type, parent_id);
+        let req = self.client.post_data(&path, data)?;
+        self.client.send_json(req).await
+    }
+
+    /// Edit a comment.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::CommentPost;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let update = CommentPost::new().body("Lovely colourwork!");
+    /// client.comments().update(12345, &update).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(
+        &self,
+        id: u64,
+        data: &CommentPost,
+    ) -> Result<CommentResponse, RavelryError> {
+        let path = format!("comments/{}.json", id);
+        let req = self.client.post_data(&path, data)?;
+        self.client.send_json(req).await
+    }
+
+    /// Delete a comment.
+    ///
+    /// Only the comment's author (or the owner of the commented item) can
+    /// delete it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// client.comments().delete(12345).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, id: u64) -> Result<CommentResponse, RavelryError> {
+        let path = format!("comments/{}.json", id);
+        let req = self.client.delete(&path)?;
+        self.client.send_json(req).await
+    }
+}
+
+/// Parameters for listing an item's comments.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct CommentsListParams {
+    /// Pagination parameters.
+    #[serde(flatten)]
+    pub page: PageParams,
+}
+
+impl CommentsListParams {
+    /// Create new params with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.page = Some(page);
+        self
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page.page_size = Some(size);
+        self
+    }
+}
+
+/// Response from listing an item's comments.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CommentsListResponse {
+    /// The comments on the item.
+    #[serde(default)]
+    pub comments: Vec<CommentList>,
+
+    /// Pagination information.
+    pub paginator: Paginator,
+}
+
+/// Response containing a single comment.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CommentResponse {
+    /// The comment.
+    pub comment: CommentFull,
+}
@@ -2,12 +2,18 @@
 //!
 //! Favorites allow users to bookmark patterns, yarns, projects, and other items.
 
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
 
+use crate::batch::BatchResult;
 use crate::client::RavelryClient;
 use crate::error::RavelryError;
 use crate::pagination::{PageParams, Paginator};
-use crate::types::{BookmarkFull, BookmarkList, BookmarkPost};
+use crate::types::{BookmarkFull, BookmarkList, BookmarkPost, FavoriteType};
+use crate::username::normalize_username;
+
+/// Maximum number of create requests in flight in [`FavoritesApi::create_many`].
+const CREATE_CONCURRENCY: usize = 4;
 
 /// Service for favorites-related API endpoints.
 pub struct FavoritesApi<'a> {
@@ -38,8 +44,9 @@ impl<'a> FavoritesApi<'a> {
         username: &str,
         params: &FavoritesListParams,
     ) -> Result<FavoritesListResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/favorites/list.json", username);
-        let req = self.client.get(&path).query(params);
+        let req = self.client.get(&path)?.query(params);
         self.client.send_json(req).await
     }
 
@@ -61,8 +68,9 @@ impl<'a> FavoritesApi<'a> {
         username: &str,
         id: u64,
     ) -> Result<FavoritesShowResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/favorites/{}.json", username, id);
-        let req = self.client.get(&path);
+        let req = self.client.get(&path)?;
         self.client.send_json(req).await
     }
 
@@ -91,11 +99,64 @@ impl<'a> FavoritesApi<'a> {
         username: &str,
         data: &BookmarkPost,
     ) -> Result<FavoritesMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/favorites/create.json", username);
-        let req = self.client.post_data(&path, data);
+        let req = self.client.post_data(&path, data)?;
         self.client.send_json(req).await
     }
 
+    /// Create several favorites.
+    ///
+    /// Ravelry creates one favorite per request, so this sends a create
+    /// request per bookmark, up to four at a time. A failed bookmark doesn't
+    /// stop the others: the result lists the created favorites and the
+    /// errors, each with the index of its bookmark in `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error without sending anything if `username` is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::BookmarkPost;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let bookmarks: Vec<BookmarkPost> = [123, 456, 789]
+    ///     .into_iter()
+    ///     .map(|id| BookmarkPost::new().type_name("pattern").favorited_id(id))
+    ///     .collect();
+    ///
+    /// let result = client.favorites().create_many("username", &bookmarks).await?;
+    /// for (index, err) in &result.failed {
+    ///     eprintln!("bookmark {index} failed: {err}");
+    /// }
+    /// println!("created {}", result.succeeded.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_many(
+        &self,
+        username: &str,
+        data: &[BookmarkPost],
+    ) -> Result<BatchResult<BookmarkFull>, RavelryError> {
+        let username = normalize_username(username)?;
+        let results = stream::iter(data.iter().enumerate())
+            .map(|(index, bookmark)| {
+                let username = &username;
+                async move {
+                    let result = self.create(username, bookmark).await;
+                    (index, result.map(|response| response.favorite))
+                }
+            })
+            .buffer_unordered(CREATE_CONCURRENCY)
+            .collect()
+            .await;
+        Ok(BatchResult::from_results(results))
+    }
+
     /// Update an existing favorite.
     ///
     /// # Example
@@ -117,8 +178,9 @@ impl<'a> FavoritesApi<'a> {
         id: u64,
         data: &BookmarkPost,
     ) -> Result<FavoritesMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/favorites/{}.json", username, id);
-        let req = self.client.post_data(&path, data);
+        let req = self.client.post_data(&path, data)?;
         self.client.send_json(req).await
     }
 
@@ -139,8 +201,9 @@ impl<'a> FavoritesApi<'a> {
         username: &str,
         id: u64,
     ) -> Result<FavoritesMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/favorites/{}.json", username, id);
-        let req = self.client.delete(&path);
+        let req = self.client.delete(&path)?;
         self.client.send_json(req).await
     }
 
@@ -168,7 +231,7 @@ impl<'a> FavoritesApi<'a> {
         );
         let req = self
             .client
-            .post(&path)
+            .post(&path)?
             .query(&[("bundle_id", bundle_id.to_string())]);
         self.client.send_json(req).await
     }
@@ -197,7 +260,7 @@ impl<'a> FavoritesApi<'a> {
         );
         let req = self
             .client
-            .post(&path)
+            .post(&path)?
             .query(&[("bundle_id", bundle_id.to_string())]);
         self.client.send_json(req).await
     }
@@ -251,6 +314,12 @@ impl FavoritesListParams {
         self
     }
 
+    /// Filter by a typed item type.
+    pub fn type_filter_enum(mut self, favorite_type: FavoriteType) -> Self {
+        self.type_filter = Some(favorite_type.as_str().to_string());
+        self
+    }
+
     /// Filter by tag.
     pub fn tag(mut self, tag: impl Into<String>) -> Self {
         self.tag = Some(tag.into());
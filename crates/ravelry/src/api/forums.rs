@@ -0,0 +1,196 @@
+//! Forum API endpoints.
+//!
+//! Ravelry groups hold forums, forums hold topics, and topics hold posts.
+//! [`ForumsApi`] lists a forum's topics and a topic's posts, [`PostsApi`]
+//! reads individual posts, and [`TopicsApi`] replies to topics.
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::pagination::{PageParams, Paginator};
+use crate::types::{ForumTopic, PostFull, PostPost};
+
+/// Service for reading forum content.
+pub struct ForumsApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> ForumsApi<'a> {
+    /// List the topics in a forum.
+    ///
+    /// A group's forum ID is [`GroupFull::forum_id`](crate::types::GroupFull::forum_id).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::forums::ForumPageParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = ForumPageParams::new().page_size(25);
+    /// let response = client.forums().topics(777, &params).await?;
+    /// for topic in response.topics {
+    ///     println!("{}: {:?}", topic.id, topic.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn topics(
+        &self,
+        forum_id: u64,
+        params: &ForumPageParams,
+    ) -> Result<ForumTopicsResponse, RavelryError> {
+        let path = format!("forums/{}/topics.json", forum_id);
+        let req = self.client.get(&path)?.query(params);
+        self.client.send_json(req).await
+    }
+
+    /// List the posts in a topic, oldest first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::forums::ForumPageParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let response = client.forums().posts(6789, &ForumPageParams::new()).await?;
+    /// for post in response.posts {
+    ///     let author = post.user.map(|u| u.username).unwrap_or_default();
+    ///     println!("#{:?} by {}: {:?}", post.post_number, author, post.body_html);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn posts(
+        &self,
+        topic_id: u64,
+        params: &ForumPageParams,
+    ) -> Result<TopicPostsResponse, RavelryError> {
+        let path = format!("topics/{}/posts.json", topic_id);
+        let req = self.client.get(&path)?.query(params);
+        self.client.send_json(req).await
+    }
+}
+
+/// Service for forum post endpoints.
+pub struct PostsApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> PostsApi<'a> {
+    /// Get a forum post, including its body.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let response = client.posts().show(12345).await?;
+    /// println!("{:?}", response.post.body);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn show(&self, id: u64) -> Result<PostResponse, RavelryError> {
+        let path = format!("forum_posts/{}.json", id);
+        let req = self.client.get(&path)?;
+        self.client.send_json(req).await
+    }
+}
+
+/// Service for forum topic endpoints.
+pub struct TopicsApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> TopicsApi<'a> {
+    /// Post a reply to a forum topic.
+    ///
+    /// Requires the `forum-write` OAuth scope.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::PostPost;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let reply = PostPost::new().body("Great question! I'd try a provisional cast-on.");
+    /// let response = client.topics().reply(6789, &reply).await?;
+    /// println!("Posted reply {}", response.post.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reply(
+        &self,
+        topic_id: u64,
+        data: &PostPost,
+    ) -> Result<PostResponse, RavelryError> {
+        let path = format!("topics/{}/reply.json", topic_id);
+        let req = self.client.post_data(&path, data)?;
+        self.client.send_json(req).await
+    }
+}
+
+/// Response containing a single forum post.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PostResponse {
+    /// The post.
+    pub post: PostFull,
+}
+
+/// Parameters for listing forum topics or topic posts.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct ForumPageParams {
+    /// Pagination parameters.
+    #[serde(flatten)]
+    pub page: PageParams,
+}
+
+impl ForumPageParams {
+    /// Create new params with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.page = Some(page);
+        self
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page.page_size = Some(size);
+        self
+    }
+}
+
+/// Response from listing a forum's topics.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ForumTopicsResponse {
+    /// The topics in the forum.
+    #[serde(default)]
+    pub topics: Vec<ForumTopic>,
+
+    /// Pagination information.
+    #[serde(default)]
+    pub paginator: Option<Paginator>,
+}
+
+/// Response from listing a topic's posts.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct TopicPostsResponse {
+    /// The posts in the topic.
+    #[serde(default)]
+    pub posts: Vec<PostFull>,
+
+    /// Pagination information.
+    #[serde(default)]
+    pub paginator: Option<Paginator>,
+}
@@ -8,6 +8,7 @@ use crate::client::RavelryClient;
 use crate::error::RavelryError;
 use crate::pagination::PageParams;
 use crate::types::{FriendActivity, Friendship};
+use crate::username::normalize_username;
 
 /// Service for friends-related API endpoints.
 pub struct FriendsApi<'a> {
@@ -17,6 +18,11 @@ pub struct FriendsApi<'a> {
 impl<'a> FriendsApi<'a> {
     /// Get a user's friend activity feed.
     ///
+    /// Activity is returned newest first. Activity IDs increase over time, so
+    /// to poll for updates, remember the highest ID seen and pass it to
+    /// [`FriendsActivityParams::since`] on the next call; only newer activity
+    /// is returned.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -38,8 +44,9 @@ impl<'a> FriendsApi<'a> {
         username: &str,
         params: &FriendsActivityParams,
     ) -> Result<FriendsActivityResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/friends/activity.json", username);
-        let req = self.client.get(&path).query(params);
+        let req = self.client.get(&path)?.query(params);
         self.client.send_json(req).await
     }
 
@@ -59,8 +66,9 @@ impl<'a> FriendsApi<'a> {
     /// # }
     /// ```
     pub async fn list(&self, username: &str) -> Result<FriendsListResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/friends/list.json", username);
-        let req = self.client.get(&path);
+        let req = self.client.get(&path)?;
         self.client.send_json(req).await
     }
 
@@ -83,10 +91,11 @@ impl<'a> FriendsApi<'a> {
         username: &str,
         friend_user_id: u64,
     ) -> Result<FriendshipMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/friends/create.json", username);
         let req = self
             .client
-            .post(&path)
+            .post(&path)?
             .query(&[("friend_user_id", friend_user_id.to_string())]);
         self.client.send_json(req).await
     }
@@ -111,8 +120,9 @@ impl<'a> FriendsApi<'a> {
         username: &str,
         friendship_id: u64,
     ) -> Result<FriendshipMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/friends/{}/destroy.json", username, friendship_id);
-        let req = self.client.post(&path);
+        let req = self.client.post(&path)?;
         self.client.send_json(req).await
     }
 }
@@ -127,6 +137,10 @@ pub struct FriendsActivityParams {
     /// Filter by activity type.
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub activity_type: Option<String>,
+
+    /// Only return activity with an ID greater than this one (exclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<u64>,
 }
 
 impl FriendsActivityParams {
@@ -152,6 +166,14 @@ impl FriendsActivityParams {
         self.activity_type = Some(activity_type.into());
         self
     }
+
+    /// Only fetch activity newer than the given activity ID.
+    ///
+    /// Pass the highest [`FriendActivity::id`] from the previous fetch.
+    pub fn since(mut self, last_seen_id: u64) -> Self {
+        self.since = Some(last_seen_id);
+        self
+    }
 }
 
 /// Response from friend activity feed.
@@ -0,0 +1,128 @@
+//! Group-related API endpoints.
+//!
+//! Groups are communities of users, each with its own forum.
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::pagination::{PageParams, Paginator};
+use crate::types::{GroupFull, GroupList};
+
+/// Service for group-related API endpoints.
+pub struct GroupsApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> GroupsApi<'a> {
+    /// Search for groups.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::groups::GroupSearchParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = GroupSearchParams::new().query("brioche").page_size(10);
+    ///
+    /// let response = client.groups().search(&params).await?;
+    /// for group in response.groups {
+    ///     println!("{}: {} ({:?} members)", group.id, group.name, group.members_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search(
+        &self,
+        params: &GroupSearchParams,
+    ) -> Result<GroupsSearchResponse, RavelryError> {
+        let req = self.client.get("groups/search.json")?.query(params);
+        self.client.send_json(req).await
+    }
+
+    /// Get details for a specific group.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let response = client.groups().show(1234).await?;
+    /// println!("Group: {}", response.group.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn show(&self, id: u64) -> Result<GroupShowResponse, RavelryError> {
+        let path = format!("groups/{}.json", id);
+        let req = self.client.get(&path)?;
+        self.client.send_json(req).await
+    }
+}
+
+/// Parameters for group search.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct GroupSearchParams {
+    /// Free-text search query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// Pagination parameters.
+    #[serde(flatten)]
+    pub page: PageParams,
+
+    /// Sort order (e.g., "best", "members", "created").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+impl GroupSearchParams {
+    /// Create new search params with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the search query.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Set the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.page = Some(page);
+        self
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page.page_size = Some(size);
+        self
+    }
+
+    /// Set the sort order.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+}
+
+/// Response from group search.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GroupsSearchResponse {
+    /// The list of groups matching the search.
+    #[serde(default)]
+    pub groups: Vec<GroupList>,
+
+    /// Pagination information.
+    pub paginator: Paginator,
+}
+
+/// Response from fetching a single group.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GroupShowResponse {
+    /// The full group details.
+    pub group: GroupFull,
+}
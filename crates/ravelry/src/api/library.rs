@@ -0,0 +1,121 @@
+//! Library API endpoints.
+//!
+//! A user's library lists the patterns and publications they own, including
+//! purchases from the Ravelry pattern store.
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::pagination::{PageParams, Paginator};
+use crate::types::LibraryVolume;
+use crate::username::normalize_username;
+
+/// Service for library endpoints.
+pub struct LibraryApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> LibraryApi<'a> {
+    /// Search a user's library.
+    ///
+    /// Requires authentication as `username`; a library is private to its
+    /// owner. Searching needs no particular OAuth scope, but downloading the
+    /// files of a volume needs [`Scope::LibraryPdf`](crate::auth::Scope::LibraryPdf).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::library::LibrarySearchParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = LibrarySearchParams::new().type_filter("pattern").page_size(50);
+    /// let response = client.library().search("username", &params).await?;
+    /// for volume in response.volumes {
+    ///     println!("{:?} by {:?}", volume.title, volume.author_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search(
+        &self,
+        username: &str,
+        params: &LibrarySearchParams,
+    ) -> Result<LibrarySearchResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/library/search.json", username);
+        let req = self.client.get(&path)?.query(params);
+        self.client.send_json(req).await
+    }
+}
+
+/// Parameters for library search.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct LibrarySearchParams {
+    /// Free-text search query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// Pagination parameters.
+    #[serde(flatten)]
+    pub page: PageParams,
+
+    /// Filter by volume type (e.g., "pattern", "book", "magazine", "booklet").
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_filter: Option<String>,
+
+    /// Sort order (e.g., "title", "added", "published").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+impl LibrarySearchParams {
+    /// Create new search params with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the search query.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Set the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.page = Some(page);
+        self
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page.page_size = Some(size);
+        self
+    }
+
+    /// Filter by volume type.
+    pub fn type_filter(mut self, type_name: impl Into<String>) -> Self {
+        self.type_filter = Some(type_name.into());
+        self
+    }
+
+    /// Set the sort order.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+}
+
+/// Response from library search.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct LibrarySearchResponse {
+    /// The matching volumes.
+    #[serde(default)]
+    pub volumes: Vec<LibraryVolume>,
+
+    /// Pagination information.
+    #[serde(default)]
+    pub paginator: Option<Paginator>,
+}
@@ -41,7 +41,38 @@ impl<'a> MessagesApi<'a> {
         &self,
         params: &MessagesListParams,
     ) -> Result<MessagesListResponse, RavelryError> {
-        let req = self.client.get("messages/list.json").query(params);
+        let req = self.client.get("messages/list.json")?.query(params);
+        self.client.send_json(req).await
+    }
+
+    /// List messages in a folder, including their content.
+    ///
+    /// This requests `output_format=full` (whatever `params` says), so each
+    /// message arrives as a [`MessageFull`] with its body, saving a
+    /// [`show`](Self::show) call per message.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::messages::{MessagesListParams, MessageFolder};
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = MessagesListParams::new().folder(MessageFolder::Inbox);
+    /// let response = client.messages().list_full(&params).await?;
+    /// for message in response.messages {
+    ///     println!("{}: {}", message.subject, message.content.unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_full(
+        &self,
+        params: &MessagesListParams,
+    ) -> Result<MessagesListFullResponse, RavelryError> {
+        let params = params.clone().full_output();
+        let req = self.client.get("messages/list.json")?.query(&params);
         self.client.send_json(req).await
     }
 
@@ -63,12 +94,14 @@ impl<'a> MessagesApi<'a> {
     /// ```
     pub async fn show(&self, id: u64) -> Result<MessageShowResponse, RavelryError> {
         let path = format!("messages/{}.json", id);
-        let req = self.client.get(&path);
+        let req = self.client.get(&path)?;
         self.client.send_json(req).await
     }
 
     /// Mark a message as read.
     ///
+    /// Requires the `message-write` OAuth scope.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -81,12 +114,14 @@ impl<'a> MessagesApi<'a> {
     /// ```
     pub async fn mark_read(&self, id: u64) -> Result<MessageResponse, RavelryError> {
         let path = format!("messages/{}/mark_read.json", id);
-        let req = self.client.post(&path);
+        let req = self.client.post(&path)?;
         self.client.send_json(req).await
     }
 
     /// Mark a message as unread.
     ///
+    /// Requires the `message-write` OAuth scope.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -99,7 +134,7 @@ impl<'a> MessagesApi<'a> {
     /// ```
     pub async fn mark_unread(&self, id: u64) -> Result<MessageResponse, RavelryError> {
         let path = format!("messages/{}/mark_unread.json", id);
-        let req = self.client.post(&path);
+        let req = self.client.post(&path)?;
         self.client.send_json(req).await
     }
 
@@ -120,7 +155,7 @@ impl<'a> MessagesApi<'a> {
     /// ```
     pub async fn archive(&self, id: u64) -> Result<MessageResponse, RavelryError> {
         let path = format!("messages/{}/archive.json", id);
-        let req = self.client.post(&path);
+        let req = self.client.post(&path)?;
         self.client.send_json(req).await
     }
 
@@ -140,7 +175,7 @@ impl<'a> MessagesApi<'a> {
     /// ```
     pub async fn delete(&self, id: u64) -> Result<MessageResponse, RavelryError> {
         let path = format!("messages/{}.json", id);
-        let req = self.client.delete(&path);
+        let req = self.client.delete(&path)?;
         self.client.send_json(req).await
     }
 
@@ -167,7 +202,7 @@ impl<'a> MessagesApi<'a> {
     /// # }
     /// ```
     pub async fn create(&self, data: &MessagePost) -> Result<MessageResponse, RavelryError> {
-        let req = self.client.post_data("messages/create.json", data);
+        let req = self.client.post_data("messages/create.json", data)?;
         self.client.send_json(req).await
     }
 
@@ -197,7 +232,7 @@ impl<'a> MessagesApi<'a> {
         data: &MessagePost,
     ) -> Result<MessageResponse, RavelryError> {
         let path = format!("messages/{}/reply.json", id);
-        let req = self.client.post_data(&path, data);
+        let req = self.client.post_data(&path, data)?;
         self.client.send_json(req).await
     }
 
@@ -218,7 +253,7 @@ impl<'a> MessagesApi<'a> {
     /// ```
     pub async fn unarchive(&self, id: u64) -> Result<MessageResponse, RavelryError> {
         let path = format!("messages/{}/unarchive.json", id);
-        let req = self.client.post(&path);
+        let req = self.client.post(&path)?;
         self.client.send_json(req).await
     }
 }
@@ -265,7 +300,10 @@ pub struct MessagesListParams {
     pub page: PageParams,
 
     /// Only return unread messages.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        with = "crate::serde_helpers::bool_as_int",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub unread_only: Option<bool>,
 
     /// Search term for fulltext searching messages.
@@ -318,6 +356,9 @@ impl MessagesListParams {
     }
 
     /// Request full message content instead of list format.
+    ///
+    /// Use [`MessagesApi::list_full`] to receive the content; [`MessagesApi::list`]
+    /// only reads list fields.
     pub fn full_output(mut self) -> Self {
         self.output_format = Some("full".to_string());
         self
@@ -334,6 +375,17 @@ pub struct MessagesListResponse {
     pub paginator: Paginator,
 }
 
+/// Response from listing messages with full content.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MessagesListFullResponse {
+    /// The list of messages, with content.
+    #[serde(default)]
+    pub messages: Vec<MessageFull>,
+
+    /// Pagination information.
+    pub paginator: Paginator,
+}
+
 /// Response from showing a single message.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct MessageShowResponse {
@@ -24,12 +24,22 @@
 
 pub mod bundled_items;
 pub mod bundles;
+pub mod comments;
 pub mod favorites;
+pub mod forums;
 pub mod friends;
+pub mod groups;
+pub mod library;
 pub mod messages;
 pub mod patterns;
+pub mod people;
+pub mod photos;
 pub mod projects;
+pub mod queue;
+pub mod reference;
 pub mod root;
+pub mod saved_searches;
 pub mod stash;
 pub mod upload;
+pub mod volumes;
 pub mod yarns;
@@ -2,12 +2,16 @@
 //!
 //! Patterns are knitting/crochet instructions for creating items.
 
+use futures::future::BoxFuture;
 use serde::{Deserialize, Serialize};
 
 use crate::client::RavelryClient;
 use crate::error::RavelryError;
-use crate::pagination::{PageParams, Paginator};
-use crate::types::{PatternFull, PatternList, ProjectSmall};
+use crate::etag::Conditional;
+use crate::pagination::{page_fetcher, Direction, Page, PageParams, Paginator};
+use crate::types::{
+    Craft, PatternFull, PatternList, PatternSort, PatternSummary, ProjectSmall, YarnWeight,
+};
 
 /// Service for pattern-related API endpoints.
 pub struct PatternsApi<'a> {
@@ -43,10 +47,108 @@ impl<'a> PatternsApi<'a> {
         &self,
         params: &PatternSearchParams,
     ) -> Result<PatternsSearchResponse, RavelryError> {
-        let req = self.client.get("patterns/search.json").query(params);
+        let req = self.client.get("patterns/search.json")?.query(params);
         self.client.send_json(req).await
     }
 
+    /// Search for patterns, returning a [`Page`] that can fetch adjacent pages.
+    ///
+    /// The params are stored with the page, so [`Page::next`] and
+    /// [`Page::prev`] repeat the same search for a different page number.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::patterns::PatternSearchParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = PatternSearchParams::new().query("baby blanket");
+    /// let first = client.patterns().search_page(&params).await?;
+    /// if let Some(second) = first.next(&client).await? {
+    ///     println!("page {}", second.paginator.page);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_page(
+        &self,
+        params: &PatternSearchParams,
+    ) -> Result<Page<PatternList>, RavelryError> {
+        let page = params.page.page.unwrap_or(1);
+        fetch_search_page(self.client, params.clone(), page).await
+    }
+
+    /// Repeat a search for the page next to (or before) a previous response.
+    ///
+    /// `paginator` comes from an earlier response to the same `params`; the
+    /// target page is computed from it and fetched. Returns `None` if there is
+    /// no page in that direction.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::patterns::PatternSearchParams;
+    /// use ravelry::Direction;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = PatternSearchParams::new().query("mittens");
+    /// let response = client.patterns().search(&params).await?;
+    /// let next = client
+    ///     .patterns()
+    ///     .search_relative(&params, &response.paginator, Direction::Next)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_relative(
+        &self,
+        params: &PatternSearchParams,
+        paginator: &Paginator,
+        direction: Direction,
+    ) -> Result<Option<Page<PatternList>>, RavelryError> {
+        match paginator.page_in(direction) {
+            Some(page) => fetch_search_page(self.client, params.clone(), page)
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// List the patterns by a designer.
+    ///
+    /// This is a search filtered on the designer's permalink (the last path
+    /// segment of their Ravelry designer page). Other filters, sort and
+    /// pagination in `params` still apply.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::patterns::PatternSearchParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = PatternSearchParams::new().sort("date").page_size(20);
+    /// let response = client.patterns().by_designer("martina-behm", &params).await?;
+    /// for pattern in response.patterns {
+    ///     println!("{}", pattern.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn by_designer(
+        &self,
+        designer_permalink: &str,
+        params: &PatternSearchParams,
+    ) -> Result<PatternsSearchResponse, RavelryError> {
+        let params = params.clone().designer(designer_permalink);
+        self.search(&params).await
+    }
+
     /// Get details for a single pattern.
     ///
     /// # Example
@@ -62,10 +164,100 @@ impl<'a> PatternsApi<'a> {
     /// ```
     pub async fn show(&self, id: u64) -> Result<PatternShowResponse, RavelryError> {
         let path = format!("patterns/{id}.json");
-        let req = self.client.get(&path);
+        let req = self.client.get(&path)?;
         self.client.send_json(req).await
     }
 
+    /// Get details for a single pattern, reusing `previous` if it hasn't
+    /// changed.
+    ///
+    /// With [`RequestOptions::return_cached_on_304`](crate::request_options::RequestOptions::return_cached_on_304)
+    /// enabled, a `304 Not Modified` response returns `previous` instead of
+    /// [`RavelryError::NotModified`]. Otherwise this behaves like
+    /// [`show`](Self::show).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::request_options::RequestOptions;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+    ///     .request_options(RequestOptions::new().if_none_match("\"v1\"").return_cached_on_304(true))
+    ///     .build()?;
+    /// let first = client.patterns().show(123456).await?;
+    /// let latest = client.patterns().show_or_previous(123456, Some(first)).await?;
+    /// println!("{}", latest.pattern.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn show_or_previous(
+        &self,
+        id: u64,
+        previous: Option<PatternShowResponse>,
+    ) -> Result<PatternShowResponse, RavelryError> {
+        let path = format!("patterns/{id}.json");
+        let req = self.client.get(&path)?;
+        self.client.send_json_or_cached(req, previous).await
+    }
+
+    /// Get details for a single pattern unless it matches `etag`.
+    ///
+    /// Pass the ETag from a previous call to skip the download when the
+    /// pattern hasn't changed; pass `None` for the first fetch. A
+    /// `304 Not Modified` response is returned as
+    /// [`Conditional::NotModified`] rather than an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::Conditional;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// # let cached_etag: Option<String> = None;
+    /// match client.patterns().show_conditional(123456, cached_etag.as_deref()).await? {
+    ///     Conditional::Modified { body, etag } => {
+    ///         println!("updated: {} (etag {:?})", body.pattern.name, etag);
+    ///     }
+    ///     Conditional::NotModified { .. } => println!("unchanged"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn show_conditional(
+        &self,
+        id: u64,
+        etag: Option<&str>,
+    ) -> Result<Conditional<PatternShowResponse>, RavelryError> {
+        let path = format!("patterns/{id}.json");
+        let req = self.client.get(&path)?;
+        self.client.send_json_conditional(req, etag).await
+    }
+
+    /// Get a compact summary of a pattern, for link previews and cards.
+    ///
+    /// This fetches the full pattern and projects it down with
+    /// [`PatternFull::summary`]; use [`show`](Self::show) if you need
+    /// anything beyond the summary fields.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let summary = client.patterns().summary(123456).await?;
+    /// println!("{} by {:?}", summary.name, summary.designer_name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn summary(&self, id: u64) -> Result<PatternSummary, RavelryError> {
+        Ok(self.show(id).await?.pattern.summary())
+    }
+
     /// Get projects made from a pattern.
     ///
     /// # Example
@@ -90,11 +282,27 @@ impl<'a> PatternsApi<'a> {
         params: &PatternProjectsParams,
     ) -> Result<PatternProjectsResponse, RavelryError> {
         let path = format!("patterns/{id}/projects.json");
-        let req = self.client.get(&path).query(params);
+        let req = self.client.get(&path)?.query(params);
         self.client.send_json(req).await
     }
 }
 
+fn fetch_search_page(
+    client: &RavelryClient,
+    mut params: PatternSearchParams,
+    page: u32,
+) -> BoxFuture<'_, Result<Page<PatternList>, RavelryError>> {
+    params.page.page = Some(page);
+    Box::pin(async move {
+        let resp = client.patterns().search(&params).await?;
+        Ok(Page::new(
+            resp.patterns,
+            resp.paginator,
+            page_fetcher(params, fetch_search_page),
+        ))
+    })
+}
+
 /// Parameters for pattern search.
 ///
 /// Use the builder methods to construct search parameters.
@@ -109,16 +317,67 @@ pub struct PatternSearchParams {
     pub page: PageParams,
 
     /// Include personal attributes in the response (requires auth).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        with = "crate::serde_helpers::bool_as_int",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub personal_attributes: Option<bool>,
 
     /// Filter by craft type (e.g., "knitting", "crochet").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub craft: Option<String>,
 
-    /// Sort order (e.g., "best_match", "recently_popular", "date").
+    /// Sort order (e.g., "best", "recently-popular", "date"); see [`PatternSort`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort: Option<String>,
+
+    /// Filter by designer permalink (e.g., "martina-behm").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub designer: Option<String>,
+
+    /// Filter by yarn weight permalinks (e.g., "dk", "worsted"); any match.
+    #[serde(
+        with = "crate::serde_helpers::pipe_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub weight: Vec<String>,
+
+    /// Filter by pattern attribute permalinks (e.g., "seamless").
+    #[serde(
+        rename = "pa",
+        with = "crate::serde_helpers::pipe_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub pattern_attributes: Vec<String>,
+
+    /// Filter by pattern category permalinks (e.g., "hat").
+    #[serde(
+        rename = "pc",
+        with = "crate::serde_helpers::pipe_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub pattern_category: Vec<String>,
+
+    /// Filter by availability (e.g., "free", "ravelry", "online", "inprint").
+    #[serde(
+        with = "crate::serde_helpers::pipe_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub availability: Vec<String>,
+
+    /// Filter by fit (e.g., "adult", "child", "baby", "female", "male").
+    #[serde(
+        with = "crate::serde_helpers::pipe_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub fit: Vec<String>,
+
+    /// Filter by color family permalinks (e.g., "blue-green").
+    #[serde(
+        with = "crate::serde_helpers::pipe_separated",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub color_family: Vec<String>,
 }
 
 impl PatternSearchParams {
@@ -157,11 +416,104 @@ impl PatternSearchParams {
         self
     }
 
-    /// Set the sort order.
+    /// Filter by a typed craft, sent as its permalink.
+    pub fn craft_enum(mut self, craft: Craft) -> Self {
+        self.craft = Some(craft.permalink());
+        self
+    }
+
+    /// Set the sort order as a raw API value.
+    ///
+    /// Prefer [`sort_by`](Self::sort_by); unknown values are silently
+    /// ignored by the API.
     pub fn sort(mut self, sort: impl Into<String>) -> Self {
         self.sort = Some(sort.into());
         self
     }
+
+    /// Set a typed sort order.
+    pub fn sort_by(mut self, sort: PatternSort) -> Self {
+        self.sort = Some(sort.as_str().to_string());
+        self
+    }
+
+    /// Only return patterns by the designer with this permalink.
+    pub fn designer(mut self, permalink: impl Into<String>) -> Self {
+        self.designer = Some(permalink.into());
+        self
+    }
+
+    /// Only return patterns for any of these yarn weight permalinks.
+    pub fn weight<I, S>(mut self, weights: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.weight = weights.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Only return patterns for any of these typed yarn weights.
+    pub fn weight_enums(mut self, weights: impl IntoIterator<Item = YarnWeight>) -> Self {
+        self.weight = weights.into_iter().map(|w| w.permalink()).collect();
+        self
+    }
+
+    /// Only return patterns with these attribute permalinks (`pa`).
+    ///
+    /// See [`ReferenceApi::pattern_attribute_groups`](crate::api::reference::ReferenceApi::pattern_attribute_groups)
+    /// for the available attributes.
+    pub fn pattern_attributes<I, S>(mut self, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.pattern_attributes = attributes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Only return patterns in any of these category permalinks (`pc`).
+    ///
+    /// See [`ReferenceApi::pattern_categories`](crate::api::reference::ReferenceApi::pattern_categories)
+    /// for the available categories.
+    pub fn pattern_category<I, S>(mut self, categories: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.pattern_category = categories.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Only return patterns with any of these availabilities (e.g., "free").
+    pub fn availability<I, S>(mut self, availability: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.availability = availability.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Only return patterns for any of these fits (e.g., "adult", "baby").
+    pub fn fit<I, S>(mut self, fits: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.fit = fits.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Only return patterns in any of these color family permalinks.
+    pub fn color_family<I, S>(mut self, families: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.color_family = families.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 /// Response from pattern search.
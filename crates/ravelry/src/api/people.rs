@@ -0,0 +1,360 @@
+//! People API endpoints.
+//!
+//! Endpoints scoped to a single Ravelry user that don't belong to a more
+//! specific service.
+
+use std::collections::HashMap;
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::pagination::{PageParams, Paginator};
+use crate::types::{CommentFull, UserFull, UserSmall};
+use crate::username::normalize_username;
+
+/// Maximum number of profile requests in flight while resolving users.
+const RESOLVE_CONCURRENCY: usize = 4;
+
+/// Service for people-related API endpoints.
+pub struct PeopleApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> PeopleApi<'a> {
+    /// Get a user's profile.
+    ///
+    /// `user` may be a username or a numeric user ID.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let response = client.people().show("username").await?;
+    /// println!("{} has id {}", response.user.username, response.user.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn show(&self, user: &str) -> Result<PersonShowResponse, RavelryError> {
+        let user = normalize_username(user)?;
+        let path = format!("people/{}.json", user);
+        let req = self.client.get(&path)?;
+        self.client.send_json(req).await
+    }
+
+    /// Search for users by name.
+    ///
+    /// Every user returned is added to the cache used by
+    /// [`resolve_ids`](Self::resolve_ids) and
+    /// [`resolve_usernames`](Self::resolve_usernames).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::people::PeopleSearchParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = PeopleSearchParams::new().query("knitwit").page_size(10);
+    /// let response = client.people().search(&params).await?;
+    /// for user in response.users {
+    ///     println!("{} ({})", user.username, user.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search(
+        &self,
+        params: &PeopleSearchParams,
+    ) -> Result<PeopleSearchResponse, RavelryError> {
+        let req = self.client.get("people/search.json")?.query(params);
+        let response: PeopleSearchResponse = self.client.send_json(req).await?;
+
+        let mut cache = self.client.people_cache();
+        for user in &response.users {
+            cache.insert(user.id, &user.username);
+        }
+
+        Ok(response)
+    }
+
+    /// Resolve usernames to user IDs.
+    ///
+    /// Results are cached on the client, so repeated lookups don't hit the
+    /// API. Uncached users are fetched a few at a time. Usernames that don't
+    /// exist are left out of the returned map.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let ids = client.people().resolve_ids(&["alice", "bob"]).await?;
+    /// if let Some(id) = ids.get("alice") {
+    ///     client.friends().create("myusername", *id).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_ids(
+        &self,
+        usernames: &[&str],
+    ) -> Result<HashMap<String, u64>, RavelryError> {
+        let mut resolved = HashMap::new();
+        let mut missing = Vec::new();
+        {
+            let cache = self.client.people_cache();
+            for &username in usernames {
+                match cache.ids.get(&username.to_lowercase()) {
+                    Some(&id) => {
+                        resolved.insert(username.to_string(), id);
+                    }
+                    None => missing.push(username),
+                }
+            }
+        }
+
+        let fetched = self.fetch_users(missing.iter().copied()).await?;
+        for (username, user) in missing.into_iter().zip(fetched) {
+            if let Some(user) = user {
+                resolved.insert(username.to_string(), user.id);
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Resolve user IDs to usernames.
+    ///
+    /// Shares its cache with [`resolve_ids`](Self::resolve_ids). IDs that
+    /// don't exist are left out of the returned map.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let names = client.people().resolve_usernames(&[12345, 67890]).await?;
+    /// for (id, username) in &names {
+    ///     println!("{id} = {username}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_usernames(
+        &self,
+        ids: &[u64],
+    ) -> Result<HashMap<u64, String>, RavelryError> {
+        let mut resolved = HashMap::new();
+        let mut missing = Vec::new();
+        {
+            let cache = self.client.people_cache();
+            for &id in ids {
+                match cache.usernames.get(&id) {
+                    Some(username) => {
+                        resolved.insert(id, username.clone());
+                    }
+                    None => missing.push(id.to_string()),
+                }
+            }
+        }
+
+        let fetched = self.fetch_users(missing.iter().map(String::as_str)).await?;
+        for user in fetched.into_iter().flatten() {
+            resolved.insert(user.id, user.username);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Fetch profiles with bounded concurrency, caching each one found.
+    ///
+    /// Returns one entry per input, in order; `None` for users that 404.
+    async fn fetch_users<'u>(
+        &self,
+        users: impl Iterator<Item = &'u str>,
+    ) -> Result<Vec<Option<UserFull>>, RavelryError> {
+        let fetched: Vec<Option<UserFull>> = stream::iter(users)
+            .map(|user| async move {
+                match self.show(user).await {
+                    Ok(response) => Ok(Some(response.user)),
+                    Err(RavelryError::ApiStatus { status, .. })
+                        if status == StatusCode::NOT_FOUND =>
+                    {
+                        Ok(None)
+                    }
+                    Err(e) => Err(e),
+                }
+            })
+            .buffered(RESOLVE_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut cache = self.client.people_cache();
+        for user in fetched.iter().flatten() {
+            cache.insert(user.id, &user.username);
+        }
+
+        Ok(fetched)
+    }
+
+    /// List comments written by a user, newest first.
+    ///
+    /// Pair with [`CommentsApi::delete`](crate::api::comments::CommentsApi::delete)
+    /// to clean up old comments.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::people::UserCommentsParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = UserCommentsParams::new().page_size(50);
+    /// let response = client.people().comments("username", &params).await?;
+    /// for comment in response.comments {
+    ///     println!("{}: {:?}", comment.id, comment.body);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn comments(
+        &self,
+        username: &str,
+        params: &UserCommentsParams,
+    ) -> Result<UserCommentsResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/comments/list.json", username);
+        let req = self.client.get(&path)?.query(params);
+        self.client.send_json(req).await
+    }
+}
+
+/// Parameters for people search.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct PeopleSearchParams {
+    /// Free-text search query, matched against usernames and names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// Pagination parameters.
+    #[serde(flatten)]
+    pub page: PageParams,
+
+    /// Sort order (e.g., "best_match", "username").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+impl PeopleSearchParams {
+    /// Create new search params with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the search query.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Set the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.page = Some(page);
+        self
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page.page_size = Some(size);
+        self
+    }
+
+    /// Set the sort order.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+}
+
+/// Response from people search.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PeopleSearchResponse {
+    /// The users matching the search.
+    #[serde(default)]
+    pub users: Vec<UserSmall>,
+
+    /// Pagination information.
+    pub paginator: Paginator,
+}
+
+/// Parameters for listing a user's comments.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct UserCommentsParams {
+    /// Pagination parameters.
+    #[serde(flatten)]
+    pub page: PageParams,
+}
+
+impl UserCommentsParams {
+    /// Create new params with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.page = Some(page);
+        self
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page.page_size = Some(size);
+        self
+    }
+}
+
+/// Response from listing a user's comments.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UserCommentsResponse {
+    /// The comments, newest first.
+    #[serde(default)]
+    pub comments: Vec<CommentFull>,
+
+    /// Pagination information.
+    pub paginator: Paginator,
+}
+
+/// Response from fetching a user's profile.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PersonShowResponse {
+    /// The user's profile.
+    pub user: UserFull,
+}
+
+/// Cache of username/ID pairs used by the resolve helpers.
+#[derive(Debug, Default)]
+pub(crate) struct PeopleCache {
+    /// Lowercased username to ID.
+    ids: HashMap<String, u64>,
+    /// ID to username, as returned by the API.
+    usernames: HashMap<u64, String>,
+    /// Username of the authenticated user, once known.
+    pub(crate) me: Option<String>,
+}
+
+impl PeopleCache {
+    pub(crate) fn insert(&mut self, id: u64, username: &str) {
+        self.ids.insert(username.to_lowercase(), id);
+        self.usernames.insert(id, username.to_string());
+    }
+}
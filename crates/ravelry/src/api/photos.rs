@@ -0,0 +1,130 @@
+//! Photos API endpoints.
+//!
+//! Photos belong to a parent such as a project. List them through the
+//! parent (e.g. [`ProjectsApi::photos`](crate::api::projects::ProjectsApi::photos));
+//! this service edits them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::types::{PhotoFull, PhotoPost};
+use crate::username::normalize_username;
+
+/// Service for photo endpoints.
+pub struct PhotosApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> PhotosApi<'a> {
+    /// Update a photo's caption or copyright holder.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::PhotoPost;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let update = PhotoPost::new().caption("Blocking the lace panel");
+    /// let response = client.photos().update(123, &update).await?;
+    /// println!("{:?}", response.photo.caption);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(
+        &self,
+        id: u64,
+        data: &PhotoPost,
+    ) -> Result<PhotoUpdateResponse, RavelryError> {
+        let path = format!("photos/{}.json", id);
+        let req = self.client.post_data(&path, data)?;
+        self.client.send_json(req).await
+    }
+
+    /// Set the order of a parent's photos.
+    ///
+    /// `ordered_ids` lists the parent's photo IDs, first photo first. Ravelry
+    /// expects every photo of the parent to be listed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::photos::PhotoParent;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let parent = PhotoParent::project("username", 123);
+    /// client.photos().set_sort_order(&parent, &[30, 10, 20]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_sort_order(
+        &self,
+        parent: &PhotoParent,
+        ordered_ids: &[u64],
+    ) -> Result<PhotoReorderResponse, RavelryError> {
+        #[derive(Serialize)]
+        struct Body {
+            sort_order: String,
+        }
+
+        let path = parent.reorder_path()?;
+        let sort_order = ordered_ids
+            .iter()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let req = self.client.post(&path)?.json(&Body { sort_order });
+        self.client.send_json(req).await
+    }
+}
+
+/// The object that owns a set of photos.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PhotoParent {
+    /// A user's project.
+    Project {
+        /// The project owner's username.
+        username: String,
+        /// The project ID.
+        id: u64,
+    },
+}
+
+impl PhotoParent {
+    /// The project `id` owned by `username`.
+    pub fn project(username: impl Into<String>, id: u64) -> Self {
+        Self::Project {
+            username: username.into(),
+            id,
+        }
+    }
+
+    fn reorder_path(&self) -> Result<String, RavelryError> {
+        match self {
+            Self::Project { username, id } => {
+                let username = normalize_username(username)?;
+                Ok(format!("projects/{}/{}/reorder_photos.json", username, id))
+            }
+        }
+    }
+}
+
+/// Response from updating a photo.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PhotoUpdateResponse {
+    /// The updated photo.
+    pub photo: PhotoFull,
+}
+
+/// Response from reordering photos.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PhotoReorderResponse {
+    /// The parent's photos in their new order, if the API returned them.
+    #[serde(default)]
+    pub photos: Vec<PhotoFull>,
+}
@@ -2,12 +2,14 @@
 //!
 //! Projects are knitting/crochet items that users are working on or have completed.
 
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use crate::client::RavelryClient;
 use crate::error::RavelryError;
 use crate::pagination::{PageParams, Paginator};
-use crate::types::{ProjectFull, ProjectPost, ProjectSmall};
+use crate::types::{Craft, PhotoFull, ProjectFull, ProjectPost, ProjectSmall, ProjectState};
+use crate::username::normalize_username;
 
 /// Service for project-related API endpoints.
 pub struct ProjectsApi<'a> {
@@ -17,6 +19,10 @@ pub struct ProjectsApi<'a> {
 impl<'a> ProjectsApi<'a> {
     /// List a user's projects.
     ///
+    /// If `params` has no sort and the client was built with
+    /// [`default_project_sort`](crate::RavelryClientBuilder::default_project_sort),
+    /// that sort is used.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -38,8 +44,68 @@ impl<'a> ProjectsApi<'a> {
         username: &str,
         params: &ProjectsListParams,
     ) -> Result<ProjectsListResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("projects/{}/list.json", username);
-        let req = self.client.get(&path).query(params);
+        let mut req = self.client.get(&path)?.query(params);
+        if params.sort.is_none() {
+            if let Some(sort) = &self.client.defaults().default_project_sort {
+                req = req.query(&[("sort", sort)]);
+            }
+        }
+        self.client.send_json(req).await
+    }
+
+    /// List the authenticated user's projects.
+    ///
+    /// The username is resolved with
+    /// [`RootApi::resolve_username`](crate::api::root::RootApi::resolve_username),
+    /// so only the first call costs an extra request.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let response = client.projects().list_me(&Default::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_me(
+        &self,
+        params: &ProjectsListParams,
+    ) -> Result<ProjectsListResponse, RavelryError> {
+        let username = self.client.root().resolve_username(None).await?;
+        self.list(&username, params).await
+    }
+
+    /// Search all public projects.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::projects::ProjectSearchParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = ProjectSearchParams::new()
+    ///     .query("fair isle")
+    ///     .status("finished")
+    ///     .page_size(10);
+    ///
+    /// let response = client.projects().search(&params).await?;
+    /// for project in response.projects {
+    ///     println!("{}: {}", project.id, project.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search(
+        &self,
+        params: &ProjectSearchParams,
+    ) -> Result<ProjectsSearchResponse, RavelryError> {
+        let req = self.client.get("projects/search.json")?.query(params);
         self.client.send_json(req).await
     }
 
@@ -64,11 +130,106 @@ impl<'a> ProjectsApi<'a> {
         id: &str,
         params: &ProjectShowParams,
     ) -> Result<ProjectShowResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("projects/{}/{}.json", username, id);
-        let req = self.client.get(&path).query(params);
+        let req = self.client.get(&path)?.query(params);
         self.client.send_json(req).await
     }
 
+    /// Fetch a project, distinguishing deleted projects from missing ones.
+    ///
+    /// Unlike [`show`](Self::show), a missing project isn't an error:
+    ///
+    /// - a project returned with `deleted: true`, or a 410 Gone response,
+    ///   yields [`ProjectState::Deleted`];
+    /// - a 404 yields [`ProjectState::NotFound`];
+    /// - anything else that succeeds yields [`ProjectState::Active`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::ProjectState;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// match client.projects().state("username", "my-sweater").await? {
+    ///     ProjectState::Active(project) => println!("{}", project.name),
+    ///     ProjectState::Deleted => println!("deleted"),
+    ///     ProjectState::NotFound => println!("never existed"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn state(&self, username: &str, id: &str) -> Result<ProjectState, RavelryError> {
+        match self.show(username, id, &ProjectShowParams::new()).await {
+            Ok(response) if response.project.deleted == Some(true) => Ok(ProjectState::Deleted),
+            Ok(response) => Ok(ProjectState::Active(Box::new(response.project))),
+            Err(RavelryError::ApiStatus { status, .. }) if status == StatusCode::GONE => {
+                Ok(ProjectState::Deleted)
+            }
+            Err(RavelryError::ApiStatus { status, .. }) if status == StatusCode::NOT_FOUND => {
+                Ok(ProjectState::NotFound)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the number of comments on a project.
+    ///
+    /// This reads `comments_count` from a plain show request, without
+    /// `include=comments`, so the comment bodies aren't transferred. The API
+    /// has no dedicated count endpoint, so this still costs one request per
+    /// project; prefer [`ProjectSmall::comments_count`] from a list response
+    /// when it is present.
+    ///
+    /// Returns `None` if the API omits the count.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// if let Some(count) = client.projects().comments_count("username", "1").await? {
+    ///     println!("{} comments", count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn comments_count(
+        &self,
+        username: &str,
+        id: &str,
+    ) -> Result<Option<u64>, RavelryError> {
+        let response = self.show(username, id, &ProjectShowParams::new()).await?;
+        Ok(response.project.comments_count)
+    }
+
+    /// Get a project's photos, sorted by `sort_order`.
+    ///
+    /// Ravelry has no separate photo listing for projects; the photo set
+    /// is part of the project itself, so this costs one show request.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// for photo in client.projects().photos("username", "my-sweater").await? {
+    ///     println!("{:?}: {:?}", photo.caption, photo.medium_url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn photos(&self, username: &str, id: &str) -> Result<Vec<PhotoFull>, RavelryError> {
+        let response = self.show(username, id, &ProjectShowParams::new()).await?;
+        let mut photos = response.project.photos;
+        photos.sort_by_key(|photo| photo.sort_order.unwrap_or(i32::MAX));
+        Ok(photos)
+    }
+
     /// Create a new project.
     ///
     /// # Example
@@ -93,8 +254,9 @@ impl<'a> ProjectsApi<'a> {
         username: &str,
         data: &ProjectPost,
     ) -> Result<ProjectCreateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("projects/{}/create.json", username);
-        let req = self.client.post_data(&path, data);
+        let req = self.client.post_data(&path, data)?;
         self.client.send_json(req).await
     }
 
@@ -120,8 +282,9 @@ impl<'a> ProjectsApi<'a> {
         id: u64,
         data: &ProjectPost,
     ) -> Result<ProjectUpdateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("projects/{}/{}.json", username, id);
-        let req = self.client.post_data(&path, data);
+        let req = self.client.post_data(&path, data)?;
         self.client.send_json(req).await
     }
 
@@ -143,8 +306,9 @@ impl<'a> ProjectsApi<'a> {
         username: &str,
         id: u64,
     ) -> Result<ProjectDeleteResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("projects/{}/{}.json", username, id);
-        let req = self.client.delete(&path);
+        let req = self.client.delete(&path)?;
         self.client.send_json(req).await
     }
 }
@@ -200,6 +364,91 @@ pub struct ProjectsListResponse {
     pub paginator: Paginator,
 }
 
+/// Parameters for searching all public projects.
+///
+/// Use the builder methods to construct search parameters.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct ProjectSearchParams {
+    /// Free-text search query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// Pagination parameters.
+    #[serde(flatten)]
+    pub page: PageParams,
+
+    /// Filter by craft type (e.g., "knitting", "crochet").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub craft: Option<String>,
+
+    /// Filter by project status (e.g., "finished", "in-progress").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Sort order (e.g., "best", "completed", "started").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+}
+
+impl ProjectSearchParams {
+    /// Create new search params with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the search query.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Set the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.page = Some(page);
+        self
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page.page_size = Some(size);
+        self
+    }
+
+    /// Filter by craft type (e.g., "knitting", "crochet").
+    pub fn craft(mut self, craft: impl Into<String>) -> Self {
+        self.craft = Some(craft.into());
+        self
+    }
+
+    /// Filter by a typed craft, sent as its permalink.
+    pub fn craft_enum(mut self, craft: Craft) -> Self {
+        self.craft = Some(craft.permalink());
+        self
+    }
+
+    /// Filter by project status (e.g., "finished").
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// Set the sort order.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+}
+
+/// Response from searching projects.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectsSearchResponse {
+    /// The list of projects matching the search.
+    pub projects: Vec<ProjectSmall>,
+
+    /// Pagination information.
+    pub paginator: Paginator,
+}
+
 /// Parameters for showing a single project.
 #[derive(Serialize, Default, Debug, Clone)]
 pub struct ProjectShowParams {
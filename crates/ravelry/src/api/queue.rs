@@ -0,0 +1,307 @@
+//! Queue API endpoints.
+//!
+//! The queue is a user's list of patterns they plan to make.
+
+use std::collections::HashMap;
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::pagination::{collect_all_pages, PageParams, Paginator};
+use crate::types::{PatternFull, QueueFull, QueueList, QueuePost, YarnRequirement, YarnWeight};
+use crate::username::normalize_username;
+
+/// Page size used when walking the whole queue.
+const QUEUE_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of pattern requests in flight while building a shopping list.
+const PATTERN_CONCURRENCY: usize = 4;
+
+/// Service for queue-related API endpoints.
+pub struct QueueApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> QueueApi<'a> {
+    /// List the patterns in a user's queue.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::queue::QueueListParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let params = QueueListParams::new().page_size(25);
+    /// let response = client.queue().list("username", &params).await?;
+    /// for entry in response.queued_projects {
+    ///     println!("{:?}", entry.pattern_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(
+        &self,
+        username: &str,
+        params: &QueueListParams,
+    ) -> Result<QueueListResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/queue/list.json", username);
+        let req = self.client.get(&path)?.query(params);
+        self.client.send_json(req).await
+    }
+
+    /// Get a single queue entry.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let response = client.queue().show("username", 123).await?;
+    /// println!("{:?}", response.queued_project.notes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn show(&self, username: &str, id: u64) -> Result<QueueShowResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/queue/{}.json", username, id);
+        let req = self.client.get(&path)?;
+        self.client.send_json(req).await
+    }
+
+    /// Add a pattern to a user's queue.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::QueuePost;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let entry = QueuePost::new()
+    ///     .pattern_id(12345)
+    ///     .notes("Use the leftover merino");
+    ///
+    /// let response = client.queue().create("username", &entry).await?;
+    /// println!("Queued as entry {}", response.queued_project.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        username: &str,
+        data: &QueuePost,
+    ) -> Result<QueueCreateResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/queue/create.json", username);
+        let req = self.client.post_data(&path, data)?;
+        self.client.send_json(req).await
+    }
+
+    /// Update a queue entry.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::QueuePost;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// // Move the entry to the top of the queue
+    /// let update = QueuePost::new().sort_order(1);
+    /// client.queue().update("username", 123, &update).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(
+        &self,
+        username: &str,
+        id: u64,
+        data: &QueuePost,
+    ) -> Result<QueueUpdateResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/queue/{}.json", username, id);
+        let req = self.client.post_data(&path, data)?;
+        self.client.send_json(req).await
+    }
+
+    /// Remove an entry from a user's queue.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let response = client.queue().delete("username", 123).await?;
+    /// println!("Removed entry {}", response.queued_project.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(
+        &self,
+        username: &str,
+        id: u64,
+    ) -> Result<QueueDeleteResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/queue/{}.json", username, id);
+        let req = self.client.delete(&path)?;
+        self.client.send_json(req).await
+    }
+
+    /// Aggregate the yarn needed for every pattern in a user's queue.
+    ///
+    /// Yardage is summed per yarn weight category, using each pattern's
+    /// largest size ([`PatternFull::yardage_max`], falling back to
+    /// [`PatternFull::yardage`]). Queue entries without a linked pattern, and
+    /// patterns without yardage, are skipped. Requirements are returned in
+    /// the order their weight first appears in the queue.
+    ///
+    /// # Cost
+    ///
+    /// This makes one request per 100 queue entries, plus one pattern request
+    /// per distinct queued pattern (up to four in flight at once). A queue of
+    /// 200 different patterns costs around 202 requests, so cache the result
+    /// rather than calling this on every page view.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// for need in client.queue().shopping_list("username").await? {
+    ///     println!("{:?}: {} yards", need.yarn_weight, need.yardage);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shopping_list(
+        &self,
+        username: &str,
+    ) -> Result<Vec<YarnRequirement>, RavelryError> {
+        let queue = collect_all_pages(QUEUE_PAGE_SIZE, None, |page| {
+            let params = QueueListParams { page };
+            async move {
+                let resp = self.list(username, &params).await?;
+                Ok((resp.queued_projects, resp.paginator))
+            }
+        })
+        .await?;
+
+        let pattern_ids: Vec<u64> = queue.iter().filter_map(|entry| entry.pattern_id).collect();
+
+        let mut unique = pattern_ids.clone();
+        unique.sort_unstable();
+        unique.dedup();
+
+        let patterns: HashMap<u64, PatternFull> = stream::iter(unique)
+            .map(|id| async move {
+                let pattern = self.client.patterns().show(id).await?.pattern;
+                Ok::<_, RavelryError>((id, pattern))
+            })
+            .buffer_unordered(PATTERN_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut requirements: Vec<YarnRequirement> = Vec::new();
+        for id in pattern_ids {
+            let pattern = &patterns[&id];
+            let Some(yardage) = pattern.yardage_max.or(pattern.yardage) else {
+                continue;
+            };
+            let weight = pattern
+                .yarn_weight
+                .as_ref()
+                .and_then(|weight| weight.name.as_deref())
+                .map(YarnWeight::from);
+
+            match requirements.iter_mut().find(|r| r.yarn_weight == weight) {
+                Some(requirement) => {
+                    requirement.yardage += yardage;
+                    requirement.pattern_ids.push(id);
+                }
+                None => requirements.push(YarnRequirement {
+                    yarn_weight: weight,
+                    yardage,
+                    pattern_ids: vec![id],
+                }),
+            }
+        }
+
+        Ok(requirements)
+    }
+}
+
+/// Parameters for listing a user's queue.
+#[derive(Serialize, Default, Debug, Clone)]
+pub struct QueueListParams {
+    /// Pagination parameters.
+    #[serde(flatten)]
+    pub page: PageParams,
+}
+
+impl QueueListParams {
+    /// Create new params with defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.page = Some(page);
+        self
+    }
+
+    /// Set the page size.
+    pub fn page_size(mut self, size: u32) -> Self {
+        self.page.page_size = Some(size);
+        self
+    }
+}
+
+/// Response from listing a user's queue.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QueueListResponse {
+    /// The queued patterns, in queue order.
+    #[serde(default)]
+    pub queued_projects: Vec<QueueList>,
+
+    /// Pagination information.
+    pub paginator: Paginator,
+}
+
+/// Response from showing a single queue entry.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QueueShowResponse {
+    /// The queue entry details.
+    pub queued_project: QueueFull,
+}
+
+/// Response from creating a queue entry.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QueueCreateResponse {
+    /// The created queue entry.
+    pub queued_project: QueueFull,
+}
+
+/// Response from updating a queue entry.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QueueUpdateResponse {
+    /// The updated queue entry.
+    pub queued_project: QueueFull,
+}
+
+/// Response from deleting a queue entry.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QueueDeleteResponse {
+    /// The deleted queue entry.
+    pub queued_project: QueueFull,
+}
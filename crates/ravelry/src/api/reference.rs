@@ -0,0 +1,165 @@
+//! Reference data API endpoints.
+//!
+//! Needle sizes, yarn weights, color families, and the pattern category and
+//! attribute taxonomies rarely change, so they suit populating pickers and
+//! search facets without hardcoding values. Pair them with an
+//! [`EtagCache`](crate::etag::EtagCache) to avoid re-downloading them.
+
+use serde::Deserialize;
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::types::{ColorFamily, Needle, PatternAttributeGroup, PatternCategory, YarnWeightInfo};
+
+/// Service for reference data endpoints.
+pub struct ReferenceApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> ReferenceApi<'a> {
+    /// List all needle and hook sizes.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// for needle in client.reference().needles().await? {
+    ///     println!("{}: {:?}", needle.id, needle.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn needles(&self) -> Result<Vec<Needle>, RavelryError> {
+        let req = self.client.get("needles/sizes.json")?;
+        let response: NeedleSizesResponse = self.client.send_json(req).await?;
+        Ok(response.needle_sizes)
+    }
+
+    /// List all yarn weights with their typical gauges.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// for weight in client.reference().yarn_weights().await? {
+    ///     println!("{:?}: {:?}", weight.name, weight.knit_gauge);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn yarn_weights(&self) -> Result<Vec<YarnWeightInfo>, RavelryError> {
+        let req = self.client.get("yarn_weights.json")?;
+        let response: YarnWeightsResponse = self.client.send_json(req).await?;
+        Ok(response.yarn_weights)
+    }
+
+    /// List all color families.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// for family in client.reference().color_families().await? {
+    ///     println!("{:?}: {:?}", family.name, family.color);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn color_families(&self) -> Result<Vec<ColorFamily>, RavelryError> {
+        let req = self.client.get("color_families.json")?;
+        let response: ColorFamiliesResponse = self.client.send_json(req).await?;
+        Ok(response.color_families)
+    }
+
+    /// Fetch the pattern category tree.
+    ///
+    /// Returns the top-level categories (e.g. "Clothing", "Accessories");
+    /// each holds its subcategories in `children`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// for category in client.reference().pattern_categories().await? {
+    ///     println!("{} ({} subcategories)", category.name, category.children.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn pattern_categories(&self) -> Result<Vec<PatternCategory>, RavelryError> {
+        let req = self.client.get("pattern_categories/list.json")?;
+        let response: PatternCategoriesResponse = self.client.send_json(req).await?;
+        Ok(match response.pattern_categories {
+            CategoryTree::Root(root) => root.children,
+            CategoryTree::List(categories) => categories,
+        })
+    }
+
+    /// Fetch the pattern attribute groups.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// for group in client.reference().pattern_attribute_groups().await? {
+    ///     println!("{}: {} attributes", group.name, group.pattern_attributes.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn pattern_attribute_groups(
+        &self,
+    ) -> Result<Vec<PatternAttributeGroup>, RavelryError> {
+        let req = self.client.get("pattern_attributes/groups.json")?;
+        let response: AttributeGroupsResponse = self.client.send_json(req).await?;
+        Ok(response.attribute_groups)
+    }
+}
+
+#[derive(Deserialize)]
+struct NeedleSizesResponse {
+    #[serde(default)]
+    needle_sizes: Vec<Needle>,
+}
+
+#[derive(Deserialize)]
+struct YarnWeightsResponse {
+    #[serde(default)]
+    yarn_weights: Vec<YarnWeightInfo>,
+}
+
+#[derive(Deserialize)]
+struct ColorFamiliesResponse {
+    #[serde(default)]
+    color_families: Vec<ColorFamily>,
+}
+
+#[derive(Deserialize)]
+struct PatternCategoriesResponse {
+    pattern_categories: CategoryTree,
+}
+
+/// The category list is served as a single unnamed root node; accept a bare
+/// list as well.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CategoryTree {
+    Root(PatternCategory),
+    List(Vec<PatternCategory>),
+}
+
+#[derive(Deserialize)]
+struct AttributeGroupsResponse {
+    #[serde(default)]
+    attribute_groups: Vec<PatternAttributeGroup>,
+}
@@ -2,11 +2,23 @@
 //!
 //! These are endpoints that don't belong to a specific resource category.
 
+use futures::stream::{self, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 
+use crate::api::friends::FriendsActivityParams;
+use crate::api::messages::{MessageFolder, MessagesListParams};
+use crate::api::patterns::PatternSearchParams;
+use crate::api::yarns::YarnSearchParams;
 use crate::client::RavelryClient;
 use crate::error::RavelryError;
-use crate::types::UserFull;
+use crate::pagination::paginate;
+use crate::types::{PatternList, UserFull, YarnList};
+
+/// Page size used by [`RootApi::search_stream`] when the params don't set one.
+const DEFAULT_STREAM_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of friend activity items [`RootApi::dashboard`] counts.
+const DASHBOARD_ACTIVITY_LIMIT: u32 = 50;
 
 /// Service for root-level API endpoints.
 pub struct RootApi<'a> {
@@ -31,9 +43,213 @@ impl<'a> RootApi<'a> {
     /// # }
     /// ```
     pub async fn current_user(&self) -> Result<CurrentUserResponse, RavelryError> {
-        let req = self.client.get("current_user.json");
-        self.client.send_json(req).await
+        let req = self.client.get("current_user.json")?;
+        let response: CurrentUserResponse = self.client.send_json(req).await?;
+
+        let mut cache = self.client.people_cache();
+        cache.insert(response.user.id, &response.user.username);
+        cache.me = Some(response.user.username.clone());
+
+        Ok(response)
+    }
+
+    /// Resolve an optional username, defaulting to the authenticated user.
+    ///
+    /// `Some(name)` is returned as-is. `None` resolves to the current user's
+    /// username, which is fetched with [`current_user`](Self::current_user)
+    /// on first use and cached on the client afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let me = client.root().resolve_username(None).await?;
+    /// let them = client.root().resolve_username(Some("someone")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_username(&self, user: Option<&str>) -> Result<String, RavelryError> {
+        if let Some(user) = user {
+            return Ok(user.to_string());
+        }
+        if let Some(me) = self.client.people_cache().me.clone() {
+            return Ok(me);
+        }
+        Ok(self.current_user().await?.user.username)
+    }
+
+    /// Fetch badge counts for a "home screen" in one call.
+    ///
+    /// Ravelry has no summary endpoint, so this makes two cheap requests
+    /// concurrently: a one-item page of unread inbox messages (for its
+    /// result count) and the friend activity feed. Resolving the current
+    /// username adds a third request on first use.
+    ///
+    /// Activity counts as new if its ID is greater than `last_seen_activity`.
+    /// Pass the previous [`Dashboard::latest_activity_id`] to count only what
+    /// arrived since the last call; with `None`, the most recent items count
+    /// as new. At most 50 new items are counted.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let dashboard = client.root().dashboard(None).await?;
+    /// println!("{} unread messages", dashboard.unread_messages);
+    ///
+    /// // Later, count only activity since the first call
+    /// let later = client.root().dashboard(dashboard.latest_activity_id).await?;
+    /// println!("{} new activity items", later.new_activity);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dashboard(
+        &self,
+        last_seen_activity: Option<u64>,
+    ) -> Result<Dashboard, RavelryError> {
+        let username = self.resolve_username(None).await?;
+
+        let unread = MessagesListParams::new()
+            .folder(MessageFolder::Inbox)
+            .unread_only(true)
+            .page_size(1);
+        let mut activity = FriendsActivityParams::new().page_size(DASHBOARD_ACTIVITY_LIMIT);
+        if let Some(id) = last_seen_activity {
+            activity = activity.since(id);
+        }
+
+        let (messages_api, friends_api) = (self.client.messages(), self.client.friends());
+        let (messages, activity) = futures::try_join!(
+            messages_api.list(&unread),
+            friends_api.activity(&username, &activity),
+        )?;
+
+        let new_activity = activity
+            .activity
+            .iter()
+            .filter(|item| match (item.id, last_seen_activity) {
+                (Some(id), Some(seen)) => id > seen,
+                _ => true,
+            })
+            .count() as u32;
+        let latest_activity_id = activity
+            .activity
+            .iter()
+            .filter_map(|item| item.id)
+            .chain(last_seen_activity)
+            .max();
+
+        Ok(Dashboard {
+            username,
+            unread_messages: messages.paginator.results,
+            new_activity,
+            latest_activity_id,
+        })
     }
+
+    /// Search patterns and yarns concurrently, merged into a single stream.
+    ///
+    /// Both searches are paginated lazily and polled together; results are
+    /// interleaved as pages arrive, alternating between sources when both
+    /// have items ready. Each source keeps its own relevance order, so the
+    /// merged stream is roughly ordered by relevance rank. The stream ends
+    /// once both searches are exhausted.
+    ///
+    /// The `page` field of each params struct sets the page size; searches
+    /// always start from the first page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use futures::StreamExt;
+    /// use ravelry::api::{patterns::PatternSearchParams, root::SearchHit, yarns::YarnSearchParams};
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let patterns = PatternSearchParams::new().query("merino");
+    /// let yarns = YarnSearchParams::new().query("merino");
+    ///
+    /// let hits = client.root().search_stream(&patterns, &yarns).take(20);
+    /// let mut hits = std::pin::pin!(hits);
+    /// while let Some(hit) = hits.next().await {
+    ///     match hit? {
+    ///         SearchHit::Pattern(p) => println!("pattern: {}", p.name),
+    ///         SearchHit::Yarn(y) => println!("yarn: {}", y.name),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_stream(
+        &self,
+        patterns: &PatternSearchParams,
+        yarns: &YarnSearchParams,
+    ) -> impl Stream<Item = Result<SearchHit, RavelryError>> + 'a {
+        let client = self.client;
+
+        let pattern_params = patterns.clone();
+        let pattern_size = pattern_params
+            .page
+            .page_size
+            .unwrap_or(DEFAULT_STREAM_PAGE_SIZE);
+        let patterns = paginate(pattern_size, None, move |page| {
+            let mut params = pattern_params.clone();
+            params.page = page;
+            async move {
+                let resp = client.patterns().search(&params).await?;
+                Ok((resp.patterns, resp.paginator))
+            }
+        })
+        .map(|hit| hit.map(SearchHit::Pattern));
+
+        let yarn_params = yarns.clone();
+        let yarn_size = yarn_params
+            .page
+            .page_size
+            .unwrap_or(DEFAULT_STREAM_PAGE_SIZE);
+        let yarns = paginate(yarn_size, None, move |page| {
+            let mut params = yarn_params.clone();
+            params.page = page;
+            async move {
+                let resp = client.yarns().search(&params).await?;
+                Ok((resp.yarns, resp.paginator))
+            }
+        })
+        .map(|hit| hit.map(SearchHit::Yarn));
+
+        stream::select(patterns, yarns)
+    }
+}
+
+/// A single result from [`RootApi::search_stream`].
+#[derive(Debug, Clone)]
+pub enum SearchHit {
+    /// A pattern search result.
+    Pattern(PatternList),
+    /// A yarn search result.
+    Yarn(YarnList),
+}
+
+/// Badge counts returned by [`RootApi::dashboard`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Dashboard {
+    /// The authenticated user's username.
+    pub username: String,
+
+    /// Number of unread messages in the inbox.
+    pub unread_messages: u32,
+
+    /// Number of new friend activity items (at most 50).
+    pub new_activity: u32,
+
+    /// Highest friend activity ID seen so far, to pass to the next call.
+    #[serde(default)]
+    pub latest_activity_id: Option<u64>,
 }
 
 /// Response from `GET /current_user.json`.
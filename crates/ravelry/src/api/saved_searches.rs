@@ -0,0 +1,108 @@
+//! Saved searches API endpoints.
+//!
+//! Saved searches are named sets of search filters a user keeps on Ravelry.
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::types::{SavedSearch, SavedSearchPost};
+use crate::username::normalize_username;
+
+/// Service for saved search API endpoints.
+pub struct SavedSearchesApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> SavedSearchesApi<'a> {
+    /// List a user's saved searches.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let response = client.saved_searches().list("username").await?;
+    /// for search in response.saved_searches {
+    ///     println!("{:?}: {:?}", search.name, search.query_string);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self, username: &str) -> Result<SavedSearchesListResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/saved_searches/list.json", username);
+        let req = self.client.get(&path)?;
+        self.client.send_json(req).await
+    }
+
+    /// Save a search.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::SavedSearchPost;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let search = SavedSearchPost::new()
+    ///     .name("DK cardigans")
+    ///     .search_type("patterns")
+    ///     .query_string("pc=cardigan&weight=dk");
+    ///
+    /// let response = client.saved_searches().create("username", &search).await?;
+    /// println!("Saved search: {}", response.saved_search.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        username: &str,
+        data: &SavedSearchPost,
+    ) -> Result<SavedSearchMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/saved_searches/create.json", username);
+        let req = self.client.post_data(&path, data)?;
+        self.client.send_json(req).await
+    }
+
+    /// Delete a saved search.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// client.saved_searches().delete("username", 12345).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(
+        &self,
+        username: &str,
+        id: u64,
+    ) -> Result<SavedSearchMutateResponse, RavelryError> {
+        let username = normalize_username(username)?;
+        let path = format!("people/{}/saved_searches/{}.json", username, id);
+        let req = self.client.delete(&path)?;
+        self.client.send_json(req).await
+    }
+}
+
+/// Response from listing saved searches.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SavedSearchesListResponse {
+    /// The user's saved searches.
+    #[serde(default)]
+    pub saved_searches: Vec<SavedSearch>,
+}
+
+/// Response from mutating a saved search (create/delete).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SavedSearchMutateResponse {
+    /// The mutated saved search.
+    pub saved_search: SavedSearch,
+}
@@ -8,6 +8,7 @@ use crate::client::RavelryClient;
 use crate::error::RavelryError;
 use crate::pagination::{PageParams, Paginator};
 use crate::types::{StashFull, StashPost, StashSmall};
+use crate::username::normalize_username;
 
 /// Service for stash-related API endpoints.
 pub struct StashApi<'a> {
@@ -38,8 +39,9 @@ impl<'a> StashApi<'a> {
         username: &str,
         params: &StashListParams,
     ) -> Result<StashListResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/stash/list.json", username);
-        let req = self.client.get(&path).query(params);
+        let req = self.client.get(&path)?.query(params);
         self.client.send_json(req).await
     }
 
@@ -59,8 +61,9 @@ impl<'a> StashApi<'a> {
     /// # }
     /// ```
     pub async fn show(&self, username: &str, id: &str) -> Result<StashShowResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/stash/{}.json", username, id);
-        let req = self.client.get(&path);
+        let req = self.client.get(&path)?;
         self.client.send_json(req).await
     }
 
@@ -89,8 +92,9 @@ impl<'a> StashApi<'a> {
         username: &str,
         data: &StashPost,
     ) -> Result<StashCreateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/stash/create.json", username);
-        let req = self.client.post_data(&path, data);
+        let req = self.client.post_data(&path, data)?;
         self.client.send_json(req).await
     }
 
@@ -115,8 +119,9 @@ impl<'a> StashApi<'a> {
         id: u64,
         data: &StashPost,
     ) -> Result<StashUpdateResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/stash/{}.json", username, id);
-        let req = self.client.post_data(&path, data);
+        let req = self.client.post_data(&path, data)?;
         self.client.send_json(req).await
     }
 
@@ -137,8 +142,9 @@ impl<'a> StashApi<'a> {
         username: &str,
         id: u64,
     ) -> Result<StashDeleteResponse, RavelryError> {
+        let username = normalize_username(username)?;
         let path = format!("people/{}/stash/{}.json", username, id);
-        let req = self.client.delete(&path);
+        let req = self.client.delete(&path)?;
         self.client.send_json(req).await
     }
 }
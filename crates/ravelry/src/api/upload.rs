@@ -4,6 +4,9 @@
 //! 1. Request an upload token via `request_token()`
 //! 2. Upload images via `image()` (multipart, unauthenticated)
 //! 3. Check status via `image_status()`
+//!
+//! [`UploadSession`](crate::types::UploadSession) drives these steps and can
+//! be saved and resumed if the process stops part way through.
 
 use reqwest::multipart::{Form, Part};
 
@@ -38,7 +41,7 @@ impl<'a> UploadApi<'a> {
     /// # }
     /// ```
     pub async fn request_token(&self) -> Result<UploadRequestTokenResponse, RavelryError> {
-        let req = self.client.post("upload/request_token.json");
+        let req = self.client.post("upload/request_token.json")?;
         self.client.send_json(req).await
     }
 
@@ -121,7 +124,7 @@ impl<'a> UploadApi<'a> {
         // Use unauthenticated POST for upload
         let req = self
             .client
-            .post_no_auth("upload/image.json")
+            .post_no_auth("upload/image.json")?
             .multipart(form);
         self.client.send_json(req).await
     }
@@ -150,7 +153,7 @@ impl<'a> UploadApi<'a> {
     ) -> Result<UploadStatusResponse, RavelryError> {
         let req = self
             .client
-            .get_no_auth("upload/image/status.json")
+            .get_no_auth("upload/image/status.json")?
             .query(&[("upload_token", upload_token)]);
         self.client.send_json(req).await
     }
@@ -0,0 +1,44 @@
+//! Volume API endpoints.
+//!
+//! A volume is a publication on Ravelry: a book, magazine, booklet, or single
+//! pattern. Volumes in a user's library are listed by
+//! [`LibraryApi::search`](crate::api::library::LibraryApi::search).
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+use crate::types::VolumeFull;
+
+/// Service for volume endpoints.
+pub struct VolumesApi<'a> {
+    pub(crate) client: &'a RavelryClient,
+}
+
+impl<'a> VolumesApi<'a> {
+    /// Get details for a volume, including the patterns it contains.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let volume = client.volumes().show(12345).await?.volume;
+    /// println!("{:?}: {} patterns", volume.title, volume.patterns.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn show(&self, id: u64) -> Result<VolumeShowResponse, RavelryError> {
+        let path = format!("volumes/{}.json", id);
+        let req = self.client.get(&path)?;
+        self.client.send_json(req).await
+    }
+}
+
+/// Response from showing a single volume.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VolumeShowResponse {
+    /// The volume details.
+    pub volume: VolumeFull,
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::client::RavelryClient;
 use crate::error::RavelryError;
 use crate::pagination::{PageParams, Paginator};
-use crate::types::{YarnFull, YarnList};
+use crate::types::{Shop, YarnFull, YarnList, YarnSort};
 
 /// Service for yarn-related API endpoints.
 pub struct YarnsApi<'a> {
@@ -40,7 +40,7 @@ impl<'a> YarnsApi<'a> {
         &self,
         params: &YarnSearchParams,
     ) -> Result<YarnsSearchResponse, RavelryError> {
-        let req = self.client.get("yarns/search.json").query(params);
+        let req = self.client.get("yarns/search.json")?.query(params);
         self.client.send_json(req).await
     }
 
@@ -63,9 +63,39 @@ impl<'a> YarnsApi<'a> {
         params: &YarnShowParams,
     ) -> Result<YarnShowResponse, RavelryError> {
         let path = format!("yarns/{}.json", id);
-        let req = self.client.get(&path).query(params);
+        let req = self.client.get(&path)?.query(params);
         self.client.send_json(req).await
     }
+
+    /// List the shops that carry a yarn.
+    ///
+    /// Ravelry has no separate endpoint for this; it fetches the yarn with
+    /// the `availability` include (see [`YarnShowParams::availability`]) and
+    /// returns the shop behind each listing, in the order Ravelry gives them.
+    /// Listings that don't name a shop are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// for shop in client.yarns().shops(573).await? {
+    ///     println!("{:?} ({})", shop.name, shop.location.as_deref().unwrap_or("online"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shops(&self, id: u64) -> Result<Vec<Shop>, RavelryError> {
+        let params = YarnShowParams::new().availability();
+        let yarn = self.show(id, &params).await?.yarn;
+        Ok(yarn
+            .availability
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|listing| listing.to_shop())
+            .collect())
+    }
 }
 
 /// Parameters for yarn search.
@@ -79,12 +109,15 @@ pub struct YarnSearchParams {
     #[serde(flatten)]
     pub page: PageParams,
 
-    /// Sort order (e.g., "best", "rating", "projects").
+    /// Sort order (e.g., "best", "rating", "projects"); see [`YarnSort`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort: Option<String>,
 
     /// Include personal attributes in the response (requires auth).
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        with = "crate::serde_helpers::bool_as_int",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub personal_attributes: Option<bool>,
 }
 
@@ -112,11 +145,20 @@ impl YarnSearchParams {
         self
     }
 
-    /// Set the sort order.
+    /// Set the sort order as a raw API value.
+    ///
+    /// Prefer [`sort_by`](Self::sort_by); unknown values are silently
+    /// ignored by the API.
     pub fn sort(mut self, sort: impl Into<String>) -> Self {
         self.sort = Some(sort.into());
         self
     }
+
+    /// Set a typed sort order.
+    pub fn sort_by(mut self, sort: YarnSort) -> Self {
+        self.sort = Some(sort.as_str().to_string());
+        self
+    }
 }
 
 /// Response from yarn search.
@@ -143,11 +185,43 @@ impl YarnShowParams {
         Self::default()
     }
 
-    /// Include extra parts in the response.
+    /// Include extra parts in the response, replacing any set before.
+    ///
+    /// `parts` is space delimited; prefer the typed builders such as
+    /// [`colorways`](Self::colorways), which can be combined.
     pub fn include(mut self, parts: impl Into<String>) -> Self {
         self.include = Some(parts.into());
         self
     }
+
+    /// Include the yarn's colorways, filling [`YarnFull::colorways`].
+    pub fn colorways(self) -> Self {
+        self.add_include("colorways")
+    }
+
+    /// Include where the yarn can be bought, filling [`YarnFull::availability`].
+    pub fn availability(self) -> Self {
+        self.add_include("availability")
+    }
+
+    /// Include the authenticated user's stash entries for the yarn.
+    ///
+    /// These aren't typed yet and land in [`YarnFull::extra`].
+    pub fn stash(self) -> Self {
+        self.add_include("stash")
+    }
+
+    fn add_include(mut self, part: &str) -> Self {
+        match &mut self.include {
+            Some(include) if include.split_whitespace().any(|p| p == part) => {}
+            Some(include) if !include.is_empty() => {
+                include.push(' ');
+                include.push_str(part);
+            }
+            _ => self.include = Some(part.to_string()),
+        }
+        self
+    }
 }
 
 /// Response from showing a single yarn.
@@ -8,6 +8,7 @@
 use reqwest::RequestBuilder;
 
 use super::{AuthKind, Authenticator};
+use crate::RavelryError;
 
 /// HTTP Basic authentication credentials.
 ///
@@ -47,8 +48,14 @@ impl BasicAuth {
 }
 
 impl Authenticator for BasicAuth {
-    fn apply(&self, req: RequestBuilder) -> RequestBuilder {
-        req.basic_auth(&self.username, Some(&self.password))
+    /// Fails if the access key contains a `:`, which Basic auth can't encode.
+    fn apply(&self, req: RequestBuilder) -> Result<RequestBuilder, RavelryError> {
+        if self.username.contains(':') {
+            return Err(RavelryError::Auth(
+                "access key must not contain ':'".to_string(),
+            ));
+        }
+        Ok(req.basic_auth(&self.username, Some(&self.password)))
     }
 
     fn kind(&self) -> AuthKind {
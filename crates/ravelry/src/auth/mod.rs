@@ -16,10 +16,20 @@
 
 mod basic;
 mod oauth2;
+mod scope;
+mod token_store;
 
 pub use basic::BasicAuth;
-pub use oauth2::{OAuth2Auth, OAuth2Token, RavelryOAuth2Client};
+/// A boxed future, as returned by [`Authenticator::ensure_fresh`] and
+/// [`TokenStore`] methods.
+pub use futures::future::BoxFuture;
+pub use oauth2::{
+    CallbackParams, OAuth2Auth, OAuth2ErrorKind, OAuth2Token, PkceVerifier, RavelryOAuth2Client,
+    RefreshingOAuth2Auth, DEFAULT_REFRESH_SKEW,
+};
 use reqwest::RequestBuilder;
+pub use scope::Scope;
+pub use token_store::{FileTokenStore, TokenStore};
 
 /// The type of authentication being used.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -37,10 +47,32 @@ pub enum AuthKind {
 /// Implementors can modify outgoing requests to add authentication credentials.
 pub trait Authenticator: Send + Sync {
     /// Apply authentication to a request builder.
-    fn apply(&self, req: RequestBuilder) -> RequestBuilder;
+    ///
+    /// Return [`RavelryError::Auth`](crate::RavelryError::Auth) if the
+    /// credentials can't be used; the client then fails the call without
+    /// sending anything.
+    fn apply(&self, req: RequestBuilder) -> Result<RequestBuilder, crate::RavelryError>;
 
     /// Return the kind of authentication this provides.
     fn kind(&self) -> AuthKind;
+
+    /// Bring the credentials up to date before an authenticated request is sent.
+    ///
+    /// The client awaits this before sending each request that carries
+    /// credentials, then re-applies them if [`apply`](Self::apply) now
+    /// produces a different `Authorization` header. The default does nothing.
+    fn ensure_fresh(&self) -> BoxFuture<'_, Result<(), crate::RavelryError>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Returns the OAuth2 scopes the credentials were granted, if known.
+    ///
+    /// `None` means the scopes are unknown or don't apply (e.g. Basic auth);
+    /// the client then never rejects a request for a missing scope itself.
+    /// The default returns `None`.
+    fn scopes(&self) -> Option<Vec<Scope>> {
+        None
+    }
 }
 
 /// A no-op authenticator for unauthenticated requests.
@@ -48,8 +80,8 @@ pub trait Authenticator: Send + Sync {
 pub struct NoAuth;
 
 impl Authenticator for NoAuth {
-    fn apply(&self, req: RequestBuilder) -> RequestBuilder {
-        req
+    fn apply(&self, req: RequestBuilder) -> Result<RequestBuilder, crate::RavelryError> {
+        Ok(req)
     }
 
     fn kind(&self) -> AuthKind {
@@ -2,6 +2,8 @@
 //!
 //! This module provides:
 //! - [`OAuth2Auth`]: An [`Authenticator`] that adds bearer tokens to requests
+//! - [`RefreshingOAuth2Auth`]: An [`Authenticator`] that refreshes its token
+//!   before it expires
 //! - [`OAuth2Token`]: A serializable token for storage/refresh
 //! - [`RavelryOAuth2Client`]: Helper for OAuth2 authorization flows
 //!
@@ -12,7 +14,8 @@
 //! 3. User authorizes your app and is redirected back with a code
 //! 4. Exchange the code for tokens using [`RavelryOAuth2Client::exchange_code`]
 //! 5. Create an [`OAuth2Auth`] from the access token to make API calls
-//! 6. When tokens expire, use [`RavelryOAuth2Client::refresh`] to get new ones
+//! 6. When tokens expire, use [`RavelryOAuth2Client::refresh`] to get new ones,
+//!    or use [`RefreshingOAuth2Auth`] to do this automatically
 //!
 //! # Example
 //!
@@ -43,13 +46,15 @@
 //! # }
 //! ```
 
-use oauth2::{ClientId, ClientSecret, CsrfToken, RedirectUrl};
+use futures::future::BoxFuture;
+use oauth2::{ClientId, ClientSecret, CsrfToken, PkceCodeChallenge, PkceCodeVerifier, RedirectUrl};
 use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use time::OffsetDateTime;
 
-use super::{AuthKind, Authenticator};
+use super::{AuthKind, Authenticator, Scope, TokenStore};
+use crate::types::common::string_enum;
 use crate::RavelryError;
 
 /// Ravelry OAuth2 authorization URL.
@@ -58,17 +63,56 @@ pub const AUTH_URL: &str = "https://www.ravelry.com/oauth2/auth";
 /// Ravelry OAuth2 token URL.
 pub const TOKEN_URL: &str = "https://www.ravelry.com/oauth2/token";
 
+string_enum! {
+    /// The `error` code returned by the OAuth2 token endpoint (RFC 6749 §5.2).
+    pub enum OAuth2ErrorKind {
+        /// The request is malformed or missing a parameter.
+        InvalidRequest => "invalid_request",
+        /// Client authentication failed (bad client ID or secret).
+        InvalidClient => "invalid_client",
+        /// The authorization code or refresh token is invalid, expired or
+        /// revoked.
+        InvalidGrant => "invalid_grant",
+        /// The client is not allowed to use this grant type.
+        UnauthorizedClient => "unauthorized_client",
+        /// The grant type is not supported.
+        UnsupportedGrantType => "unsupported_grant_type",
+        /// The requested scope is invalid.
+        InvalidScope => "invalid_scope",
+    }
+}
+
+impl OAuth2ErrorKind {
+    /// Returns `true` if the user must log in again to get a new token.
+    ///
+    /// Retrying with the same code or refresh token will not succeed.
+    pub fn requires_reauth(&self) -> bool {
+        matches!(self, Self::InvalidGrant)
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // OAuth2Auth - Authenticator implementation
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Default buffer before token expiry at which a token is treated as expired.
+pub const DEFAULT_REFRESH_SKEW: Duration = Duration::from_secs(5 * 60);
+
 /// OAuth2 bearer token authentication.
 ///
 /// This implements [`Authenticator`] and adds the access token as a
 /// `Bearer` token in the `Authorization` header.
+///
+/// If the expiry time is known (see [`OAuth2Auth::from_token`]),
+/// [`OAuth2Auth::is_expired`] reports whether the token should be refreshed,
+/// treating it as expired [`refresh_skew`](OAuth2Auth::refresh_skew) before
+/// it actually is.
 #[derive(Clone)]
 pub struct OAuth2Auth {
     access_token: String,
+    expires_at: Option<OffsetDateTime>,
+    refresh_skew: Duration,
+    scopes: Option<Vec<Scope>>,
 }
 
 impl OAuth2Auth {
@@ -76,33 +120,311 @@ impl OAuth2Auth {
     pub fn new(access_token: impl Into<String>) -> Self {
         Self {
             access_token: access_token.into(),
+            expires_at: None,
+            refresh_skew: DEFAULT_REFRESH_SKEW,
+            scopes: None,
+        }
+    }
+
+    /// Create an authenticator from a stored token, keeping its expiry time
+    /// and granted scopes.
+    pub fn from_token(token: &OAuth2Token) -> Self {
+        let auth = Self::new(&token.access_token).expires_at(token.expires_at);
+        match token.scope {
+            Some(_) => auth.granted_scopes(token.scopes()),
+            None => auth,
         }
     }
 
+    /// Set the scopes the access token was granted.
+    ///
+    /// Lets [`RavelryClient::has_scope`](crate::RavelryClient::has_scope)
+    /// answer, and lets the client reject requests for missing scopes before
+    /// sending them (see
+    /// [`RavelryClientBuilder::check_scopes`](crate::RavelryClientBuilder::check_scopes)).
+    pub fn granted_scopes(mut self, scopes: impl IntoIterator<Item = Scope>) -> Self {
+        self.scopes = Some(scopes.into_iter().collect());
+        self
+    }
+
+    /// Set when the access token expires.
+    pub fn expires_at(mut self, expires_at: Option<OffsetDateTime>) -> Self {
+        self.expires_at = expires_at;
+        self
+    }
+
+    /// Set how long before expiry the token should be treated as expired.
+    ///
+    /// Defaults to [`DEFAULT_REFRESH_SKEW`] (5 minutes). Increase this in
+    /// high-latency environments so tokens are refreshed earlier.
+    pub fn refresh_skew(mut self, skew: Duration) -> Self {
+        self.refresh_skew = skew;
+        self
+    }
+
     /// Returns the access token.
     pub fn access_token(&self) -> &str {
         &self.access_token
     }
+
+    /// Returns the configured refresh skew.
+    pub fn refresh_skew_value(&self) -> Duration {
+        self.refresh_skew
+    }
+
+    /// Check if the token is expired or will expire within the refresh skew.
+    ///
+    /// Returns `false` if the expiry time is unknown.
+    pub fn is_expired(&self) -> bool {
+        is_expired_at(self.expires_at, self.refresh_skew)
+    }
 }
 
 impl Authenticator for OAuth2Auth {
-    fn apply(&self, req: RequestBuilder) -> RequestBuilder {
-        req.bearer_auth(&self.access_token)
+    /// Fails if the access token is empty.
+    fn apply(&self, req: RequestBuilder) -> Result<RequestBuilder, RavelryError> {
+        bearer(req, &self.access_token)
     }
 
     fn kind(&self) -> AuthKind {
         AuthKind::OAuth2
     }
+
+    fn scopes(&self) -> Option<Vec<Scope>> {
+        self.scopes.clone()
+    }
 }
 
 impl std::fmt::Debug for OAuth2Auth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("OAuth2Auth")
             .field("access_token", &"[REDACTED]")
+            .field("expires_at", &self.expires_at)
+            .field("refresh_skew", &self.refresh_skew)
+            .field("scopes", &self.scopes)
             .finish()
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// RefreshingOAuth2Auth - Authenticator that refreshes its own token
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Callback invoked with each newly refreshed token.
+type RefreshCallback = Box<dyn Fn(&OAuth2Token) + Send + Sync>;
+
+/// OAuth2 bearer token authentication that refreshes itself.
+///
+/// Before each request, the client asks this authenticator to
+/// [`ensure_fresh`](Authenticator::ensure_fresh). Once the token is within
+/// the [refresh skew](Self::refresh_skew) of expiring, it is exchanged for a
+/// new one using the token's refresh token. Concurrent requests share a
+/// single refresh. Attach a [`TokenStore`] with
+/// [`token_store`](Self::token_store) (or register
+/// [`on_refresh`](Self::on_refresh)) to persist each new token.
+///
+/// The token must have a refresh token (request the `offline` scope);
+/// without one, an expired token fails with [`RavelryError::Auth`].
+///
+/// # Example
+///
+/// ```no_run
+/// use ravelry::auth::{OAuth2Token, RavelryOAuth2Client, RefreshingOAuth2Auth};
+/// use ravelry::RavelryClient;
+///
+/// # fn example(token: OAuth2Token) -> Result<(), ravelry::RavelryError> {
+/// let oauth_client = RavelryOAuth2Client::new(
+///     "your_client_id",
+///     "your_client_secret",
+///     "https://localhost:8080/callback",
+/// )?;
+///
+/// let auth = RefreshingOAuth2Auth::new(oauth_client, token).on_refresh(|token| {
+///     let json = serde_json::to_string(token).expect("token serializes");
+///     std::fs::write("token.json", json).ok();
+/// });
+/// let client = RavelryClient::builder(auth).build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RefreshingOAuth2Auth {
+    oauth_client: RavelryOAuth2Client,
+    token: std::sync::RwLock<OAuth2Token>,
+    refresh_lock: tokio::sync::Mutex<()>,
+    refresh_skew: Duration,
+    on_refresh: Option<RefreshCallback>,
+    store: Option<Box<dyn TokenStore>>,
+}
+
+impl RefreshingOAuth2Auth {
+    /// Create an authenticator that refreshes `token` through `oauth_client`.
+    pub fn new(oauth_client: RavelryOAuth2Client, token: OAuth2Token) -> Self {
+        Self {
+            oauth_client,
+            token: std::sync::RwLock::new(token),
+            refresh_lock: tokio::sync::Mutex::new(()),
+            refresh_skew: DEFAULT_REFRESH_SKEW,
+            on_refresh: None,
+            store: None,
+        }
+    }
+
+    /// Create an authenticator from the token in `store`, saving refreshed
+    /// tokens back to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RavelryError::Auth`] if the store has no token.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ravelry::auth::{FileTokenStore, RavelryOAuth2Client, RefreshingOAuth2Auth};
+    /// use ravelry::RavelryClient;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// let oauth_client = RavelryOAuth2Client::new(
+    ///     "your_client_id",
+    ///     "your_client_secret",
+    ///     "https://localhost:8080/callback",
+    /// )?;
+    /// let store = FileTokenStore::new("token.json");
+    /// let auth = RefreshingOAuth2Auth::from_store(oauth_client, store).await?;
+    /// let client = RavelryClient::builder(auth).build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn from_store<S>(
+        oauth_client: RavelryOAuth2Client,
+        store: S,
+    ) -> Result<Self, RavelryError>
+    where
+        S: TokenStore + 'static,
+    {
+        let token = store
+            .load()
+            .await
+            .ok_or_else(|| RavelryError::Auth("No OAuth2 token in store".to_string()))?;
+        Ok(Self::new(oauth_client, token).token_store(store))
+    }
+
+    /// Set how long before expiry the token is refreshed.
+    ///
+    /// Defaults to [`DEFAULT_REFRESH_SKEW`] (5 minutes).
+    pub fn refresh_skew(mut self, skew: Duration) -> Self {
+        self.refresh_skew = skew;
+        self
+    }
+
+    /// Returns the configured refresh skew.
+    pub fn refresh_skew_value(&self) -> Duration {
+        self.refresh_skew
+    }
+
+    /// Call `callback` with each refreshed token, e.g. to save it to disk.
+    pub fn on_refresh<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&OAuth2Token) + Send + Sync + 'static,
+    {
+        self.on_refresh = Some(Box::new(callback));
+        self
+    }
+
+    /// Save each refreshed token to `store`.
+    ///
+    /// If saving fails, the request that triggered the refresh fails with
+    /// the store's error, but the new token is still used from memory.
+    pub fn token_store<S>(mut self, store: S) -> Self
+    where
+        S: TokenStore + 'static,
+    {
+        self.store = Some(Box::new(store));
+        self
+    }
+
+    /// Returns a copy of the current token.
+    pub fn token(&self) -> OAuth2Token {
+        self.read_token().clone()
+    }
+
+    /// Refresh the token if it is expired or within the refresh skew.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token has no refresh token, the refresh
+    /// request fails, or the new token can't be saved to the token store.
+    pub async fn refresh_if_needed(&self) -> Result<(), RavelryError> {
+        if !self.read_token().is_expired(self.refresh_skew) {
+            return Ok(());
+        }
+
+        let _guard = self.refresh_lock.lock().await;
+        // Another request may have refreshed while we waited for the lock.
+        let refresh_token = {
+            let token = self.read_token();
+            if !token.is_expired(self.refresh_skew) {
+                return Ok(());
+            }
+            token.refresh_token.clone().ok_or_else(|| {
+                RavelryError::Auth("OAuth2 token expired and has no refresh token".to_string())
+            })?
+        };
+
+        let mut token = self.oauth_client.refresh(&refresh_token).await?;
+        // Ravelry may not rotate the refresh token; keep the old one if so.
+        token.refresh_token.get_or_insert(refresh_token);
+        *self
+            .token
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = token.clone();
+
+        if let Some(callback) = &self.on_refresh {
+            callback(&token);
+        }
+        if let Some(store) = &self.store {
+            store.save(&token).await?;
+        }
+        Ok(())
+    }
+
+    fn read_token(&self) -> std::sync::RwLockReadGuard<'_, OAuth2Token> {
+        self.token
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Authenticator for RefreshingOAuth2Auth {
+    /// Fails if the current access token is empty.
+    fn apply(&self, req: RequestBuilder) -> Result<RequestBuilder, RavelryError> {
+        bearer(req, &self.read_token().access_token)
+    }
+
+    fn kind(&self) -> AuthKind {
+        AuthKind::OAuth2
+    }
+
+    fn ensure_fresh(&self) -> BoxFuture<'_, Result<(), RavelryError>> {
+        Box::pin(self.refresh_if_needed())
+    }
+
+    /// Returns the current token's scopes, or `None` if it doesn't list any.
+    fn scopes(&self) -> Option<Vec<Scope>> {
+        let token = self.read_token();
+        token.scope.as_ref().map(|_| token.scopes())
+    }
+}
+
+impl std::fmt::Debug for RefreshingOAuth2Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshingOAuth2Auth")
+            .field("access_token", &"[REDACTED]")
+            .field("expires_at", &self.read_token().expires_at)
+            .field("refresh_skew", &self.refresh_skew)
+            .finish_non_exhaustive()
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // OAuth2Token - Serializable token for storage
 // ─────────────────────────────────────────────────────────────────────────────
@@ -149,19 +471,54 @@ impl OAuth2Token {
     /// `true` if the token is expired or will expire within the skew duration,
     /// `false` if the token is still valid or has no expiration time.
     pub fn is_expired(&self, skew: Duration) -> bool {
-        match self.expires_at {
-            Some(expires_at) => {
-                let now = OffsetDateTime::now_utc();
-                let skew = time::Duration::try_from(skew).unwrap_or(time::Duration::ZERO);
-                now + skew >= expires_at
-            }
-            None => false, // No expiration means it doesn't expire (treat as valid)
-        }
+        is_expired_at(self.expires_at, skew)
+    }
+
+    /// Returns the scopes granted by this token, parsed from
+    /// [`scope`](Self::scope).
+    ///
+    /// Returns an empty list if the token doesn't list its scopes.
+    pub fn scopes(&self) -> Vec<Scope> {
+        self.scope
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(Scope::from)
+            .collect()
+    }
+
+    /// Returns `true` if this token was granted `scope`.
+    pub fn has_scope(&self, scope: &Scope) -> bool {
+        self.scopes().contains(scope)
     }
 
     /// Create an [`OAuth2Auth`] from this token.
+    ///
+    /// The authenticator keeps the token's expiry time and scopes, and uses
+    /// [`DEFAULT_REFRESH_SKEW`].
     pub fn to_auth(&self) -> OAuth2Auth {
-        OAuth2Auth::new(&self.access_token)
+        OAuth2Auth::from_token(self)
+    }
+}
+
+/// Attach `token` as a bearer token, refusing an empty one.
+fn bearer(req: RequestBuilder, token: &str) -> Result<RequestBuilder, RavelryError> {
+    if token.is_empty() {
+        return Err(RavelryError::Auth(
+            "OAuth2 access token is empty".to_string(),
+        ));
+    }
+    Ok(req.bearer_auth(token))
+}
+
+fn is_expired_at(expires_at: Option<OffsetDateTime>, skew: Duration) -> bool {
+    match expires_at {
+        Some(expires_at) => {
+            let now = OffsetDateTime::now_utc();
+            let skew = time::Duration::try_from(skew).unwrap_or(time::Duration::ZERO);
+            now + skew >= expires_at
+        }
+        None => false, // No expiration means it doesn't expire (treat as valid)
     }
 }
 
@@ -216,6 +573,8 @@ pub struct RavelryOAuth2Client {
     client_secret: ClientSecret,
     redirect_uri: RedirectUrl,
     http_client: reqwest::Client,
+    token_url: url::Url,
+    strict: bool,
 }
 
 impl RavelryOAuth2Client {
@@ -229,7 +588,10 @@ impl RavelryOAuth2Client {
     ///
     /// # Errors
     ///
-    /// Returns an error if the URLs are invalid or the HTTP client fails to build.
+    /// Returns an error if the redirect URI is not a valid URL, uses plain
+    /// `http` for a non-loopback host (Ravelry only accepts HTTPS redirects,
+    /// apart from local development callbacks), or the HTTP client fails to
+    /// build.
     pub fn new(
         client_id: &str,
         client_secret: &str,
@@ -237,6 +599,7 @@ impl RavelryOAuth2Client {
     ) -> Result<Self, RavelryError> {
         let redirect_url = RedirectUrl::new(redirect_uri.to_string())
             .map_err(|e| RavelryError::Auth(format!("Invalid redirect URI: {e}")))?;
+        validate_redirect_uri(redirect_url.url())?;
 
         let http_client = reqwest::Client::builder()
             .build()
@@ -247,9 +610,28 @@ impl RavelryOAuth2Client {
             client_secret: ClientSecret::new(client_secret.to_string()),
             redirect_uri: redirect_url,
             http_client,
+            token_url: url::Url::parse(TOKEN_URL).expect("TOKEN_URL is valid"),
+            strict: false,
         })
     }
 
+    /// Use a custom token endpoint instead of [`TOKEN_URL`].
+    ///
+    /// This is useful for testing against a mock server.
+    pub fn token_url(mut self, url: url::Url) -> Self {
+        self.token_url = url;
+        self
+    }
+
+    /// Enable strict mode.
+    ///
+    /// In strict mode, [`try_authorize_url`](Self::try_authorize_url) rejects
+    /// an empty scope list instead of requesting a token with no scopes.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
     /// Generate an authorization URL for the user to visit.
     ///
     /// # Arguments
@@ -261,6 +643,59 @@ impl RavelryOAuth2Client {
     /// A tuple of (authorization_url, csrf_state). The CSRF state should be
     /// verified when the user is redirected back to your callback URL.
     pub fn authorize_url(&self, scopes: impl IntoIterator<Item = String>) -> (url::Url, String) {
+        self.build_authorize_url(scopes, None)
+    }
+
+    /// Generate an authorization URL with a PKCE code challenge.
+    ///
+    /// PKCE (RFC 7636) protects the authorization code for public clients,
+    /// such as desktop apps, that can't keep their client secret private.
+    /// The URL carries a `code_challenge` derived from the returned
+    /// [`PkceVerifier`] using `S256`; keep the verifier and pass it to
+    /// [`exchange_code_pkce`](Self::exchange_code_pkce).
+    ///
+    /// This only adds protection if Ravelry's authorization server
+    /// enforces PKCE; a server that ignores the challenge will still issue
+    /// tokens as it would for [`authorize_url`](Self::authorize_url).
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (authorization_url, csrf_state, verifier).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ravelry::auth::RavelryOAuth2Client;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// let oauth_client = RavelryOAuth2Client::new(
+    ///     "your_client_id",
+    ///     "your_client_secret",
+    ///     "http://localhost:8080/callback",
+    /// )?;
+    /// let (url, _csrf_state, verifier) =
+    ///     oauth_client.authorize_url_pkce(vec!["offline".to_string()]);
+    /// println!("Open this URL: {url}");
+    ///
+    /// let code = "code_from_callback";
+    /// let token = oauth_client.exchange_code_pkce(code, &verifier).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn authorize_url_pkce(
+        &self,
+        scopes: impl IntoIterator<Item = String>,
+    ) -> (url::Url, String, PkceVerifier) {
+        let (challenge, verifier) = PkceCodeChallenge::new_random_sha256();
+        let (url, csrf_state) = self.build_authorize_url(scopes, Some(&challenge));
+        (url, csrf_state, PkceVerifier(verifier))
+    }
+
+    fn build_authorize_url(
+        &self,
+        scopes: impl IntoIterator<Item = String>,
+        challenge: Option<&PkceCodeChallenge>,
+    ) -> (url::Url, String) {
         let csrf_token = CsrfToken::new_random();
         let scope_str: Vec<String> = scopes.into_iter().collect();
 
@@ -274,11 +709,67 @@ impl RavelryOAuth2Client {
             if !scope_str.is_empty() {
                 query.append_pair("scope", &scope_str.join(" "));
             }
+            if let Some(challenge) = challenge {
+                query.append_pair("code_challenge", challenge.as_str());
+                query.append_pair("code_challenge_method", challenge.method().as_str());
+            }
         }
 
         (url, csrf_token.secret().clone())
     }
 
+    /// Generate an authorization URL, checking the requested scopes first.
+    ///
+    /// Without any scopes the resulting token can only read public data,
+    /// which is rarely what's intended. In [strict mode](Self::strict) an
+    /// empty scope list is an error; otherwise this behaves like
+    /// [`authorize_url`](Self::authorize_url).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RavelryError::Auth`] if strict mode is enabled and `scopes`
+    /// is empty.
+    pub fn try_authorize_url(
+        &self,
+        scopes: impl IntoIterator<Item = String>,
+    ) -> Result<(url::Url, String), RavelryError> {
+        let scopes: Vec<String> = scopes.into_iter().collect();
+        if self.strict && scopes.is_empty() {
+            return Err(RavelryError::Auth(
+                "No OAuth2 scopes requested; the token would only have public read access"
+                    .to_string(),
+            ));
+        }
+        Ok(self.authorize_url(scopes))
+    }
+
+    /// Check that the `state` received on the callback matches the CSRF state
+    /// returned by [`authorize_url`](Self::authorize_url).
+    ///
+    /// A mismatch means the callback didn't come from the authorization this
+    /// client started, so its code must not be exchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RavelryError::Auth`] if the states differ.
+    pub fn verify_state(expected: &str, received: &str) -> Result<(), RavelryError> {
+        // Compare in constant time so the expected state can't be guessed
+        // byte by byte from response timings.
+        let matches = expected.len() == received.len()
+            && expected
+                .bytes()
+                .zip(received.bytes())
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0;
+        if matches {
+            Ok(())
+        } else {
+            Err(RavelryError::Auth(
+                "OAuth2 state mismatch; the callback may be forged".to_string(),
+            ))
+        }
+    }
+
     /// Exchange an authorization code for tokens.
     ///
     /// # Arguments
@@ -289,15 +780,46 @@ impl RavelryOAuth2Client {
     ///
     /// Returns an error if the token exchange fails.
     pub async fn exchange_code(&self, code: &str) -> Result<OAuth2Token, RavelryError> {
+        self.exchange(code, None).await
+    }
+
+    /// Exchange an authorization code obtained with
+    /// [`authorize_url_pkce`](Self::authorize_url_pkce) for tokens.
+    ///
+    /// Sends `verifier` as the `code_verifier`, proving this client started
+    /// the authorization.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token exchange fails, including when the
+    /// server rejects the verifier.
+    pub async fn exchange_code_pkce(
+        &self,
+        code: &str,
+        verifier: &PkceVerifier,
+    ) -> Result<OAuth2Token, RavelryError> {
+        self.exchange(code, Some(verifier)).await
+    }
+
+    async fn exchange(
+        &self,
+        code: &str,
+        verifier: Option<&PkceVerifier>,
+    ) -> Result<OAuth2Token, RavelryError> {
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", self.redirect_uri.as_str()),
+        ];
+        if let Some(verifier) = verifier {
+            form.push(("code_verifier", verifier.secret()));
+        }
+
         let response = self
             .http_client
-            .post(TOKEN_URL)
+            .post(self.token_url.clone())
             .basic_auth(self.client_id.as_str(), Some(self.client_secret.secret()))
-            .form(&[
-                ("grant_type", "authorization_code"),
-                ("code", code),
-                ("redirect_uri", self.redirect_uri.as_str()),
-            ])
+            .form(&form)
             .send()
             .await
             .map_err(|e| RavelryError::Auth(format!("Token exchange request failed: {e}")))?;
@@ -305,9 +827,7 @@ impl RavelryOAuth2Client {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(RavelryError::Auth(format!(
-                "Token exchange failed with {status}: {body}"
-            )));
+            return Err(token_error("Token exchange", status, &body));
         }
 
         let token_response: RawTokenResponse = response
@@ -330,7 +850,7 @@ impl RavelryOAuth2Client {
     pub async fn refresh(&self, refresh_token: &str) -> Result<OAuth2Token, RavelryError> {
         let response = self
             .http_client
-            .post(TOKEN_URL)
+            .post(self.token_url.clone())
             .basic_auth(self.client_id.as_str(), Some(self.client_secret.secret()))
             .form(&[
                 ("grant_type", "refresh_token"),
@@ -343,9 +863,7 @@ impl RavelryOAuth2Client {
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
-            return Err(RavelryError::Auth(format!(
-                "Token refresh failed with {status}: {body}"
-            )));
+            return Err(token_error("Token refresh", status, &body));
         }
 
         let token_response: RawTokenResponse = response
@@ -357,6 +875,156 @@ impl RavelryOAuth2Client {
     }
 }
 
+/// The parameters Ravelry sends to the OAuth2 redirect URI.
+///
+/// # Example
+///
+/// ```
+/// use ravelry::auth::CallbackParams;
+///
+/// # fn example() -> Result<(), ravelry::RavelryError> {
+/// let params = CallbackParams::parse("code=abc123&state=xyz")?;
+/// let code = params.verify("xyz")?;
+/// assert_eq!(code, "abc123");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallbackParams {
+    /// The authorization code to exchange for tokens.
+    pub code: String,
+    /// The CSRF state echoed back from the authorization URL.
+    pub state: Option<String>,
+}
+
+impl CallbackParams {
+    /// Parse the query string of a callback URL, with or without the
+    /// leading `?`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RavelryError::Auth`] if the user denied access (an `error`
+    /// parameter is present) or there is no `code`.
+    pub fn parse(query: &str) -> Result<Self, RavelryError> {
+        let query = query.strip_prefix('?').unwrap_or(query);
+        let mut code = None;
+        let mut state = None;
+        let mut error = None;
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "code" => code = Some(value.into_owned()),
+                "state" => state = Some(value.into_owned()),
+                "error" => error = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(RavelryError::Auth(format!("Authorization failed: {error}")));
+        }
+        let code = code.ok_or_else(|| {
+            RavelryError::Auth("No authorization code in OAuth2 callback".to_string())
+        })?;
+        Ok(Self { code, state })
+    }
+
+    /// Verify the callback's state against `expected_state` and return the
+    /// authorization code.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RavelryError::Auth`] if the state is missing or doesn't
+    /// match (see [`RavelryOAuth2Client::verify_state`]).
+    pub fn verify(&self, expected_state: &str) -> Result<&str, RavelryError> {
+        let received = self.state.as_deref().unwrap_or_default();
+        RavelryOAuth2Client::verify_state(expected_state, received)?;
+        Ok(&self.code)
+    }
+}
+
+/// The secret half of a PKCE challenge, returned by
+/// [`RavelryOAuth2Client::authorize_url_pkce`].
+///
+/// Keep it until the user is redirected back, then pass it to
+/// [`RavelryOAuth2Client::exchange_code_pkce`].
+pub struct PkceVerifier(PkceCodeVerifier);
+
+impl PkceVerifier {
+    /// Restore a verifier from its secret, e.g. after storing it in a
+    /// session while the user authorizes.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(PkceCodeVerifier::new(secret.into()))
+    }
+
+    /// Returns the verifier's secret value.
+    pub fn secret(&self) -> &str {
+        self.0.secret()
+    }
+}
+
+impl std::fmt::Debug for PkceVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PkceVerifier").field(&"[REDACTED]").finish()
+    }
+}
+
+/// Map a failed token endpoint response to an error.
+///
+/// The standard `{"error": ..., "error_description": ...}` body becomes
+/// [`RavelryError::OAuth2`]; Ravelry sometimes sends `{"errors": [...]}`
+/// instead, which is mapped the same way using the first entry. Anything
+/// else falls back to [`RavelryError::Auth`] with the raw body.
+fn token_error(action: &str, status: reqwest::StatusCode, body: &str) -> RavelryError {
+    let parsed = serde_json::from_str::<serde_json::Value>(body).ok();
+    let error = parsed.as_ref().and_then(|value| {
+        if let Some(code) = value.get("error").and_then(|e| e.as_str()) {
+            let description = value
+                .get("error_description")
+                .and_then(|d| d.as_str())
+                .map(String::from);
+            return Some((code.to_string(), description));
+        }
+        let errors: Vec<&str> = value
+            .get("errors")?
+            .as_array()?
+            .iter()
+            .filter_map(|e| e.as_str())
+            .collect();
+        let (first, rest) = errors.split_first()?;
+        let description = (!rest.is_empty()).then(|| rest.join("; "));
+        Some((first.to_string(), description))
+    });
+
+    match error {
+        Some((code, description)) => RavelryError::OAuth2 {
+            status,
+            kind: OAuth2ErrorKind::from(code),
+            description,
+        },
+        None => RavelryError::Auth(format!("{action} failed with {status}: {body}")),
+    }
+}
+
+/// Check that a redirect URI is HTTPS, or HTTP on a loopback host.
+fn validate_redirect_uri(url: &url::Url) -> Result<(), RavelryError> {
+    match url.scheme() {
+        "https" => Ok(()),
+        "http" if is_loopback(url) => Ok(()),
+        scheme => Err(RavelryError::Auth(format!(
+            "Invalid redirect URI {url}: must use https (or http on localhost), got {scheme}"
+        ))),
+    }
+}
+
+fn is_loopback(url: &url::Url) -> bool {
+    match url.host() {
+        Some(url::Host::Domain(domain)) => domain.eq_ignore_ascii_case("localhost"),
+        Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    }
+}
+
 impl std::fmt::Debug for RavelryOAuth2Client {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RavelryOAuth2Client")
@@ -369,6 +1037,80 @@ impl std::fmt::Debug for RavelryOAuth2Client {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_token_scopes_parsed_from_scope_string() {
+        let mut token = OAuth2Token {
+            access_token: "token".to_string(),
+            refresh_token: None,
+            expires_at: None,
+            scope: Some("offline  message-write new-scope".to_string()),
+            token_type: None,
+        };
+        assert_eq!(
+            token.scopes(),
+            vec![
+                Scope::Offline,
+                Scope::MessageWrite,
+                Scope::Other("new-scope".to_string())
+            ]
+        );
+        assert!(token.has_scope(&Scope::MessageWrite));
+        assert!(!token.has_scope(&Scope::ForumWrite));
+        assert_eq!(
+            Authenticator::scopes(&token.to_auth()).map(|s| s.len()),
+            Some(3)
+        );
+
+        token.scope = None;
+        assert!(token.scopes().is_empty());
+        assert!(Authenticator::scopes(&token.to_auth()).is_none());
+    }
+
+    #[test]
+    fn test_token_error_invalid_grant() {
+        let body = r#"{"error":"invalid_grant","error_description":"refresh token revoked"}"#;
+        let err = token_error("Token refresh", reqwest::StatusCode::BAD_REQUEST, body);
+        match err {
+            RavelryError::OAuth2 {
+                status,
+                kind,
+                description,
+            } => {
+                assert_eq!(status, reqwest::StatusCode::BAD_REQUEST);
+                assert_eq!(kind, OAuth2ErrorKind::InvalidGrant);
+                assert!(kind.requires_reauth());
+                assert_eq!(description.as_deref(), Some("refresh token revoked"));
+            }
+            other => panic!("expected OAuth2 error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_token_error_invalid_client_in_errors_array() {
+        let body = r#"{"errors":["invalid_client"]}"#;
+        let err = token_error("Token exchange", reqwest::StatusCode::UNAUTHORIZED, body);
+        match err {
+            RavelryError::OAuth2 {
+                kind, description, ..
+            } => {
+                assert_eq!(kind, OAuth2ErrorKind::InvalidClient);
+                assert!(!kind.requires_reauth());
+                assert_eq!(description, None);
+            }
+            other => panic!("expected OAuth2 error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_token_error_unparseable_body_falls_back() {
+        let err = token_error(
+            "Token refresh",
+            reqwest::StatusCode::BAD_GATEWAY,
+            "<html>Bad Gateway</html>",
+        );
+        assert!(matches!(err, RavelryError::Auth(msg) if msg.contains("502")));
+    }
+
     #[test]
     fn test_oauth2_token_not_expired() {
         let token = OAuth2Token {
@@ -419,9 +1161,127 @@ mod tests {
         assert!(!token.is_expired(Duration::from_secs(0)));
     }
 
+    #[test]
+    fn test_oauth2_auth_default_skew() {
+        let token = OAuth2Token {
+            access_token: "test".to_string(),
+            refresh_token: None,
+            expires_at: Some(OffsetDateTime::now_utc() + time::Duration::minutes(10)),
+            scope: None,
+            token_type: None,
+        };
+        let auth = token.to_auth();
+        assert_eq!(auth.refresh_skew_value(), DEFAULT_REFRESH_SKEW);
+        assert!(!auth.is_expired());
+    }
+
+    #[test]
+    fn test_oauth2_auth_expires_within_configured_skew() {
+        let token = OAuth2Token {
+            access_token: "test".to_string(),
+            refresh_token: None,
+            expires_at: Some(OffsetDateTime::now_utc() + time::Duration::minutes(10)),
+            scope: None,
+            token_type: None,
+        };
+        // Token expires in 10 minutes, inside a 15 minute skew
+        let auth = OAuth2Auth::from_token(&token).refresh_skew(Duration::from_secs(15 * 60));
+        assert!(auth.is_expired());
+    }
+
+    #[test]
+    fn test_oauth2_auth_without_expiry_never_expires() {
+        let auth = OAuth2Auth::new("test").refresh_skew(Duration::from_secs(3600));
+        assert!(!auth.is_expired());
+    }
+
     #[test]
     fn test_oauth2_auth_kind() {
         let auth = OAuth2Auth::new("test_token");
         assert_eq!(auth.kind(), AuthKind::OAuth2);
     }
+
+    #[test]
+    fn test_redirect_uri_https_accepted() {
+        assert!(RavelryOAuth2Client::new("id", "secret", "https://example.com/callback").is_ok());
+        assert!(
+            RavelryOAuth2Client::new("id", "secret", "https://localhost:8080/callback").is_ok()
+        );
+    }
+
+    #[test]
+    fn test_redirect_uri_http_loopback_accepted() {
+        assert!(RavelryOAuth2Client::new("id", "secret", "http://localhost:8080/callback").is_ok());
+        assert!(RavelryOAuth2Client::new("id", "secret", "http://127.0.0.1:8080/callback").is_ok());
+        assert!(RavelryOAuth2Client::new("id", "secret", "http://[::1]:8080/callback").is_ok());
+    }
+
+    #[test]
+    fn test_redirect_uri_http_non_loopback_rejected() {
+        let err =
+            RavelryOAuth2Client::new("id", "secret", "http://example.com/callback").unwrap_err();
+        assert!(matches!(err, RavelryError::Auth(msg) if msg.contains("https")));
+    }
+
+    #[test]
+    fn test_redirect_uri_other_scheme_rejected() {
+        assert!(RavelryOAuth2Client::new("id", "secret", "ftp://localhost/callback").is_err());
+    }
+
+    #[test]
+    fn test_try_authorize_url_empty_scopes() {
+        let client =
+            RavelryOAuth2Client::new("id", "secret", "https://localhost:8080/callback").unwrap();
+        assert!(client.try_authorize_url(Vec::new()).is_ok());
+
+        let client = client.strict(true);
+        assert!(client.try_authorize_url(Vec::new()).is_err());
+        let (url, _) = client
+            .try_authorize_url(vec!["offline".to_string()])
+            .unwrap();
+        assert!(url.query().unwrap().contains("scope=offline"));
+    }
+
+    #[test]
+    fn test_verify_state() {
+        assert!(RavelryOAuth2Client::verify_state("abc", "abc").is_ok());
+        assert!(RavelryOAuth2Client::verify_state("abc", "abd").is_err());
+        assert!(RavelryOAuth2Client::verify_state("abc", "abcd").is_err());
+        assert!(RavelryOAuth2Client::verify_state("abc", "").is_err());
+    }
+
+    #[test]
+    fn test_callback_params_parse() {
+        let params = CallbackParams::parse("?code=a%2Bb&state=s1&extra=1").unwrap();
+        assert_eq!(params.code, "a+b");
+        assert_eq!(params.state.as_deref(), Some("s1"));
+        assert_eq!(params.verify("s1").unwrap(), "a+b");
+        assert!(params.verify("s2").is_err());
+
+        let no_state = CallbackParams::parse("code=abc").unwrap();
+        assert!(no_state.verify("s1").is_err());
+
+        assert!(CallbackParams::parse("state=s1").is_err());
+        let denied = CallbackParams::parse("error=access_denied&state=s1").unwrap_err();
+        assert!(denied.to_string().contains("access_denied"));
+    }
+
+    #[test]
+    fn test_authorize_url_pkce_adds_s256_challenge() {
+        let client =
+            RavelryOAuth2Client::new("id", "secret", "https://localhost:8080/callback").unwrap();
+        let (url, state, verifier) = client.authorize_url_pkce(vec!["offline".to_string()]);
+
+        let query: std::collections::HashMap<_, _> = url.query_pairs().into_owned().collect();
+        let expected = PkceCodeChallenge::from_code_verifier_sha256(&PkceCodeVerifier::new(
+            verifier.secret().to_string(),
+        ));
+        assert_eq!(query["code_challenge"], expected.as_str());
+        assert_eq!(query["code_challenge_method"], "S256");
+        assert_eq!(query["state"], state);
+        assert_eq!(format!("{verifier:?}"), "PkceVerifier(\"[REDACTED]\")");
+
+        let (url, _) = client.authorize_url(vec!["offline".to_string()]);
+        assert!(!url.query().unwrap().contains("code_challenge"));
+    }
 }
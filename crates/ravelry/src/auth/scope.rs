@@ -0,0 +1,93 @@
+//! OAuth2 scopes and the endpoints that need them.
+
+use reqwest::Method;
+
+use crate::types::common::string_enum;
+
+string_enum! {
+    /// An OAuth2 scope an application can request.
+    ///
+    /// Tokens without any scope can only read public data. Request the
+    /// scopes an application needs when building the authorization URL, e.g.
+    /// with [`RavelryOAuth2Client::authorize_url`](crate::auth::RavelryOAuth2Client::authorize_url).
+    pub enum Scope {
+        /// Issue a refresh token so access can be renewed.
+        Offline => "offline",
+        /// Post and edit forum posts.
+        ForumWrite => "forum-write",
+        /// Send, reply to, archive and delete messages.
+        MessageWrite => "message-write",
+        /// Read pattern store purchases.
+        PatternstoreRead => "patternstore-read",
+        /// Download purchased pattern PDFs.
+        PatternstorePdf => "patternstore-pdf",
+        /// Read pattern deliveries.
+        DeliveriesRead => "deliveries-read",
+        /// Download PDFs from the user's library.
+        LibraryPdf => "library-pdf",
+        /// Read the user's profile only.
+        ProfileOnly => "profile-only",
+        /// Manage shopping carts only.
+        CartsOnly => "carts-only",
+    }
+}
+
+impl Scope {
+    /// Returns the scope a write request needs, if it needs a specific one.
+    ///
+    /// `path` is relative to the API base URL (e.g. "messages/create.json").
+    /// Reads and endpoints without a dedicated scope return `None`.
+    pub(crate) fn required_for(method: &Method, path: &str) -> Option<Scope> {
+        if method == Method::GET {
+            return None;
+        }
+        let path = path.trim_start_matches('/');
+        if path.starts_with("messages/") {
+            Some(Scope::MessageWrite)
+        } else if path.starts_with("forum_posts/")
+            || (path.starts_with("topics/") && path.ends_with("/reply.json"))
+        {
+            Some(Scope::ForumWrite)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_for_write_endpoints() {
+        assert_eq!(
+            Scope::required_for(&Method::POST, "messages/create.json"),
+            Some(Scope::MessageWrite)
+        );
+        assert_eq!(
+            Scope::required_for(&Method::DELETE, "/messages/12.json"),
+            Some(Scope::MessageWrite)
+        );
+        assert_eq!(
+            Scope::required_for(&Method::POST, "topics/5/reply.json"),
+            Some(Scope::ForumWrite)
+        );
+        assert_eq!(
+            Scope::required_for(&Method::POST, "forum_posts/9.json"),
+            Some(Scope::ForumWrite)
+        );
+    }
+
+    #[test]
+    fn test_required_for_ignores_reads_and_unscoped_writes() {
+        assert_eq!(
+            Scope::required_for(&Method::GET, "messages/list.json"),
+            None
+        );
+        assert_eq!(
+            Scope::required_for(&Method::POST, "projects/me/create.json"),
+            None
+        );
+        assert_eq!(Scope::from("message-write"), Scope::MessageWrite);
+    }
+}
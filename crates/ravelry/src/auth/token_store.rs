@@ -0,0 +1,121 @@
+//! Persistent storage for OAuth2 tokens.
+
+use std::path::{Path, PathBuf};
+
+use futures::future::BoxFuture;
+
+use super::OAuth2Token;
+use crate::RavelryError;
+
+/// Storage for an OAuth2 token that outlives the process.
+///
+/// Attach a store to [`RefreshingOAuth2Auth`](super::RefreshingOAuth2Auth)
+/// with [`token_store`](super::RefreshingOAuth2Auth::token_store) and it will
+/// save every refreshed token, or start from a stored token with
+/// [`from_store`](super::RefreshingOAuth2Auth::from_store).
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Mutex;
+/// use ravelry::auth::{BoxFuture, OAuth2Token, TokenStore};
+/// use ravelry::RavelryError;
+///
+/// #[derive(Default)]
+/// struct MemoryStore(Mutex<Option<OAuth2Token>>);
+///
+/// impl TokenStore for MemoryStore {
+///     fn load(&self) -> BoxFuture<'_, Option<OAuth2Token>> {
+///         Box::pin(async move { self.0.lock().unwrap().clone() })
+///     }
+///
+///     fn save<'a>(&'a self, token: &'a OAuth2Token) -> BoxFuture<'a, Result<(), RavelryError>> {
+///         Box::pin(async move {
+///             *self.0.lock().unwrap() = Some(token.clone());
+///             Ok(())
+///         })
+///     }
+/// }
+/// ```
+pub trait TokenStore: Send + Sync {
+    /// Returns the stored token, if there is one.
+    fn load(&self) -> BoxFuture<'_, Option<OAuth2Token>>;
+
+    /// Store `token`, replacing any previous token.
+    fn save<'a>(&'a self, token: &'a OAuth2Token) -> BoxFuture<'a, Result<(), RavelryError>>;
+}
+
+/// A [`TokenStore`] that keeps the token in a JSON file.
+///
+/// The file is replaced atomically on save, so a crash mid-write leaves the
+/// previous token in place. Missing parent directories are created.
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+impl FileTokenStore {
+    /// Create a store backed by the file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the token file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    /// Returns `None` if the file is missing or doesn't hold a token.
+    fn load(&self) -> BoxFuture<'_, Option<OAuth2Token>> {
+        Box::pin(async move {
+            let content = tokio::fs::read(&self.path).await.ok()?;
+            serde_json::from_slice(&content).ok()
+        })
+    }
+
+    fn save<'a>(&'a self, token: &'a OAuth2Token) -> BoxFuture<'a, Result<(), RavelryError>> {
+        Box::pin(async move {
+            if let Some(parent) = self.path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            let content = serde_json::to_vec_pretty(token)?;
+            let tmp = self.path.with_extension("tmp");
+            tokio::fs::write(&tmp, content).await?;
+            tokio::fs::rename(&tmp, &self.path).await?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(access_token: &str) -> OAuth2Token {
+        OAuth2Token {
+            access_token: access_token.to_string(),
+            refresh_token: Some("refresh".to_string()),
+            expires_at: None,
+            scope: Some("offline".to_string()),
+            token_type: Some("Bearer".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_token_store_round_trips() {
+        let dir = std::env::temp_dir().join(format!("ravelry-token-store-{}", std::process::id()));
+        let store = FileTokenStore::new(dir.join("nested").join("token.json"));
+
+        assert!(store.load().await.is_none());
+
+        store.save(&token("first")).await.unwrap();
+        store.save(&token("second")).await.unwrap();
+        let loaded = store.load().await.unwrap();
+        assert_eq!(loaded.access_token, "second");
+        assert_eq!(loaded.refresh_token.as_deref(), Some("refresh"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
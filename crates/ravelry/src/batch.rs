@@ -0,0 +1,49 @@
+//! Results of operations applied to many items at once.
+//!
+//! Ravelry's write endpoints take one item per request, so batch helpers
+//! such as [`FavoritesApi::create_many`](crate::api::favorites::FavoritesApi::create_many)
+//! send one request per item and keep going when some of them fail.
+
+use crate::error::RavelryError;
+
+/// The outcome of a batch operation, split into successes and failures.
+///
+/// Each entry carries the index of the input item it belongs to. Both lists
+/// are sorted by that index.
+#[derive(Debug)]
+pub struct BatchResult<T> {
+    /// Items that succeeded, with their input index.
+    pub succeeded: Vec<(usize, T)>,
+
+    /// Items that failed, with their input index and error.
+    pub failed: Vec<(usize, RavelryError)>,
+}
+
+impl<T> BatchResult<T> {
+    /// Split per-item results, in any order, into a sorted batch result.
+    pub(crate) fn from_results(results: Vec<(usize, Result<T, RavelryError>)>) -> Self {
+        let mut batch = Self {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (index, result) in results {
+            match result {
+                Ok(item) => batch.succeeded.push((index, item)),
+                Err(e) => batch.failed.push((index, e)),
+            }
+        }
+        batch.succeeded.sort_by_key(|(index, _)| *index);
+        batch.failed.sort_by_key(|(index, _)| *index);
+        batch
+    }
+
+    /// Returns `true` if every item succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Returns the successful items in input order, dropping their indices.
+    pub fn into_succeeded(self) -> Vec<T> {
+        self.succeeded.into_iter().map(|(_, item)| item).collect()
+    }
+}
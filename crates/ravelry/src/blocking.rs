@@ -0,0 +1,177 @@
+//! A blocking Ravelry client.
+//!
+//! Available with the `blocking` feature. [`RavelryClient`] wraps the async
+//! [`crate::RavelryClient`] and drives it on a private current-thread Tokio
+//! runtime, so its methods return results directly instead of futures. This
+//! suits scripts and tools that don't otherwise use async.
+//!
+//! Like `reqwest::blocking`, the blocking client must not be used from
+//! within an async runtime; doing so panics.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use ravelry::auth::BasicAuth;
+//! use ravelry::api::patterns::PatternSearchParams;
+//! use ravelry::blocking::RavelryClient;
+//!
+//! # fn example() -> Result<(), ravelry::RavelryError> {
+//! let client = RavelryClient::builder(BasicAuth::new("access_key", "personal_key"))
+//!     .build_blocking()?;
+//!
+//! let user = client.root().current_user()?;
+//! println!("Logged in as: {}", user.user.username);
+//!
+//! let params = PatternSearchParams::new().query("socks");
+//! for pattern in client.patterns().search(&params)?.patterns {
+//!     println!("{}", pattern.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+
+use tokio::runtime::Runtime;
+
+use crate::api::patterns::{PatternSearchParams, PatternShowResponse, PatternsSearchResponse};
+use crate::api::projects::{
+    ProjectShowParams, ProjectShowResponse, ProjectsListParams, ProjectsListResponse,
+};
+use crate::api::root::CurrentUserResponse;
+use crate::auth::Authenticator;
+use crate::client::RavelryClientBuilder;
+use crate::error::RavelryError;
+
+/// A blocking client for the Ravelry API.
+///
+/// Build one with [`RavelryClient::builder`] and
+/// [`RavelryClientBuilder::build_blocking`], or wrap an existing async
+/// client with [`RavelryClient::from_async`].
+pub struct RavelryClient {
+    inner: crate::RavelryClient,
+    runtime: Runtime,
+}
+
+impl RavelryClient {
+    /// Create a new builder with the given authenticator.
+    ///
+    /// Configure it as for the async client, then call
+    /// [`build_blocking`](RavelryClientBuilder::build_blocking).
+    pub fn builder<A>(auth: A) -> RavelryClientBuilder
+    where
+        A: Authenticator + 'static,
+    {
+        RavelryClientBuilder::new(auth)
+    }
+
+    /// Wrap an async client.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RavelryError::Io`] if the runtime can't be created.
+    pub fn from_async(client: crate::RavelryClient) -> Result<Self, RavelryError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            inner: client,
+            runtime,
+        })
+    }
+
+    /// Returns the wrapped async client.
+    pub fn as_async(&self) -> &crate::RavelryClient {
+        &self.inner
+    }
+
+    /// Access root endpoints (current user, etc.).
+    pub fn root(&self) -> RootApi<'_> {
+        RootApi { client: self }
+    }
+
+    /// Access pattern-related endpoints.
+    pub fn patterns(&self) -> PatternsApi<'_> {
+        PatternsApi { client: self }
+    }
+
+    /// Access project-related endpoints.
+    pub fn projects(&self) -> ProjectsApi<'_> {
+        ProjectsApi { client: self }
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+impl std::fmt::Debug for RavelryClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RavelryClient")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Blocking version of [`crate::api::root::RootApi`].
+pub struct RootApi<'a> {
+    client: &'a RavelryClient,
+}
+
+impl RootApi<'_> {
+    /// Get the currently authenticated user.
+    pub fn current_user(&self) -> Result<CurrentUserResponse, RavelryError> {
+        let client = self.client;
+        client.block_on(client.inner.root().current_user())
+    }
+}
+
+/// Blocking version of [`crate::api::patterns::PatternsApi`].
+pub struct PatternsApi<'a> {
+    client: &'a RavelryClient,
+}
+
+impl PatternsApi<'_> {
+    /// Search for patterns.
+    pub fn search(
+        &self,
+        params: &PatternSearchParams,
+    ) -> Result<PatternsSearchResponse, RavelryError> {
+        let client = self.client;
+        client.block_on(client.inner.patterns().search(params))
+    }
+
+    /// Get details for a single pattern.
+    pub fn show(&self, id: u64) -> Result<PatternShowResponse, RavelryError> {
+        let client = self.client;
+        client.block_on(client.inner.patterns().show(id))
+    }
+}
+
+/// Blocking version of [`crate::api::projects::ProjectsApi`].
+pub struct ProjectsApi<'a> {
+    client: &'a RavelryClient,
+}
+
+impl ProjectsApi<'_> {
+    /// List a user's projects.
+    pub fn list(
+        &self,
+        username: &str,
+        params: &ProjectsListParams,
+    ) -> Result<ProjectsListResponse, RavelryError> {
+        let client = self.client;
+        client.block_on(client.inner.projects().list(username, params))
+    }
+
+    /// Get details for a single project.
+    pub fn show(
+        &self,
+        username: &str,
+        id: &str,
+        params: &ProjectShowParams,
+    ) -> Result<ProjectShowResponse, RavelryError> {
+        let client = self.client;
+        client.block_on(client.inner.projects().show(username, id, params))
+    }
+}
@@ -0,0 +1,209 @@
+//! Record and replay HTTP interactions for hermetic tests.
+//!
+//! Available with the `test-util` feature. A [`Cassette`] attached to a
+//! client with [`RavelryClientBuilder::cassette`](crate::client::RavelryClientBuilder::cassette)
+//! either records every real response the client receives, or replays
+//! previously recorded responses without touching the network.
+//!
+//! Interactions are keyed by method, path and query string. The host is
+//! ignored, so a cassette recorded against one base URL replays against any
+//! other. Request headers (including credentials) are never recorded.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use ravelry::{RavelryClient, auth::BasicAuth};
+//! use ravelry::cassette::Cassette;
+//!
+//! # async fn example() -> Result<(), ravelry::RavelryError> {
+//! // First run: record real responses.
+//! let cassette = Cassette::record("tests/cassettes/current_user.json");
+//! let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+//!     .cassette(cassette.clone())
+//!     .build()?;
+//! client.root().current_user().await?;
+//! cassette.save()?;
+//!
+//! // Later runs: replay them offline.
+//! let cassette = Cassette::replay("tests/cassettes/current_user.json")?;
+//! let client = RavelryClient::builder(BasicAuth::new("", ""))
+//!     .cassette(cassette)
+//!     .build()?;
+//! let response = client.root().current_user().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::RavelryError;
+use crate::etag::store_key;
+
+/// Whether a [`Cassette`] is recording or replaying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send real requests and record their responses.
+    Record,
+    /// Serve recorded responses; never send real requests.
+    Replay,
+}
+
+/// A single recorded request/response pair.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Interaction {
+    /// HTTP method (e.g. "GET").
+    pub method: String,
+
+    /// Request path and query string, without the host.
+    pub path: String,
+
+    /// Response status code.
+    pub status: u16,
+
+    /// Response headers.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+
+    /// Response body.
+    pub body: String,
+}
+
+/// A set of recorded HTTP interactions, shared with a client.
+///
+/// Cloning a cassette is cheap; clones share the same recordings.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    mode: CassetteMode,
+    path: PathBuf,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    interactions: Vec<Interaction>,
+    /// Replay only: which interactions have already been served.
+    used: Vec<bool>,
+}
+
+impl Cassette {
+    /// Create an empty cassette that records to `path`.
+    ///
+    /// Nothing is written until [`save`](Self::save) is called.
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self::new(CassetteMode::Record, path.as_ref(), Vec::new())
+    }
+
+    /// Load a previously saved cassette for replay.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, RavelryError> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)?;
+        let interactions = serde_json::from_str(&json)?;
+        Ok(Self::new(CassetteMode::Replay, path, interactions))
+    }
+
+    fn new(mode: CassetteMode, path: &Path, interactions: Vec<Interaction>) -> Self {
+        let used = vec![false; interactions.len()];
+        Self {
+            inner: Arc::new(Inner {
+                mode,
+                path: path.to_path_buf(),
+                state: Mutex::new(State { interactions, used }),
+            }),
+        }
+    }
+
+    /// Returns whether this cassette is recording or replaying.
+    pub fn mode(&self) -> CassetteMode {
+        self.inner.mode
+    }
+
+    /// Returns a copy of the interactions recorded or loaded so far.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state().interactions.clone()
+    }
+
+    /// Write the recorded interactions to the cassette's file as JSON.
+    pub fn save(&self) -> Result<(), RavelryError> {
+        let json = serde_json::to_string_pretty(&self.state().interactions)?;
+        if let Some(parent) = self.inner.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.inner.path, json)?;
+        Ok(())
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.inner.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Send `request` through the cassette.
+    pub(crate) async fn execute(
+        &self,
+        http: &reqwest::Client,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, RavelryError> {
+        let method = request.method().as_str().to_string();
+        let path = store_key(request.url());
+
+        let interaction = match self.inner.mode {
+            CassetteMode::Replay => self.take(&method, &path)?,
+            CassetteMode::Record => {
+                let resp = http.execute(request).await?;
+                let status = resp.status().as_u16();
+                let headers = resp
+                    .headers()
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+                    })
+                    .collect();
+                let body = resp.text().await?;
+                let interaction = Interaction {
+                    method,
+                    path,
+                    status,
+                    headers,
+                    body,
+                };
+                self.state().interactions.push(interaction.clone());
+                interaction
+            }
+        };
+
+        to_response(interaction)
+    }
+
+    /// Find the first unused recorded interaction matching the request.
+    fn take(&self, method: &str, path: &str) -> Result<Interaction, RavelryError> {
+        let mut state = self.state();
+        let State { interactions, used } = &mut *state;
+        let index = interactions
+            .iter()
+            .zip(used.iter())
+            .position(|(i, used)| !used && i.method == method && i.path == path)
+            .ok_or_else(|| {
+                RavelryError::InvalidRequest(format!("no recorded interaction for {method} {path}"))
+            })?;
+        used[index] = true;
+        Ok(interactions[index].clone())
+    }
+}
+
+fn to_response(interaction: Interaction) -> Result<reqwest::Response, RavelryError> {
+    let mut builder = http::Response::builder().status(interaction.status);
+    for (name, value) in &interaction.headers {
+        builder = builder.header(name, value);
+    }
+    let response = builder
+        .body(interaction.body)
+        .map_err(|e| RavelryError::InvalidRequest(format!("invalid recorded interaction: {e}")))?;
+    Ok(reqwest::Response::from(response))
+}
@@ -1,17 +1,49 @@
 //! The main Ravelry API client.
 
-use reqwest::RequestBuilder;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use reqwest::header::AUTHORIZATION;
+use reqwest::{RequestBuilder, StatusCode};
 use serde::de::DeserializeOwned;
 use url::Url;
 
+use crate::api::people::PeopleCache;
 use crate::api::{
-    bundled_items::BundledItemsApi, bundles::BundlesApi, favorites::FavoritesApi,
-    friends::FriendsApi, messages::MessagesApi, patterns::PatternsApi, projects::ProjectsApi,
-    root::RootApi, stash::StashApi, upload::UploadApi, yarns::YarnsApi,
+    bundled_items::BundledItemsApi,
+    bundles::BundlesApi,
+    comments::CommentsApi,
+    favorites::FavoritesApi,
+    forums::{ForumsApi, PostsApi, TopicsApi},
+    friends::FriendsApi,
+    groups::GroupsApi,
+    library::LibraryApi,
+    messages::MessagesApi,
+    patterns::PatternsApi,
+    people::PeopleApi,
+    photos::PhotosApi,
+    projects::ProjectsApi,
+    queue::QueueApi,
+    reference::ReferenceApi,
+    root::RootApi,
+    saved_searches::SavedSearchesApi,
+    stash::StashApi,
+    upload::UploadApi,
+    volumes::VolumesApi,
+    yarns::YarnsApi,
 };
-use crate::auth::{AuthKind, Authenticator, NoAuth};
+use crate::auth::{AuthKind, Authenticator, NoAuth, Scope};
+#[cfg(feature = "test-util")]
+use crate::cassette::Cassette;
 use crate::error::{map_error_response, RavelryError};
-use crate::request_options::RequestOptions;
+use crate::etag::{
+    response_etag, response_last_modified, store_key, Conditional, EtagCache, EtagStore,
+};
+use crate::interceptor::RequestInterceptor;
+use crate::rate_limit::RateLimitInfo;
+use crate::request_options::{http_date, RequestOptions};
+use crate::retry::RetryPolicy;
 
 /// Controls whether authentication is applied to a request.
 #[derive(Clone, Copy, Debug, Default)]
@@ -46,10 +78,18 @@ pub const DEFAULT_BASE_URL: &str = "https://api.ravelry.com/";
 /// # }
 /// ```
 pub struct RavelryClient {
-    http: reqwest::Client,
+    http: Arc<reqwest::Client>,
     base_url: Url,
     auth: Box<dyn Authenticator + Send + Sync>,
     defaults: RequestOptions,
+    people_cache: Mutex<PeopleCache>,
+    rate_limit: Mutex<Option<RateLimitInfo>>,
+    retry: Option<RetryPolicy>,
+    last_attempts: AtomicU32,
+    etag_store: Option<Arc<dyn EtagStore>>,
+    interceptors: Arc<[Box<dyn RequestInterceptor>]>,
+    #[cfg(feature = "test-util")]
+    cassette: Option<Cassette>,
 }
 
 impl RavelryClient {
@@ -71,6 +111,147 @@ impl RavelryClient {
         &self.base_url
     }
 
+    /// Returns whether the credentials were granted `scope`.
+    ///
+    /// Returns `None` if the authenticator doesn't know its scopes, as with
+    /// Basic auth or an [`OAuth2Auth`](crate::auth::OAuth2Auth) built from a
+    /// bare access token.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ravelry::{RavelryClient, auth::{OAuth2Token, Scope}};
+    ///
+    /// # fn example(token: OAuth2Token) -> Result<(), ravelry::RavelryError> {
+    /// let client = RavelryClient::builder(token.to_auth()).build()?;
+    /// if client.has_scope(&Scope::MessageWrite) == Some(false) {
+    ///     eprintln!("log in again with the message-write scope to send messages");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_scope(&self, scope: &Scope) -> Option<bool> {
+        self.auth.scopes().map(|scopes| scopes.contains(scope))
+    }
+
+    /// Returns a client that uses `auth` in place of this client's
+    /// authenticator.
+    ///
+    /// The new client shares this client's HTTP connection pool, so swapping
+    /// credentials (for example, after refreshing a token by hand) doesn't
+    /// reopen connections. It also keeps the base URL, default request
+    /// options, retry policy, and interceptors. Per-client state such as the
+    /// people cache and the last observed rate limit starts empty.
+    ///
+    /// The ETag store is not carried over. Its entries are keyed by path and
+    /// query only, so sharing it would let the new credentials revalidate
+    /// against, and be answered with, bodies cached for the old ones (e.g.
+    /// another user's `current_user.json`).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ravelry::{RavelryClient, auth::{BasicAuth, OAuth2Auth}};
+    ///
+    /// # fn example() -> Result<(), ravelry::RavelryError> {
+    /// let client = RavelryClient::builder(BasicAuth::new("key", "secret")).build()?;
+    /// let oauth_client = client.with_auth(OAuth2Auth::new("access_token"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_auth<A>(&self, auth: A) -> RavelryClient
+    where
+        A: Authenticator + 'static,
+    {
+        RavelryClient {
+            http: Arc::clone(&self.http),
+            base_url: self.base_url.clone(),
+            auth: Box::new(auth),
+            defaults: self.defaults.clone(),
+            people_cache: Mutex::default(),
+            rate_limit: Mutex::default(),
+            retry: self.retry.clone(),
+            last_attempts: AtomicU32::new(0),
+            etag_store: None,
+            interceptors: Arc::clone(&self.interceptors),
+            #[cfg(feature = "test-util")]
+            cassette: self.cassette.clone(),
+        }
+    }
+
+    /// Check that the API is reachable and accepts this client's credentials.
+    ///
+    /// Sends an authenticated `HEAD` request for the current user, so no
+    /// body is downloaded or parsed. Suited to readiness probes and startup
+    /// validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RavelryError::Auth`] if the API rejects the credentials
+    /// (HTTP 401), and the usual errors for other failures.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// let client = RavelryClient::builder(BasicAuth::new("key", "secret")).build()?;
+    /// client.ping().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "health")]
+    pub async fn ping(&self) -> Result<(), RavelryError> {
+        let req = self.request(reqwest::Method::HEAD, "current_user.json")?;
+        match self.send(req).await {
+            Ok(_) | Err(RavelryError::NotModified { .. }) => Ok(()),
+            Err(RavelryError::Unauthorized { .. }) => Err(RavelryError::Auth(
+                "credentials rejected by the API (HTTP 401)".to_string(),
+            )),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the rate limit quota from the most recent response that
+    /// reported one, or `None` if none has yet.
+    ///
+    /// This makes no request, so it's cheap to check before starting a large
+    /// batch of calls.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// client.root().current_user().await?;
+    /// if let Some(status) = client.rate_limit_status() {
+    ///     println!("{:?} requests remaining", status.remaining);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rate_limit_status(&self) -> Option<RateLimitInfo> {
+        *self
+            .rate_limit
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Alias for [`rate_limit_status`](Self::rate_limit_status).
+    pub fn last_rate_limit(&self) -> Option<RateLimitInfo> {
+        self.rate_limit_status()
+    }
+
+    /// Returns how many attempts the most recently completed request took.
+    ///
+    /// This is `1` unless a [`RetryPolicy`] is configured and the request
+    /// was retried, and `0` before any request has completed. With
+    /// concurrent requests, it reflects whichever finished last.
+    pub fn last_attempts(&self) -> u32 {
+        self.last_attempts.load(Ordering::Relaxed)
+    }
+
     // --- Service Pattern Methods ---
 
     /// Access root-level endpoints (current_user, search, etc.).
@@ -93,6 +274,16 @@ impl RavelryClient {
         ProjectsApi { client: self }
     }
 
+    /// Access queue-related endpoints.
+    pub fn queue(&self) -> QueueApi<'_> {
+        QueueApi { client: self }
+    }
+
+    /// Access reference data endpoints (needle sizes, yarn weights, color families).
+    pub fn reference(&self) -> ReferenceApi<'_> {
+        ReferenceApi { client: self }
+    }
+
     /// Access stash-related endpoints.
     pub fn stash(&self) -> StashApi<'_> {
         StashApi { client: self }
@@ -128,73 +319,205 @@ impl RavelryClient {
         FriendsApi { client: self }
     }
 
+    /// Access group-related endpoints.
+    pub fn groups(&self) -> GroupsApi<'_> {
+        GroupsApi { client: self }
+    }
+
+    /// Access a user's pattern library.
+    pub fn library(&self) -> LibraryApi<'_> {
+        LibraryApi { client: self }
+    }
+
+    /// Access volume (book and magazine) endpoints.
+    pub fn volumes(&self) -> VolumesApi<'_> {
+        VolumesApi { client: self }
+    }
+
+    /// Access people-related endpoints (a user's comments, etc.).
+    pub fn people(&self) -> PeopleApi<'_> {
+        PeopleApi { client: self }
+    }
+
+    /// Access photo editing endpoints (captions, ordering).
+    pub fn photos(&self) -> PhotosApi<'_> {
+        PhotosApi { client: self }
+    }
+
+    /// Access a user's saved searches.
+    pub fn saved_searches(&self) -> SavedSearchesApi<'_> {
+        SavedSearchesApi { client: self }
+    }
+
+    /// Access comment-related endpoints.
+    pub fn comments(&self) -> CommentsApi<'_> {
+        CommentsApi { client: self }
+    }
+
+    /// Access forum reading endpoints (a forum's topics, a topic's posts).
+    pub fn forums(&self) -> ForumsApi<'_> {
+        ForumsApi { client: self }
+    }
+
+    /// Access forum post endpoints.
+    pub fn posts(&self) -> PostsApi<'_> {
+        PostsApi { client: self }
+    }
+
+    /// Access forum topic endpoints.
+    pub fn topics(&self) -> TopicsApi<'_> {
+        TopicsApi { client: self }
+    }
+
+    // --- Raw Requests ---
+
+    /// Send a GET request to an endpoint this crate doesn't wrap, and
+    /// deserialize the JSON response as `T`.
+    ///
+    /// This is a lower-level escape hatch: prefer the typed service methods
+    /// where they exist. `path` is relative to the base URL (e.g.
+    /// `"shops/search.json"`) and `query` is serialized as the query string;
+    /// pass `&()` for none. The request goes through the same pipeline as
+    /// every other call, so authentication, interceptors, retries, ETag
+    /// caching and error mapping all apply.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let shops: serde_json::Value = client
+    ///     .get_json("shops/search.json", &[("query", "portland")])
+    ///     .await?;
+    /// println!("{}", shops["shops"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_json<T, Q>(&self, path: &str, query: &Q) -> Result<T, RavelryError>
+    where
+        T: DeserializeOwned,
+        Q: serde::Serialize + ?Sized,
+    {
+        let req = self.get(path)?.query(query);
+        self.send_json(req).await
+    }
+
+    /// Send a POST request with a JSON body to an endpoint this crate doesn't
+    /// wrap, and deserialize the JSON response as `T`.
+    ///
+    /// Like [`get_json`](Self::get_json), this is a lower-level escape hatch
+    /// that shares the client's auth, retries and error mapping. `body` is
+    /// sent as-is; many Ravelry endpoints expect mutations wrapped in a
+    /// `{"data": ...}` object, which the caller must add.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let body = serde_json::json!({ "data": { "notes": "Gift for Mum" } });
+    /// let response: serde_json::Value = client
+    ///     .post_json("people/username/projects/123.json", &body)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn post_json<T, B>(&self, path: &str, body: &B) -> Result<T, RavelryError>
+    where
+        T: DeserializeOwned,
+        B: serde::Serialize + ?Sized,
+    {
+        let req = self.post(path)?.json(body);
+        self.send_json(req).await
+    }
+
     // --- Internal Request Helpers ---
 
+    /// Returns the client-wide default request options.
+    pub(crate) fn defaults(&self) -> &RequestOptions {
+        &self.defaults
+    }
+
+    /// Locks the username/ID cache used by [`PeopleApi`].
+    pub(crate) fn people_cache(&self) -> MutexGuard<'_, PeopleCache> {
+        self.people_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// Create a GET request for the given path.
-    pub(crate) fn get(&self, path: &str) -> RequestBuilder {
+    pub(crate) fn get(&self, path: &str) -> Result<RequestBuilder, RavelryError> {
         self.request(reqwest::Method::GET, path)
     }
 
     /// Create a POST request for the given path.
-    pub(crate) fn post(&self, path: &str) -> RequestBuilder {
+    pub(crate) fn post(&self, path: &str) -> Result<RequestBuilder, RavelryError> {
         self.request(reqwest::Method::POST, path)
     }
 
     /// Create a DELETE request for the given path.
-    pub(crate) fn delete(&self, path: &str) -> RequestBuilder {
+    pub(crate) fn delete(&self, path: &str) -> Result<RequestBuilder, RavelryError> {
         self.request(reqwest::Method::DELETE, path)
     }
 
     /// Create a PUT request for the given path.
     #[allow(dead_code)]
-    pub(crate) fn put(&self, path: &str) -> RequestBuilder {
+    pub(crate) fn put(&self, path: &str) -> Result<RequestBuilder, RavelryError> {
         self.request(reqwest::Method::PUT, path)
     }
 
     /// Create a GET request without authentication.
     ///
     /// Used for endpoints like upload status that don't require auth.
-    pub(crate) fn get_no_auth(&self, path: &str) -> RequestBuilder {
+    pub(crate) fn get_no_auth(&self, path: &str) -> Result<RequestBuilder, RavelryError> {
         self.request_with_auth(reqwest::Method::GET, path, AuthMode::None)
     }
 
     /// Create a POST request without authentication.
     ///
     /// Used for endpoints like upload/image that explicitly don't use auth.
-    pub(crate) fn post_no_auth(&self, path: &str) -> RequestBuilder {
+    pub(crate) fn post_no_auth(&self, path: &str) -> Result<RequestBuilder, RavelryError> {
         self.request_with_auth(reqwest::Method::POST, path, AuthMode::None)
     }
 
     /// Create a POST request with the common `{ "data": ... }` wrapper.
     ///
     /// Many Ravelry endpoints expect mutations to be wrapped in a `data` field.
-    pub(crate) fn post_data<T: serde::Serialize>(&self, path: &str, data: &T) -> RequestBuilder {
-        #[derive(serde::Serialize)]
-        struct Wrapper<'a, T: serde::Serialize> {
-            data: &'a T,
-        }
-
-        self.post(path).json(&Wrapper { data })
+    pub(crate) fn post_data<T: serde::Serialize>(
+        &self,
+        path: &str,
+        data: &T,
+    ) -> Result<RequestBuilder, RavelryError> {
+        // Going through a `Value` merges keys that a flattened `extra` map
+        // repeats, keeping the `extra` value; serializing directly would
+        // send the key twice.
+        let data = serde_json::to_value(data)?;
+        Ok(self.post(path)?.json(&serde_json::json!({ "data": data })))
     }
 
     /// Create a request for the given method and path (with default auth).
-    fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+    fn request(&self, method: reqwest::Method, path: &str) -> Result<RequestBuilder, RavelryError> {
         self.request_with_auth(method, path, AuthMode::Default)
     }
 
     /// Create a request with explicit auth mode control.
+    ///
+    /// Fails if the path doesn't form a valid URL or the authenticator can't
+    /// supply credentials.
     fn request_with_auth(
         &self,
         method: reqwest::Method,
         path: &str,
         auth_mode: AuthMode,
-    ) -> RequestBuilder {
-        let url = self.base_url.join(path).expect("Invalid path");
+    ) -> Result<RequestBuilder, RavelryError> {
+        let url = self.base_url.join(path)?;
         let mut req = self.http.request(method, url);
 
         // Apply authentication only if requested
         if matches!(auth_mode, AuthMode::Default) {
-            req = self.auth.apply(req);
+            req = self.auth.apply(req)?;
         }
 
         // Apply default options
@@ -206,7 +529,15 @@ impl RavelryClient {
             req = req.header("If-None-Match", etag);
         }
 
-        req
+        if let Some(since) = self.defaults.if_modified_since {
+            req = req.header("If-Modified-Since", http_date(since)?);
+        }
+
+        for interceptor in self.interceptors.iter() {
+            req = interceptor.intercept(req);
+        }
+
+        Ok(req)
     }
 
     /// Send a request and deserialize the JSON response.
@@ -214,26 +545,306 @@ impl RavelryClient {
         &self,
         req: RequestBuilder,
     ) -> Result<T, RavelryError> {
-        let resp = req.send().await?;
+        self.send_json_or_cached(req, None).await
+    }
 
-        if resp.status().is_success() {
-            Ok(resp.json().await?)
-        } else {
-            Err(map_error_response(resp).await)
+    /// Send a request and deserialize the JSON response, answering a 304
+    /// with `cached`.
+    ///
+    /// `cached` is only used when [`RequestOptions::return_cached_on_304`]
+    /// is enabled and the [`EtagStore`], if any, has no body to serve;
+    /// otherwise a 304 is still [`RavelryError::NotModified`].
+    pub(crate) async fn send_json_or_cached<T: DeserializeOwned>(
+        &self,
+        req: RequestBuilder,
+        cached: Option<T>,
+    ) -> Result<T, RavelryError> {
+        match self.fetch_json(req).await {
+            Err(RavelryError::NotModified { etag }) => match cached {
+                Some(value) if self.defaults.return_cached_on_304 => Ok(value),
+                _ => Err(RavelryError::NotModified { etag }),
+            },
+            result => result,
+        }
+    }
+
+    /// Send a request and deserialize the JSON response, going through the
+    /// [`EtagStore`] for GETs.
+    async fn fetch_json<T: DeserializeOwned>(
+        &self,
+        req: RequestBuilder,
+    ) -> Result<T, RavelryError> {
+        let Some(store) = &self.etag_store else {
+            return Ok(self.send(req).await?.json().await?);
+        };
+
+        let (http, request) = req.build_split();
+        let mut request = request?;
+        if request.method() != reqwest::Method::GET {
+            return Ok(self
+                .send(RequestBuilder::from_parts(http, request))
+                .await?
+                .json()
+                .await?);
+        }
+
+        let key = store_key(request.url());
+        let headers = request.headers_mut();
+        if !headers.contains_key(reqwest::header::IF_NONE_MATCH) {
+            if let Some(etag) = store.get(&key).and_then(|e| e.parse().ok()) {
+                headers.insert(reqwest::header::IF_NONE_MATCH, etag);
+            }
+        }
+        if !headers.contains_key(reqwest::header::IF_MODIFIED_SINCE) {
+            if let Some(date) = store.last_modified(&key).and_then(|d| d.parse().ok()) {
+                headers.insert(reqwest::header::IF_MODIFIED_SINCE, date);
+            }
+        }
+
+        match self.send(RequestBuilder::from_parts(http, request)).await {
+            Ok(resp) => {
+                let etag = response_etag(&resp);
+                let last_modified = response_last_modified(&resp);
+                let body = resp.bytes().await?;
+                if etag.is_some() || last_modified.is_some() {
+                    store.put_response(&key, etag.as_deref(), last_modified.as_deref(), &body);
+                }
+                Ok(serde_json::from_slice(&body)?)
+            }
+            Err(RavelryError::NotModified { etag }) => match store.body(&key) {
+                Some(body) => Ok(serde_json::from_slice(&body)?),
+                None => Err(RavelryError::NotModified { etag }),
+            },
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Send a GET with an explicit `If-None-Match`, treating 304 as success.
+    ///
+    /// `etag` replaces any default [`RequestOptions::if_none_match`]; with
+    /// `None` the request is unconditional. The client's [`EtagStore`], if
+    /// any, is bypassed so the caller sees exactly what the API returned.
+    pub(crate) async fn send_json_conditional<T: DeserializeOwned>(
+        &self,
+        req: RequestBuilder,
+        etag: Option<&str>,
+    ) -> Result<Conditional<T>, RavelryError> {
+        let (http, request) = req.build_split();
+        let mut request = request?;
+        let headers = request.headers_mut();
+        headers.remove(reqwest::header::IF_NONE_MATCH);
+        if let Some(etag) = etag {
+            let value = etag
+                .parse()
+                .map_err(|_| RavelryError::InvalidRequest(format!("invalid ETag: {etag:?}")))?;
+            headers.insert(reqwest::header::IF_NONE_MATCH, value);
+        }
+
+        match self.send(RequestBuilder::from_parts(http, request)).await {
+            Ok(resp) => {
+                let etag = response_etag(&resp);
+                let body = resp.json().await?;
+                Ok(Conditional::Modified { body, etag })
+            }
+            Err(RavelryError::NotModified { etag }) => Ok(Conditional::NotModified { etag }),
+            Err(e) => Err(e),
         }
     }
 
     /// Send a request that returns an empty response (for DELETE, mark_read, etc.).
     #[allow(dead_code)]
     pub(crate) async fn send_empty(&self, req: RequestBuilder) -> Result<(), RavelryError> {
-        let resp = req.send().await?;
+        self.send(req).await?;
+        Ok(())
+    }
+
+    /// Send a request, retrying per the client's [`RetryPolicy`].
+    #[cfg(not(feature = "tracing"))]
+    async fn send(&self, req: RequestBuilder) -> Result<reqwest::Response, RavelryError> {
+        self.send_with_retry(req).await
+    }
+
+    /// Send a request, retrying per the client's [`RetryPolicy`], inside a
+    /// `ravelry_request` span.
+    ///
+    /// Only the method and URL path are recorded; headers (and so
+    /// credentials) and query strings never are.
+    #[cfg(feature = "tracing")]
+    async fn send(&self, req: RequestBuilder) -> Result<reqwest::Response, RavelryError> {
+        use tracing::Instrument;
+
+        let (http, request) = req.build_split();
+        let request = request?;
+        let span = tracing::debug_span!(
+            "ravelry_request",
+            method = %request.method(),
+            path = request.url().path(),
+        );
+        let req = RequestBuilder::from_parts(http, request);
+        async move {
+            let result = self.send_with_retry(req).await;
+            if let Err(err) = &result {
+                tracing::debug!(error = %err, "request failed");
+            }
+            result
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Returns the response if it was successful, or the mapped error of the
+    /// last attempt. Requests whose body can't be cloned (multipart uploads)
+    /// are sent only once, and non-idempotent requests aren't retried after
+    /// transport errors unless [`RetryPolicy::retry_non_idempotent`] is set.
+    async fn send_with_retry(
+        &self,
+        req: RequestBuilder,
+    ) -> Result<reqwest::Response, RavelryError> {
+        let Some(policy) = &self.retry else {
+            self.last_attempts.store(1, Ordering::Relaxed);
+            return self.send_once(req).await;
+        };
+
+        let (http, request) = req.build_split();
+        let request = request?;
+        let idempotent = request.method().is_idempotent();
+        let mut req = RequestBuilder::from_parts(http, request);
+
+        let start = Instant::now();
+        let mut delays = policy.delays();
+        let mut attempts = 0;
+        loop {
+            let retry_req = req.try_clone();
+            attempts += 1;
+            let result = self.send_once(req).await;
+            self.last_attempts.store(attempts, Ordering::Relaxed);
+
+            let err = match result {
+                Ok(resp) => return Ok(resp),
+                Err(err) if !err.is_retryable() => return Err(err),
+                // The server may have handled a request whose response was lost
+                Err(err @ RavelryError::Http(_)) if !idempotent && !policy.retry_non_idempotent => {
+                    return Err(err)
+                }
+                Err(err) => err,
+            };
+            let (Some(next_req), Some(backoff)) = (retry_req, delays.next()) else {
+                return Err(err);
+            };
+            let delay = err.retry_after().unwrap_or(backoff);
+            if policy
+                .max_elapsed
+                .is_some_and(|limit| start.elapsed() + delay > limit)
+            {
+                return Err(err);
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::info!(
+                attempt = attempts,
+                delay_ms = delay.as_millis() as u64,
+                error = %err,
+                "retrying request",
+            );
+            tokio::time::sleep(delay).await;
+            req = next_req;
+        }
+    }
+
+    /// Send a request once, mapping non-success responses to errors.
+    ///
+    /// A 403 from an OAuth2 client on an endpoint with a known required scope
+    /// becomes [`RavelryError::MissingScope`], unless the token is known to
+    /// hold that scope, in which case it stays [`RavelryError::Forbidden`]. With
+    /// [`RequestOptions::check_scopes`], such a request fails that way
+    /// without being sent if the token is known to lack the scope.
+    async fn send_once(&self, req: RequestBuilder) -> Result<reqwest::Response, RavelryError> {
+        let (http, request) = req.build_split();
+        let mut request = request?;
+        if request.headers().contains_key(AUTHORIZATION) {
+            self.auth.ensure_fresh().await?;
+            self.refresh_authorization(&http, &mut request)?;
+        }
+        let required = match self.auth_kind() {
+            AuthKind::OAuth2 => {
+                let path = request.url().path();
+                let path = path.strip_prefix(self.base_url.path()).unwrap_or(path);
+                Scope::required_for(request.method(), path)
+            }
+            _ => None,
+        };
+        if let Some(required) = &required {
+            if self.defaults.check_scopes && self.has_scope(required) == Some(false) {
+                return Err(RavelryError::MissingScope {
+                    required: required.clone(),
+                });
+            }
+        }
+        #[cfg(feature = "tracing")]
+        let started = Instant::now();
+        let resp = self
+            .execute(RequestBuilder::from_parts(http, request))
+            .await?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            status = resp.status().as_u16(),
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "response received",
+        );
 
         if resp.status().is_success() {
-            Ok(())
+            Ok(resp)
+        } else if let (StatusCode::FORBIDDEN, Some(required)) = (resp.status(), required) {
+            // A token known to hold the scope was refused for another reason
+            if self.has_scope(&required) == Some(true) {
+                Err(map_error_response(resp).await)
+            } else {
+                Err(RavelryError::MissingScope { required })
+            }
         } else {
             Err(map_error_response(resp).await)
         }
     }
+
+    /// Replace a request's `Authorization` header if the authenticator's
+    /// credentials changed after the request was built.
+    fn refresh_authorization(
+        &self,
+        http: &reqwest::Client,
+        request: &mut reqwest::Request,
+    ) -> Result<(), RavelryError> {
+        let current = self.auth.apply(http.get(request.url().clone()))?.build()?;
+        if let Some(value) = current.headers().get(AUTHORIZATION) {
+            if request.headers().get(AUTHORIZATION) != Some(value) {
+                request.headers_mut().insert(AUTHORIZATION, value.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Send a request, through the cassette if one is attached.
+    async fn execute(&self, req: RequestBuilder) -> Result<reqwest::Response, RavelryError> {
+        #[cfg(feature = "test-util")]
+        let resp = match &self.cassette {
+            Some(cassette) => cassette.execute(&self.http, req.build()?).await?,
+            None => req.send().await?,
+        };
+        #[cfg(not(feature = "test-util"))]
+        let resp = req.send().await?;
+
+        self.record_rate_limit(&resp);
+        Ok(resp)
+    }
+
+    /// Remember the rate limit headers of a response, if it has any.
+    fn record_rate_limit(&self, resp: &reqwest::Response) {
+        if let Some(info) = RateLimitInfo::from_headers(resp.headers()) {
+            *self
+                .rate_limit
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(info);
+        }
+    }
 }
 
 impl std::fmt::Debug for RavelryClient {
@@ -250,6 +861,20 @@ pub struct RavelryClientBuilder {
     base_url: Url,
     auth: Box<dyn Authenticator + Send + Sync>,
     defaults: RequestOptions,
+    retry: Option<RetryPolicy>,
+    etag_store: Option<Arc<dyn EtagStore>>,
+    interceptors: Vec<Box<dyn RequestInterceptor>>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    no_proxy: bool,
+    user_agent: Option<String>,
+    #[cfg(feature = "gzip")]
+    gzip: bool,
+    #[cfg(feature = "brotli")]
+    brotli: bool,
+    #[cfg(feature = "test-util")]
+    cassette: Option<Cassette>,
 }
 
 impl RavelryClientBuilder {
@@ -262,6 +887,20 @@ impl RavelryClientBuilder {
             base_url: Url::parse(DEFAULT_BASE_URL).expect("Invalid default URL"),
             auth: Box::new(auth),
             defaults: RequestOptions::default(),
+            retry: None,
+            etag_store: None,
+            interceptors: Vec::new(),
+            timeout: None,
+            connect_timeout: None,
+            proxies: Vec::new(),
+            no_proxy: false,
+            user_agent: None,
+            #[cfg(feature = "gzip")]
+            gzip: true,
+            #[cfg(feature = "brotli")]
+            brotli: true,
+            #[cfg(feature = "test-util")]
+            cassette: None,
         }
     }
 
@@ -286,27 +925,331 @@ impl RavelryClientBuilder {
         Ok(self)
     }
 
+    /// Set options applied to every request.
+    ///
+    /// Options are merged into those already configured: flags left off and
+    /// fields left unset keep the values set by earlier calls such as
+    /// [`debug`](Self::debug), [`check_scopes`](Self::check_scopes) or
+    /// [`default_project_sort`](Self::default_project_sort).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::request_options::RequestOptions;
+    ///
+    /// # fn example() -> Result<(), ravelry::RavelryError> {
+    /// let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+    ///     .request_options(RequestOptions::new().return_cached_on_304(true))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn request_options(mut self, options: RequestOptions) -> Self {
+        let RequestOptions {
+            debug,
+            if_none_match,
+            if_modified_since,
+            return_cached_on_304,
+            default_project_sort,
+            check_scopes,
+        } = options;
+        let defaults = &mut self.defaults;
+        defaults.debug |= debug;
+        defaults.return_cached_on_304 |= return_cached_on_304;
+        defaults.check_scopes |= check_scopes;
+        if if_none_match.is_some() {
+            defaults.if_none_match = if_none_match;
+        }
+        if if_modified_since.is_some() {
+            defaults.if_modified_since = if_modified_since;
+        }
+        if default_project_sort.is_some() {
+            defaults.default_project_sort = default_project_sort;
+        }
+        self
+    }
+
     /// Enable debug mode for all requests.
     pub fn debug(mut self, enabled: bool) -> Self {
         self.defaults.debug = enabled;
         self
     }
 
+    /// Reject writes the OAuth2 token lacks the scope for, without sending
+    /// them.
+    ///
+    /// Off by default. When enabled and the authenticator knows its scopes,
+    /// a request to an endpoint with a known required scope (such as
+    /// [`MessagesApi::create`](crate::api::messages::MessagesApi::create),
+    /// which needs [`Scope::MessageWrite`]) fails with
+    /// [`RavelryError::MissingScope`] before anything is sent.
+    pub fn check_scopes(mut self, enabled: bool) -> Self {
+        self.defaults.check_scopes = enabled;
+        self
+    }
+
+    /// Set the sort order used by [`ProjectsApi::list`] when the params
+    /// don't specify one (e.g. `"created"` for newest first).
+    ///
+    /// An explicit [`ProjectsListParams::sort`](crate::api::projects::ProjectsListParams::sort)
+    /// always takes precedence.
+    pub fn default_project_sort(mut self, sort: impl Into<String>) -> Self {
+        self.defaults.default_project_sort = Some(sort.into());
+        self
+    }
+
+    /// Set a timeout for each request, from connecting until the response
+    /// body has been read.
+    ///
+    /// A request that times out fails with [`RavelryError::Http`], which
+    /// [`is_retryable`](RavelryError::is_retryable), so a [`retry`](Self::retry)
+    /// policy will try it again. By default requests never time out.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # fn example() -> Result<(), ravelry::RavelryError> {
+    /// let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set a timeout for establishing each connection.
+    ///
+    /// See [`timeout`](Self::timeout) for how timeouts are reported.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Accept gzip-compressed responses and decompress them transparently.
+    ///
+    /// On by default. Requires the `gzip` crate feature, which is enabled
+    /// by default.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(mut self, enabled: bool) -> Self {
+        self.gzip = enabled;
+        self
+    }
+
+    /// Accept brotli-compressed responses and decompress them transparently.
+    ///
+    /// On by default. Requires the `brotli` crate feature, which is enabled
+    /// by default.
+    #[cfg(feature = "brotli")]
+    pub fn brotli(mut self, enabled: bool) -> Self {
+        self.brotli = enabled;
+        self
+    }
+
+    /// Identify your application in the `User-Agent` header.
+    ///
+    /// The crate's own agent is kept after it, so `"myapp/1.0"` is sent as
+    /// `myapp/1.0 (ravelry/<version>)`. Ravelry asks API users to identify
+    /// their app this way.
+    ///
+    /// [`build`](Self::build) fails with [`RavelryError::InvalidRequest`] if
+    /// the result isn't a legal header value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # fn example() -> Result<(), ravelry::RavelryError> {
+    /// let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+    ///     .user_agent("stash-tracker/2.1 (+https://example.com)")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Route requests through `proxy`.
+    ///
+    /// May be called more than once; reqwest uses the first proxy that
+    /// matches each request. Setting a proxy replaces proxies detected from
+    /// the environment.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Parse `url` and route all requests (HTTP and HTTPS) through it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// # fn example() -> Result<(), ravelry::RavelryError> {
+    /// // Inspect traffic with mitmproxy listening on its default port.
+    /// let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+    ///     .proxy_url("http://127.0.0.1:8080")?
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn proxy_url(self, url: &str) -> Result<Self, RavelryError> {
+        let url = Url::parse(url)?;
+        Ok(self.proxy(reqwest::Proxy::all(url)?))
+    }
+
+    /// Ignore proxies configured in the environment (such as `HTTPS_PROXY`).
+    ///
+    /// Proxies set with [`proxy`](Self::proxy) are also dropped.
+    pub fn no_proxy(mut self) -> Self {
+        self.no_proxy = true;
+        self
+    }
+
+    /// Retry rate-limited and transient failures according to `policy`.
+    ///
+    /// A `Retry-After` duration sent with a 429 or 503 is used as-is;
+    /// otherwise the policy's jittered exponential backoff applies. Other
+    /// errors are returned immediately. By default requests are not retried.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use ravelry::{RavelryClient, auth::BasicAuth, retry::RetryPolicy};
+    ///
+    /// # fn example() -> Result<(), ravelry::RavelryError> {
+    /// let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+    ///     .retry(
+    ///         RetryPolicy::new()
+    ///             .max_attempts(5)
+    ///             .base_delay(Duration::from_millis(500))
+    ///             .max_elapsed(Duration::from_secs(60)),
+    ///     )
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Send conditional GET requests using ETags from `store`.
+    ///
+    /// See [`crate::etag`] for how the store is consulted and updated.
+    pub fn etag_store<S>(mut self, store: S) -> Self
+    where
+        S: EtagStore + 'static,
+    {
+        self.etag_store = Some(Arc::new(store));
+        self
+    }
+
+    /// Cache GET responses in memory and revalidate them with ETags.
+    ///
+    /// Shorthand for [`etag_store`](Self::etag_store) with an [`EtagCache`].
+    pub fn etag_cache(self, cache: EtagCache) -> Self {
+        self.etag_store(cache)
+    }
+
+    /// Run `interceptor` on every request after authentication is applied.
+    ///
+    /// Interceptors run in registration order; see [`crate::interceptor`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ravelry::{RavelryClient, auth::BasicAuth};
+    ///
+    /// # fn example() -> Result<(), ravelry::RavelryError> {
+    /// let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+    ///     .interceptor(|req: reqwest::RequestBuilder| req.header("X-Request-Id", "abc123"))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn interceptor<I>(mut self, interceptor: I) -> Self
+    where
+        I: RequestInterceptor + 'static,
+    {
+        self.interceptors.push(Box::new(interceptor));
+        self
+    }
+
+    /// Record or replay all requests through a [`Cassette`].
+    ///
+    /// Requires the `test-util` feature.
+    #[cfg(feature = "test-util")]
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
     /// Build the client.
     pub fn build(self) -> Result<RavelryClient, RavelryError> {
-        let http = reqwest::Client::builder()
-            .user_agent(concat!(
-                env!("CARGO_PKG_NAME"),
-                "/",
-                env!("CARGO_PKG_VERSION")
-            ))
-            .build()?;
+        const DEFAULT_USER_AGENT: &str =
+            concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+        let user_agent = match &self.user_agent {
+            Some(app) => format!("{app} ({DEFAULT_USER_AGENT})"),
+            None => DEFAULT_USER_AGENT.to_string(),
+        };
+        let user_agent = reqwest::header::HeaderValue::from_str(&user_agent).map_err(|_| {
+            RavelryError::InvalidRequest(format!("invalid user agent: {user_agent:?}"))
+        })?;
+        let mut http = reqwest::Client::builder().user_agent(user_agent);
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        #[cfg(feature = "gzip")]
+        {
+            http = http.gzip(self.gzip);
+        }
+        #[cfg(feature = "brotli")]
+        {
+            http = http.brotli(self.brotli);
+        }
+        if self.no_proxy {
+            http = http.no_proxy();
+        } else {
+            for proxy in self.proxies {
+                http = http.proxy(proxy);
+            }
+        }
+        let http = Arc::new(http.build()?);
 
         Ok(RavelryClient {
             http,
             base_url: self.base_url,
             auth: self.auth,
             defaults: self.defaults,
+            people_cache: Mutex::default(),
+            rate_limit: Mutex::default(),
+            retry: self.retry,
+            last_attempts: AtomicU32::new(0),
+            etag_store: self.etag_store,
+            interceptors: self.interceptors.into(),
+            #[cfg(feature = "test-util")]
+            cassette: self.cassette,
         })
     }
+
+    /// Build a [blocking client](crate::blocking::RavelryClient).
+    ///
+    /// Requires the `blocking` feature.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::RavelryClient, RavelryError> {
+        crate::blocking::RavelryClient::from_async(self.build()?)
+    }
 }
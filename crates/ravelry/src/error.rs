@@ -3,14 +3,22 @@
 use reqwest::StatusCode;
 use std::time::Duration;
 
+use crate::auth::{OAuth2ErrorKind, Scope};
+use crate::request_options::parse_http_date;
+
 /// The main error type for Ravelry API operations.
 #[derive(thiserror::Error, Debug)]
 pub enum RavelryError {
     /// An HTTP transport error occurred.
+    ///
+    /// Credential-like query parameters in the error's URL are replaced
+    /// with `[REDACTED]`.
     #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(reqwest::Error),
 
     /// The API returned a non-success status code.
+    ///
+    /// Credential-like fields in `body` are replaced with `[REDACTED]`.
     #[error("API error {status}: {body}")]
     ApiStatus {
         /// The HTTP status code
@@ -19,6 +27,28 @@ pub enum RavelryError {
         body: serde_json::Value,
     },
 
+    /// The API rejected the credentials (HTTP 401).
+    ///
+    /// The credentials are missing, wrong or expired; log in again.
+    /// Credential-like fields in `body` are replaced with `[REDACTED]`.
+    #[error("Unauthorized: {body}")]
+    Unauthorized {
+        /// The response body (parsed as JSON if possible)
+        body: serde_json::Value,
+    },
+
+    /// The credentials are valid but not allowed to make this request
+    /// (HTTP 403).
+    ///
+    /// OAuth2 clients calling an endpoint with a known required scope get
+    /// [`MissingScope`](Self::MissingScope) instead. Credential-like fields
+    /// in `body` are replaced with `[REDACTED]`.
+    #[error("Forbidden: {body}")]
+    Forbidden {
+        /// The response body (parsed as JSON if possible)
+        body: serde_json::Value,
+    },
+
     /// Rate limited by the API. Check `retry_after` for when to retry.
     #[error("Rate limited, retry after {retry_after:?}")]
     RateLimited {
@@ -28,7 +58,15 @@ pub enum RavelryError {
         body: Option<serde_json::Value>,
     },
 
-    /// Resource not modified (ETag matched).
+    /// The API is temporarily unavailable (HTTP 503), e.g. during planned
+    /// maintenance. Check `retry_after` for when to retry.
+    #[error("Service unavailable, retry after {retry_after:?}")]
+    ServiceUnavailable {
+        /// Duration to wait before retrying (from Retry-After header)
+        retry_after: Option<Duration>,
+    },
+
+    /// Resource not modified (ETag or `If-Modified-Since` date matched).
     #[error("Not modified (ETag match)")]
     NotModified {
         /// The ETag value, if present
@@ -39,6 +77,31 @@ pub enum RavelryError {
     #[error("Authentication error: {0}")]
     Auth(String),
 
+    /// The OAuth2 token endpoint rejected a code exchange or refresh.
+    ///
+    /// Use [`OAuth2ErrorKind::requires_reauth`] to tell an expired or revoked
+    /// grant (log in again) from other failures.
+    #[error("OAuth2 error {status}: {kind}{}", description.as_deref().map(|d| format!(" ({d})")).unwrap_or_default())]
+    OAuth2 {
+        /// The HTTP status code
+        status: StatusCode,
+        /// The OAuth2 `error` code
+        kind: OAuth2ErrorKind,
+        /// The `error_description`, if provided
+        description: Option<String>,
+    },
+
+    /// An OAuth2 token lacks the scope an endpoint requires (HTTP 403).
+    ///
+    /// Only reported for OAuth2 clients calling endpoints with a known
+    /// required scope, and not when the token is known to hold that scope;
+    /// re-authorize with `required` added to the scopes.
+    #[error("Missing OAuth2 scope: {required}")]
+    MissingScope {
+        /// The scope the endpoint requires
+        required: Scope,
+    },
+
     /// URL parsing error.
     #[error("URL error: {0}")]
     Url(#[from] url::ParseError),
@@ -58,23 +121,178 @@ pub enum RavelryError {
 
 impl RavelryError {
     /// Returns `true` if this error is retryable.
+    ///
+    /// Only rate limiting, service unavailability and timeouts or connection
+    /// failures are; authentication failures and all other errors are not.
     pub fn is_retryable(&self) -> bool {
         match self {
             RavelryError::RateLimited { .. } => true,
+            RavelryError::ServiceUnavailable { .. } => true,
             RavelryError::Http(e) if e.is_timeout() || e.is_connect() => true,
             _ => false,
         }
     }
 
-    /// If rate limited, returns the duration to wait before retrying.
+    /// Returns the HTTP status code behind this error, if it came from a response.
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match self {
+            RavelryError::Http(e) => e.status(),
+            RavelryError::ApiStatus { status, .. } => Some(*status),
+            RavelryError::Unauthorized { .. } => Some(StatusCode::UNAUTHORIZED),
+            RavelryError::Forbidden { .. } => Some(StatusCode::FORBIDDEN),
+            RavelryError::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            RavelryError::ServiceUnavailable { .. } => Some(StatusCode::SERVICE_UNAVAILABLE),
+            RavelryError::NotModified { .. } => Some(StatusCode::NOT_MODIFIED),
+            RavelryError::OAuth2 { status, .. } => Some(*status),
+            RavelryError::MissingScope { .. } => Some(StatusCode::FORBIDDEN),
+            _ => None,
+        }
+    }
+
+    /// Returns the `error` message from the API's JSON error body, if any.
+    ///
+    /// Ravelry reports most failures as `{ "error": "..." }`. Bodies that
+    /// weren't JSON are kept under `"raw"` and yield `None` here.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, RavelryError};
+    /// # async fn example(client: &RavelryClient) {
+    /// if let Err(e) = client.patterns().show(1).await {
+    ///     eprintln!("{:?}: {}", e.status_code(), e.api_message().unwrap_or("unknown error"));
+    /// }
+    /// # }
+    /// ```
+    pub fn api_message(&self) -> Option<&str> {
+        let body = match self {
+            RavelryError::ApiStatus { body, .. }
+            | RavelryError::Unauthorized { body }
+            | RavelryError::Forbidden { body } => body,
+            RavelryError::RateLimited {
+                body: Some(body), ..
+            } => body,
+            _ => return None,
+        };
+        body.get("error").and_then(serde_json::Value::as_str)
+    }
+
+    /// If rate limited or unavailable, returns the duration to wait before retrying.
     pub fn retry_after(&self) -> Option<Duration> {
         match self {
             RavelryError::RateLimited { retry_after, .. } => *retry_after,
+            RavelryError::ServiceUnavailable { retry_after } => *retry_after,
             _ => None,
         }
     }
 }
 
+impl From<reqwest::Error> for RavelryError {
+    fn from(mut err: reqwest::Error) -> Self {
+        if let Some(url) = err.url_mut() {
+            redact_url(url);
+        }
+        RavelryError::Http(err)
+    }
+}
+
+/// Replacement for redacted secrets.
+const REDACTED: &str = "[REDACTED]";
+
+/// Returns `true` if a JSON field named `name` may hold a credential.
+fn is_sensitive(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    matches!(name.as_str(), "password" | "personal_key")
+        || name.ends_with("access_key")
+        || name.contains("secret")
+        || name.contains("token")
+}
+
+/// Returns `true` if a URL query parameter named `name` may hold a
+/// credential.
+///
+/// Unlike a JSON field, a `code` parameter is an OAuth authorization code.
+fn is_sensitive_param(name: &str) -> bool {
+    name.eq_ignore_ascii_case("code") || is_sensitive(name)
+}
+
+/// Replaces the values of credential-like query parameters in `url`.
+///
+/// The rest of the query is kept as sent, and the placeholder is written
+/// unescaped so it reads as `[REDACTED]` when the URL is printed.
+fn redact_url(url: &mut url::Url) {
+    let Some(query) = url.query() else {
+        return;
+    };
+    let mut redacted = false;
+    let pairs: Vec<String> = query
+        .split('&')
+        .map(|pair| {
+            let name = pair.split('=').next().unwrap_or_default();
+            let decoded = url::form_urlencoded::parse(name.as_bytes())
+                .next()
+                .map(|(name, _)| name)
+                .unwrap_or_default();
+            if is_sensitive_param(&decoded) {
+                redacted = true;
+                format!("{name}={REDACTED}")
+            } else {
+                pair.to_string()
+            }
+        })
+        .collect();
+    if redacted {
+        url.set_query(Some(&pairs.join("&")));
+    }
+}
+
+/// Replaces credential-like fields in a JSON error body, and credential-like
+/// `name=value` pairs in any string it contains.
+fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive(key) && !value.is_null() {
+                    *value = serde_json::Value::from(REDACTED);
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        serde_json::Value::String(text) => *text = redact_text(text),
+        _ => {}
+    }
+}
+
+/// Replaces the values of credential-like `name=value` pairs in free text,
+/// such as a URL echoed in an HTML error page.
+fn redact_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(eq) = rest.find('=') {
+        let (before, after) = (&rest[..eq], &rest[eq + 1..]);
+        // Names are ASCII, so the character count is also the byte length.
+        let name_len = before
+            .chars()
+            .rev()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+            .count();
+        out.push_str(before);
+        out.push('=');
+        rest = after;
+        if is_sensitive_param(&before[before.len() - name_len..]) {
+            let end = after
+                .find(|c: char| matches!(c, '&' | '"' | '\'' | '<' | '>') || c.is_whitespace())
+                .unwrap_or(after.len());
+            out.push_str(REDACTED);
+            rest = &after[end..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
 /// Maps an HTTP response to a `RavelryError`.
 ///
 /// This is used internally by the client to convert non-success responses.
@@ -93,25 +311,46 @@ pub(crate) async fn map_error_response(resp: reqwest::Response) -> RavelryError
 
     // Handle 429 Rate Limited
     if status == StatusCode::TOO_MANY_REQUESTS {
-        let retry_after = resp
-            .headers()
-            .get("retry-after")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|s| s.parse::<u64>().ok())
-            .map(Duration::from_secs);
-
-        let body = resp.json::<serde_json::Value>().await.ok();
+        let retry_after = parse_retry_after(&resp);
+        let mut body = resp.json::<serde_json::Value>().await.ok();
+        if let Some(body) = &mut body {
+            redact_json(body);
+        }
 
         return RavelryError::RateLimited { retry_after, body };
     }
 
+    // Handle 503 Service Unavailable (maintenance)
+    if status == StatusCode::SERVICE_UNAVAILABLE {
+        let retry_after = parse_retry_after(&resp);
+        return RavelryError::ServiceUnavailable { retry_after };
+    }
+
     // Try to parse body as JSON, fallback to raw text
-    let body = match resp.text().await {
+    let mut body = match resp.text().await {
         Ok(text) => {
             serde_json::from_str(&text).unwrap_or_else(|_| serde_json::json!({ "raw": text }))
         }
         Err(_) => serde_json::json!({ "error": "Failed to read response body" }),
     };
+    redact_json(&mut body);
 
-    RavelryError::ApiStatus { status, body }
+    match status {
+        StatusCode::UNAUTHORIZED => RavelryError::Unauthorized { body },
+        StatusCode::FORBIDDEN => RavelryError::Forbidden { body },
+        _ => RavelryError::ApiStatus { status, body },
+    }
+}
+
+/// Parses a `Retry-After` header given in seconds or as an HTTP-date.
+///
+/// A date in the past means the client may retry immediately.
+fn parse_retry_after(resp: &reqwest::Response) -> Option<Duration> {
+    let value = resp.headers().get("retry-after")?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = parse_http_date(value)?;
+    let wait = at - time::OffsetDateTime::now_utc();
+    Some(Duration::try_from(wait).unwrap_or(Duration::ZERO))
 }
@@ -0,0 +1,267 @@
+//! Pluggable ETag storage for conditional GET requests.
+//!
+//! Implement [`EtagStore`] over any cache (memory, disk, Redis, ...) and
+//! attach it with [`RavelryClientBuilder::etag_store`](crate::RavelryClientBuilder::etag_store).
+//! Before each GET, the client asks the store for an ETag and sends it as
+//! `If-None-Match`; after each successful GET that returns an ETag, it hands
+//! the ETag and body back to the store. Stores that also keep `Last-Modified`
+//! dates (see [`EtagStore::put_response`]) get `If-Modified-Since` sent too.
+//!
+//! When the API answers `304 Not Modified`, the client serves the body from
+//! [`EtagStore::body`] if the store keeps bodies, and otherwise returns
+//! [`RavelryError::NotModified`](crate::RavelryError::NotModified).
+//!
+//! [`EtagCache`] is a ready-made in-memory store that keeps bodies; attach it
+//! with [`RavelryClientBuilder::etag_cache`](crate::RavelryClientBuilder::etag_cache).
+//!
+//! # Example
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use std::sync::Mutex;
+//! use ravelry::etag::EtagStore;
+//!
+//! #[derive(Default)]
+//! struct MemoryStore(Mutex<HashMap<String, (String, Vec<u8>)>>);
+//!
+//! impl EtagStore for MemoryStore {
+//!     fn get(&self, path: &str) -> Option<String> {
+//!         self.0.lock().unwrap().get(path).map(|(etag, _)| etag.clone())
+//!     }
+//!
+//!     fn put(&self, path: &str, etag: &str, body: &[u8]) {
+//!         let entry = (etag.to_string(), body.to_vec());
+//!         self.0.lock().unwrap().insert(path.to_string(), entry);
+//!     }
+//!
+//!     fn body(&self, path: &str) -> Option<Vec<u8>> {
+//!         self.0.lock().unwrap().get(path).map(|(_, body)| body.clone())
+//!     }
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Storage for ETags (and optionally response bodies), keyed by request path.
+///
+/// Keys are the request path and query string without the host, e.g.
+/// `/patterns/123.json` or `/patterns/search.json?query=hat`. Implementations
+/// are called from async code and should not block for long.
+pub trait EtagStore: Send + Sync {
+    /// Returns the stored ETag for `path`, if any.
+    fn get(&self, path: &str) -> Option<String>;
+
+    /// Store the ETag and body of a successful response to `path`.
+    fn put(&self, path: &str, etag: &str, body: &[u8]);
+
+    /// Returns the stored body for `path`, used to answer a 304.
+    ///
+    /// The default returns `None`, in which case a 304 surfaces as
+    /// [`RavelryError::NotModified`](crate::RavelryError::NotModified).
+    fn body(&self, path: &str) -> Option<Vec<u8>> {
+        let _ = path;
+        None
+    }
+
+    /// Returns the stored `Last-Modified` date for `path`, if any.
+    ///
+    /// When present, it is sent as `If-Modified-Since`. The default returns
+    /// `None`.
+    fn last_modified(&self, path: &str) -> Option<String> {
+        let _ = path;
+        None
+    }
+
+    /// Store the validators and body of a successful response to `path`.
+    ///
+    /// Called for every successful GET that returns an `ETag` or a
+    /// `Last-Modified` header. The default stores the ETag with
+    /// [`put`](Self::put) and ignores `last_modified`; override it, along
+    /// with [`last_modified`](Self::last_modified), to revalidate by date.
+    fn put_response(
+        &self,
+        path: &str,
+        etag: Option<&str>,
+        last_modified: Option<&str>,
+        body: &[u8],
+    ) {
+        let _ = last_modified;
+        if let Some(etag) = etag {
+            self.put(path, etag, body);
+        }
+    }
+}
+
+/// A cached response's validators and body.
+#[derive(Debug, Clone, Default)]
+struct CacheEntry {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: Vec<u8>,
+}
+
+/// Cached responses, keyed by request path.
+type CacheEntries = HashMap<String, CacheEntry>;
+
+/// An in-memory [`EtagStore`] that keeps response bodies.
+///
+/// Repeated GETs of an unchanged resource are answered with `304 Not
+/// Modified` and served from the cache, so they cost a round trip but not
+/// the body. Responses are revalidated by ETag and, when the API sends one,
+/// by `Last-Modified` date. Entries are never evicted; call [`clear`](Self::clear) to drop
+/// them.
+///
+/// Cloning a cache is cheap; clones share the same entries.
+///
+/// # Example
+///
+/// ```no_run
+/// use ravelry::{RavelryClient, auth::BasicAuth};
+/// use ravelry::etag::EtagCache;
+///
+/// # async fn example() -> Result<(), ravelry::RavelryError> {
+/// let cache = EtagCache::new();
+/// let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+///     .etag_cache(cache.clone())
+///     .build()?;
+///
+/// client.patterns().show(12345).await?;
+/// // Sent with If-None-Match; a 304 is answered from the cache.
+/// client.patterns().show(12345).await?;
+/// assert_eq!(cache.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EtagCache {
+    entries: Arc<Mutex<CacheEntries>>,
+}
+
+impl EtagCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached responses.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Drop all cached responses.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheEntries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl EtagStore for EtagCache {
+    fn get(&self, path: &str) -> Option<String> {
+        self.lock().get(path).and_then(|entry| entry.etag.clone())
+    }
+
+    fn put(&self, path: &str, etag: &str, body: &[u8]) {
+        self.put_response(path, Some(etag), None, body);
+    }
+
+    fn body(&self, path: &str) -> Option<Vec<u8>> {
+        self.lock().get(path).map(|entry| entry.body.clone())
+    }
+
+    fn last_modified(&self, path: &str) -> Option<String> {
+        self.lock()
+            .get(path)
+            .and_then(|entry| entry.last_modified.clone())
+    }
+
+    fn put_response(
+        &self,
+        path: &str,
+        etag: Option<&str>,
+        last_modified: Option<&str>,
+        body: &[u8],
+    ) {
+        let entry = CacheEntry {
+            etag: etag.map(String::from),
+            last_modified: last_modified.map(String::from),
+            body: body.to_vec(),
+        };
+        self.lock().insert(path.to_string(), entry);
+    }
+}
+
+/// The outcome of a conditional GET made with an explicit ETag.
+///
+/// Returned by per-call conditional methods such as
+/// [`PatternsApi::show_conditional`](crate::api::patterns::PatternsApi::show_conditional),
+/// which treat `304 Not Modified` as success rather than an error.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conditional<T> {
+    /// The resource changed (or no ETag was sent); here is the new body.
+    Modified {
+        /// The parsed response body.
+        body: T,
+        /// The response's ETag, to send on the next request.
+        etag: Option<String>,
+    },
+    /// The resource still matches the ETag that was sent.
+    NotModified {
+        /// The ETag echoed by the API, if any.
+        etag: Option<String>,
+    },
+}
+
+impl<T> Conditional<T> {
+    /// Returns `true` if a new body was returned.
+    pub fn is_modified(&self) -> bool {
+        matches!(self, Self::Modified { .. })
+    }
+
+    /// Returns the ETag of the response, if any.
+    pub fn etag(&self) -> Option<&str> {
+        match self {
+            Self::Modified { etag, .. } | Self::NotModified { etag } => etag.as_deref(),
+        }
+    }
+
+    /// Returns the new body, or `None` if the resource was not modified.
+    pub fn into_body(self) -> Option<T> {
+        match self {
+            Self::Modified { body, .. } => Some(body),
+            Self::NotModified { .. } => None,
+        }
+    }
+}
+
+/// Returns the `ETag` header of `resp`, if present.
+pub(crate) fn response_etag(resp: &reqwest::Response) -> Option<String> {
+    resp.headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+}
+
+/// Returns the `Last-Modified` header of `resp`, if present.
+pub(crate) fn response_last_modified(resp: &reqwest::Response) -> Option<String> {
+    resp.headers()
+        .get(reqwest::header::LAST_MODIFIED)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+}
+
+/// The key used for `url` in an [`EtagStore`].
+pub(crate) fn store_key(url: &url::Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    }
+}
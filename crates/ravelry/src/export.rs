@@ -0,0 +1,161 @@
+//! Stable, versioned export schemas.
+//!
+//! The API types in [`crate::types`] track Ravelry's responses closely and
+//! capture unmodeled fields in `extra`, so their serialized form changes
+//! whenever Ravelry's does. The structs in this module are a fixed projection
+//! of those types, intended for apps that persist Ravelry data in their own
+//! store.
+//!
+//! Every exported record carries a `schema_version`. Fields are only ever
+//! added, renamed or removed alongside a bump of [`SCHEMA_VERSION`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use ravelry::{RavelryClient, auth::BasicAuth};
+//! use ravelry::export::to_stable_json;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+//! let response = client.projects().show("username", "1", &Default::default()).await?;
+//! let stable = to_stable_json(&response.project);
+//! let json = serde_json::to_string(&stable)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::ProjectFull;
+
+/// Current version of the export schema.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// A project in the stable export schema (version 1).
+///
+/// Unlike [`ProjectFull`], this struct never includes unmodeled API fields.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct StableProject {
+    /// Version of the schema this record was written with.
+    pub schema_version: u32,
+
+    /// Ravelry project ID.
+    pub id: u64,
+
+    /// Project name.
+    pub name: String,
+
+    /// URL-friendly unique identifier.
+    pub permalink: String,
+
+    /// Linked pattern ID, if any.
+    pub pattern_id: Option<u64>,
+
+    /// Linked pattern name, if any.
+    pub pattern_name: Option<String>,
+
+    /// Project status ID.
+    pub status_id: Option<u64>,
+
+    /// Project status name.
+    pub status_name: Option<String>,
+
+    /// Progress percentage (0-100).
+    pub progress: Option<u32>,
+
+    /// When the project was started, as returned by the API.
+    pub started: Option<String>,
+
+    /// When the project was completed, as returned by the API.
+    pub completed: Option<String>,
+
+    /// Project notes (markdown/text).
+    pub notes: Option<String>,
+
+    /// Happiness rating (1-4).
+    pub rating: Option<u32>,
+
+    /// Number of favorites.
+    pub favorites_count: Option<u64>,
+
+    /// Number of comments.
+    pub comments_count: Option<u64>,
+
+    /// Whether the project appears in the owner's showcase.
+    pub include_in_showcase: Option<bool>,
+}
+
+/// Map a project into the stable export schema.
+///
+/// Fields captured in [`ProjectFull::extra`] are dropped, as is the
+/// derived `notes_html`.
+pub fn to_stable_json(project: &ProjectFull) -> StableProject {
+    StableProject {
+        schema_version: SCHEMA_VERSION,
+        id: project.id,
+        name: project.name.clone(),
+        permalink: project.permalink.clone(),
+        pattern_id: project.pattern_id,
+        pattern_name: project.pattern_name.clone(),
+        status_id: project.status_id,
+        status_name: project.status_name.clone(),
+        progress: project.progress,
+        started: project.started.clone(),
+        completed: project.completed.clone(),
+        notes: project.notes.clone(),
+        rating: project.rating,
+        favorites_count: project.favorites_count,
+        comments_count: project.comments_count,
+        include_in_showcase: project.include_in_showcase,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stable_project_omits_extra() {
+        let project: ProjectFull = serde_json::from_value(serde_json::json!({
+            "id": 42,
+            "name": "Cardigan",
+            "permalink": "cardigan",
+            "pattern_id": 7,
+            "progress": 80,
+            "notes_html": "<p>Nearly done</p>",
+            "comments_count": 2,
+            "some_new_field": "volatile"
+        }))
+        .unwrap();
+        assert!(project.extra.contains_key("some_new_field"));
+
+        let value = serde_json::to_value(to_stable_json(&project)).unwrap();
+        let obj = value.as_object().unwrap();
+
+        assert_eq!(obj["schema_version"], SCHEMA_VERSION);
+        assert_eq!(obj["id"], 42);
+        assert_eq!(obj["name"], "Cardigan");
+        assert_eq!(obj["pattern_id"], 7);
+        assert_eq!(obj["progress"], 80);
+        assert_eq!(obj["comments_count"], 2);
+        assert!(obj.contains_key("include_in_showcase"));
+        assert!(!obj.contains_key("some_new_field"));
+        assert!(!obj.contains_key("notes_html"));
+        assert!(!obj.contains_key("extra"));
+    }
+
+    #[test]
+    fn test_stable_project_round_trips() {
+        let project: ProjectFull = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "name": "Hat",
+            "permalink": "hat"
+        }))
+        .unwrap();
+
+        let stable = to_stable_json(&project);
+        let json = serde_json::to_string(&stable).unwrap();
+        let parsed: StableProject = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, stable);
+    }
+}
@@ -0,0 +1,56 @@
+//! Hooks for customizing every outgoing request.
+//!
+//! Register a [`RequestInterceptor`] with
+//! [`RavelryClientBuilder::interceptor`](crate::RavelryClientBuilder::interceptor)
+//! to add headers, log requests, or otherwise adjust each request before it
+//! is sent. Interceptors run after authentication has been applied, in the
+//! order they were registered. A request that is retried is not intercepted
+//! again. If the authenticator's credentials change before the request is
+//! sent (see [`Authenticator::ensure_fresh`](crate::Authenticator::ensure_fresh)),
+//! the `Authorization` header is replaced.
+//!
+//! Any `Fn(RequestBuilder) -> RequestBuilder` closure is an interceptor.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use ravelry::{RavelryClient, auth::BasicAuth};
+//! use ravelry::interceptor::RequestInterceptor;
+//! use reqwest::RequestBuilder;
+//!
+//! struct LogUrl;
+//!
+//! impl RequestInterceptor for LogUrl {
+//!     fn intercept(&self, req: RequestBuilder) -> RequestBuilder {
+//!         if let Some(Ok(request)) = req.try_clone().map(|r| r.build()) {
+//!             eprintln!("{} {}", request.method(), request.url());
+//!         }
+//!         req
+//!     }
+//! }
+//!
+//! # fn example() -> Result<(), ravelry::RavelryError> {
+//! let client = RavelryClient::builder(BasicAuth::new("key", "secret"))
+//!     .interceptor(|req: RequestBuilder| req.header("X-Request-Id", "abc123"))
+//!     .interceptor(LogUrl)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::RequestBuilder;
+
+/// Adjusts a request before it is sent.
+pub trait RequestInterceptor: Send + Sync {
+    /// Returns the request to send in place of `req`.
+    fn intercept(&self, req: RequestBuilder) -> RequestBuilder;
+}
+
+impl<F> RequestInterceptor for F
+where
+    F: Fn(RequestBuilder) -> RequestBuilder + Send + Sync,
+{
+    fn intercept(&self, req: RequestBuilder) -> RequestBuilder {
+        self(req)
+    }
+}
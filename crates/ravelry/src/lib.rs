@@ -21,16 +21,32 @@
 
 pub mod api;
 pub mod auth;
+pub mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "test-util")]
+pub mod cassette;
 pub mod client;
 pub mod error;
+pub mod etag;
+pub mod export;
+pub mod interceptor;
 pub mod pagination;
+pub mod rate_limit;
 pub mod request_options;
+pub mod retry;
+pub mod serde_helpers;
 pub mod types;
+pub mod username;
 
 // Re-export main entry points for ergonomic usage
+pub use batch::BatchResult;
 pub use client::{RavelryClient, RavelryClientBuilder};
 pub use error::RavelryError;
-pub use pagination::{PageParams, Paginator};
+pub use etag::Conditional;
+pub use pagination::{Direction, Page, PageParams, Paginator};
+pub use rate_limit::RateLimitInfo;
+pub use username::normalize_username;
 
 // Re-export auth types
 pub use auth::{AuthKind, Authenticator, BasicAuth, OAuth2Auth, OAuth2Token, RavelryOAuth2Client};
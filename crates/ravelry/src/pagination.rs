@@ -1,7 +1,14 @@
 //! Pagination types for Ravelry API requests and responses.
 
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
+
 /// Parameters for paginated requests.
 ///
 /// # Example
@@ -41,6 +48,53 @@ impl PageParams {
         self.page_size = Some(size);
         self
     }
+
+    /// Params for the page after `paginator`'s, or `None` on the last page.
+    ///
+    /// The page size is taken from `paginator`, so it stays the same across
+    /// navigation.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::api::patterns::PatternSearchParams;
+    /// use ravelry::PageParams;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let mut params = PatternSearchParams::new().query("hat").page_size(20);
+    /// loop {
+    ///     let response = client.patterns().search(&params).await?;
+    ///     println!("{} patterns", response.patterns.len());
+    ///     match PageParams::from_paginator_next(&response.paginator) {
+    ///         Some(next) => params.page = next,
+    ///         None => break,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_paginator_next(paginator: &Paginator) -> Option<Self> {
+        Self::from_paginator(paginator, Direction::Next)
+    }
+
+    /// Params for the page before `paginator`'s, or `None` on the first page.
+    ///
+    /// The page size is taken from `paginator`.
+    pub fn from_paginator_prev(paginator: &Paginator) -> Option<Self> {
+        Self::from_paginator(paginator, Direction::Prev)
+    }
+
+    /// Params for the page in `direction` from `paginator`'s, if it exists.
+    ///
+    /// The page size is taken from `paginator`.
+    pub fn from_paginator(paginator: &Paginator, direction: Direction) -> Option<Self> {
+        paginator.page_in(direction).map(|page| Self {
+            page: Some(page),
+            page_size: Some(paginator.page_size),
+        })
+    }
 }
 
 /// Pagination metadata from API responses.
@@ -84,12 +138,151 @@ impl Paginator {
             None
         }
     }
+
+    /// Returns the page number reached by moving in `direction`, if it exists.
+    pub fn page_in(&self, direction: Direction) -> Option<u32> {
+        match direction {
+            Direction::Next => self.next_page(),
+            Direction::Prev => self.has_prev().then(|| self.page - 1),
+            Direction::First => Some(1),
+            Direction::Last => Some(self.last_page.max(1)),
+        }
+    }
+}
+
+/// A direction to move in relative to the current page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The following page.
+    Next,
+    /// The preceding page.
+    Prev,
+    /// The first page.
+    First,
+    /// The last page.
+    Last,
+}
+
+/// Future resolving to a [`Page`] borrowed from a client.
+type PageFuture<'c, T> = BoxFuture<'c, Result<Page<T>, RavelryError>>;
+
+/// Fetches a given page number, reusing the params of the original request.
+type FetchPage<T> = Arc<dyn for<'c> Fn(&'c RavelryClient, u32) -> PageFuture<'c, T> + Send + Sync>;
+
+/// A single page of results that knows how to fetch its neighbours.
+///
+/// Unlike [`paginate`], a `Page` is a stateful handle: it keeps the params
+/// of the request that produced it, so a UI can hold on to it and move
+/// forwards or backwards on demand.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ravelry::{RavelryClient, auth::BasicAuth};
+/// use ravelry::api::patterns::PatternSearchParams;
+///
+/// # async fn example() -> Result<(), ravelry::RavelryError> {
+/// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+/// let params = PatternSearchParams::new().query("hat").page_size(20);
+/// let page = client.patterns().search_page(&params).await?;
+/// println!("page {} of {}", page.paginator.page, page.paginator.page_count);
+///
+/// if let Some(next) = page.next(&client).await? {
+///     println!("{} more patterns", next.items.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Page<T> {
+    /// The items on this page.
+    pub items: Vec<T>,
+
+    /// Pagination information for this page.
+    pub paginator: Paginator,
+
+    fetch: FetchPage<T>,
+}
+
+impl<T> Page<T> {
+    pub(crate) fn new(items: Vec<T>, paginator: Paginator, fetch: FetchPage<T>) -> Self {
+        Self {
+            items,
+            paginator,
+            fetch,
+        }
+    }
+
+    /// Fetch the following page, or `None` if this is the last page.
+    pub async fn next(&self, client: &RavelryClient) -> Result<Option<Page<T>>, RavelryError> {
+        self.relative(client, Direction::Next).await
+    }
+
+    /// Fetch the preceding page, or `None` if this is the first page.
+    pub async fn prev(&self, client: &RavelryClient) -> Result<Option<Page<T>>, RavelryError> {
+        self.relative(client, Direction::Prev).await
+    }
+
+    /// Fetch the page in `direction`, or `None` if there is no such page.
+    pub async fn relative(
+        &self,
+        client: &RavelryClient,
+        direction: Direction,
+    ) -> Result<Option<Page<T>>, RavelryError> {
+        match self.paginator.page_in(direction) {
+            Some(page) => (self.fetch)(client, page).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch an arbitrary page of the same request.
+    pub async fn goto(&self, client: &RavelryClient, page: u32) -> Result<Page<T>, RavelryError> {
+        (self.fetch)(client, page).await
+    }
+}
+
+impl<T> Clone for Page<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            paginator: self.paginator.clone(),
+            fetch: Arc::clone(&self.fetch),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Page<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Page")
+            .field("items", &self.items)
+            .field("paginator", &self.paginator)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Build the fetcher stored in a [`Page`] from the request params and a
+/// function that fetches one page for them.
+pub(crate) fn page_fetcher<T, P>(
+    params: P,
+    fetch: for<'c> fn(&'c RavelryClient, P, u32) -> PageFuture<'c, T>,
+) -> FetchPage<T>
+where
+    T: 'static,
+    P: Clone + Send + Sync + 'static,
+{
+    Arc::new(move |client, page| fetch(client, params.clone(), page))
 }
 
 /// Collect all pages from a paginated endpoint.
 ///
 /// This is a helper for CLI `--all` flags and similar use cases where you
-/// want to fetch all results regardless of pagination.
+/// want to fetch all results regardless of pagination. It buffers every item;
+/// use [`paginate`] to process items as they arrive instead.
 ///
 /// # Arguments
 ///
@@ -132,34 +325,303 @@ where
     F: Fn(PageParams) -> Fut,
     Fut: std::future::Future<Output = Result<(Vec<T>, Paginator), crate::RavelryError>>,
 {
-    let mut all_items = Vec::new();
-    let mut current_page = 1u32;
-    let mut pages_fetched = 0u32;
-
-    loop {
-        // Check if we've hit the max pages limit
-        if let Some(max) = max_pages {
-            if pages_fetched >= max {
-                break;
+    paginate(initial_page_size, max_pages, fetch)
+        .try_collect()
+        .await
+}
+
+/// Collect all pages from a paginated endpoint, fetching up to `concurrency`
+/// pages at once.
+///
+/// Page 1 is fetched first to learn how many pages there are; the rest are
+/// then requested in parallel. Items are returned in page order regardless
+/// of which request finishes first. The first error aborts the remaining
+/// requests and is returned.
+///
+/// Prefer [`collect_all_pages`] for small result sets: parallel requests
+/// use up the rate limit faster. A `concurrency` of 0 is treated as 1.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ravelry::{RavelryClient, auth::BasicAuth, RavelryError};
+/// # use ravelry::pagination::collect_all_pages_concurrent;
+/// use ravelry::api::patterns::PatternSearchParams;
+///
+/// # async fn example() -> Result<(), RavelryError> {
+/// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+/// let patterns = collect_all_pages_concurrent(100, Some(50), 4, |page_params| {
+///     let client = &client;
+///     let params = PatternSearchParams {
+///         query: Some("cardigan".to_string()),
+///         page: page_params,
+///         ..Default::default()
+///     };
+///     async move {
+///         let resp = client.patterns().search(&params).await?;
+///         Ok((resp.patterns, resp.paginator))
+///     }
+/// })
+/// .await?;
+///
+/// println!("{} patterns", patterns.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn collect_all_pages_concurrent<T, F, Fut>(
+    page_size: u32,
+    max_pages: Option<u32>,
+    concurrency: usize,
+    fetch: F,
+) -> Result<Vec<T>, crate::RavelryError>
+where
+    F: Fn(PageParams) -> Fut,
+    Fut: std::future::Future<Output = Result<(Vec<T>, Paginator), crate::RavelryError>>,
+{
+    if max_pages == Some(0) {
+        return Ok(Vec::new());
+    }
+    let params = |page| PageParams {
+        page: Some(page),
+        page_size: Some(page_size),
+    };
+
+    let (mut items, paginator) = fetch(params(1)).await?;
+    let last_page = match max_pages {
+        Some(max) => paginator.last_page.min(max),
+        None => paginator.last_page,
+    };
+
+    let mut rest: Vec<(u32, Vec<T>)> = stream::iter(2..=last_page)
+        .map(|page| {
+            let request = fetch(params(page));
+            async move { request.await.map(|(items, _)| (page, items)) }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .try_collect()
+        .await?;
+
+    rest.sort_by_key(|(page, _)| *page);
+    items.extend(rest.into_iter().flat_map(|(_, items)| items));
+    Ok(items)
+}
+
+/// Stream items from a paginated endpoint, fetching pages lazily.
+///
+/// This is the streaming counterpart to [`collect_all_pages`]: the next page
+/// is only requested once the items of the current one have been consumed.
+/// The stream ends after the last page, after `max_pages` pages, or after
+/// yielding the first error.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ravelry::{RavelryClient, auth::BasicAuth, RavelryError};
+/// use futures::StreamExt;
+/// use ravelry::api::patterns::PatternSearchParams;
+/// use ravelry::pagination::paginate;
+///
+/// # async fn example() -> Result<(), RavelryError> {
+/// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+/// let patterns = paginate(50, Some(3), |page_params| {
+///     let client = &client;
+///     let params = PatternSearchParams {
+///         query: Some("socks".to_string()),
+///         page: page_params,
+///         ..Default::default()
+///     };
+///     async move {
+///         let resp = client.patterns().search(&params).await?;
+///         Ok((resp.patterns, resp.paginator))
+///     }
+/// });
+///
+/// let mut patterns = std::pin::pin!(patterns);
+/// while let Some(pattern) = patterns.next().await {
+///     println!("{}", pattern?.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate<T, F, Fut>(
+    page_size: u32,
+    max_pages: Option<u32>,
+    fetch: F,
+) -> impl Stream<Item = Result<T, crate::RavelryError>>
+where
+    F: Fn(PageParams) -> Fut,
+    Fut: std::future::Future<Output = Result<(Vec<T>, Paginator), crate::RavelryError>>,
+{
+    // State: (fetch, next page to request, pages fetched so far)
+    let pages = stream::unfold(
+        (fetch, Some(1u32), 0u32),
+        move |(fetch, next_page, pages_fetched)| async move {
+            let page = next_page?;
+            if max_pages.is_some_and(|max| pages_fetched >= max) {
+                return None;
             }
+
+            let page_params = PageParams {
+                page: Some(page),
+                page_size: Some(page_size),
+            };
+
+            match fetch(page_params).await {
+                Ok((items, paginator)) => {
+                    let next = paginator.has_next().then(|| paginator.page + 1);
+                    Some((Ok(items), (fetch, next, pages_fetched + 1)))
+                }
+                Err(e) => Some((Err(e), (fetch, None, pages_fetched + 1))),
+            }
+        },
+    );
+
+    pages
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use futures::executor::block_on;
+    use futures::StreamExt;
+
+    use super::*;
+
+    /// Three pages of two items each; fetching page `fail_on` errors.
+    async fn fetch(
+        params: PageParams,
+        calls: &Cell<u32>,
+        fail_on: Option<u32>,
+    ) -> Result<(Vec<u32>, Paginator), RavelryError> {
+        calls.set(calls.get() + 1);
+        let page = params.page.unwrap();
+        if fail_on == Some(page) {
+            return Err(RavelryError::InvalidRequest(format!("page {page}")));
         }
+        let paginator = Paginator {
+            page_count: 3,
+            page,
+            page_size: 2,
+            results: 6,
+            last_page: 3,
+        };
+        Ok((vec![page * 10, page * 10 + 1], paginator))
+    }
+
+    #[test]
+    fn test_paginate_fetches_lazily() {
+        let calls = Cell::new(0);
+        let stream = paginate(2, None, |p| fetch(p, &calls, None));
+        let mut stream = std::pin::pin!(stream);
+
+        block_on(async {
+            assert_eq!(stream.next().await.unwrap().unwrap(), 10);
+            assert_eq!(stream.next().await.unwrap().unwrap(), 11);
+            assert_eq!(calls.get(), 1);
+            assert_eq!(stream.next().await.unwrap().unwrap(), 20);
+            assert_eq!(calls.get(), 2);
+        });
+    }
 
-        let page_params = PageParams {
-            page: Some(current_page),
-            page_size: Some(initial_page_size),
+    #[test]
+    fn test_paginate_yields_items_before_error_then_stops() {
+        let calls = Cell::new(0);
+        let items: Vec<_> = block_on(paginate(2, None, |p| fetch(p, &calls, Some(2))).collect());
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].as_ref().unwrap(), &10);
+        assert_eq!(items[1].as_ref().unwrap(), &11);
+        assert!(items[2].is_err());
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_collect_all_pages_wraps_stream() {
+        let calls = Cell::new(0);
+        let all = block_on(collect_all_pages(2, None, |p| fetch(p, &calls, None))).unwrap();
+        assert_eq!(all, vec![10, 11, 20, 21, 30, 31]);
+
+        let limited = block_on(collect_all_pages(2, Some(2), |p| fetch(p, &calls, None))).unwrap();
+        assert_eq!(limited, vec![10, 11, 20, 21]);
+
+        assert!(block_on(collect_all_pages(2, None, |p| fetch(p, &calls, Some(3)))).is_err());
+    }
+
+    #[test]
+    fn test_page_params_from_paginator_keeps_page_size() {
+        let paginator = Paginator {
+            page_count: 3,
+            page: 2,
+            page_size: 25,
+            results: 60,
+            last_page: 3,
         };
 
-        let (items, paginator) = fetch(page_params).await?;
-        all_items.extend(items);
-        pages_fetched += 1;
+        let next = PageParams::from_paginator_next(&paginator).unwrap();
+        assert_eq!(next, PageParams::new().page(3).page_size(25));
+        let prev = PageParams::from_paginator_prev(&paginator).unwrap();
+        assert_eq!(prev, PageParams::new().page(1).page_size(25));
+        let last = PageParams::from_paginator(&paginator, Direction::Last).unwrap();
+        assert_eq!(last.page, Some(3));
 
-        // Check if there are more pages
-        if paginator.has_next() {
-            current_page = paginator.page + 1;
-        } else {
-            break;
-        }
+        let first = Paginator {
+            page: 1,
+            ..paginator.clone()
+        };
+        assert!(PageParams::from_paginator_prev(&first).is_none());
+        let last = Paginator {
+            page: 3,
+            ..paginator
+        };
+        assert!(PageParams::from_paginator_next(&last).is_none());
     }
 
-    Ok(all_items)
+    #[tokio::test]
+    async fn test_collect_all_pages_concurrent_keeps_page_order() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::time::Duration;
+
+        let in_flight = AtomicU32::new(0);
+        let max_in_flight = AtomicU32::new(0);
+        let fetch = |params: PageParams| {
+            let (in_flight, max_in_flight) = (&in_flight, &max_in_flight);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(now, Ordering::SeqCst);
+                let page = params.page.unwrap();
+                // Later pages finish first.
+                tokio::time::sleep(Duration::from_millis(u64::from(6 - page) * 5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                let paginator = Paginator {
+                    page_count: 5,
+                    page,
+                    page_size: 1,
+                    results: 5,
+                    last_page: 5,
+                };
+                Ok((vec![page], paginator))
+            }
+        };
+
+        let all = collect_all_pages_concurrent(1, None, 2, fetch)
+            .await
+            .unwrap();
+        assert_eq!(all, vec![1, 2, 3, 4, 5]);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+
+        let limited = collect_all_pages_concurrent(1, Some(3), 8, fetch)
+            .await
+            .unwrap();
+        assert_eq!(limited, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_pages_concurrent_returns_error() {
+        let calls = Cell::new(0);
+        let result = collect_all_pages_concurrent(2, None, 4, |p| fetch(p, &calls, Some(2))).await;
+        assert!(matches!(result, Err(RavelryError::InvalidRequest(msg)) if msg == "page 2"));
+    }
 }
@@ -0,0 +1,37 @@
+//! Rate limit information reported by the API.
+
+use reqwest::header::HeaderMap;
+
+/// Rate limit quota, as reported in a response's `X-RateLimit-*` headers.
+///
+/// The client records this from every response it receives; read the most
+/// recent value with [`RavelryClient::rate_limit_status`](crate::RavelryClient::rate_limit_status).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Maximum number of requests allowed in the current window
+    /// (`X-RateLimit-Limit`).
+    pub limit: Option<u32>,
+
+    /// Requests left in the current window (`X-RateLimit-Remaining`).
+    pub remaining: Option<u32>,
+
+    /// When the window resets, as sent in `X-RateLimit-Reset`.
+    pub reset: Option<u64>,
+}
+
+impl RateLimitInfo {
+    /// Read rate limit headers, or `None` if the response carried none.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        fn number<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+            headers.get(name)?.to_str().ok()?.trim().parse().ok()
+        }
+
+        let info = Self {
+            limit: number(headers, "x-ratelimit-limit"),
+            remaining: number(headers, "x-ratelimit-remaining"),
+            reset: number(headers, "x-ratelimit-reset"),
+        };
+
+        (info.limit.is_some() || info.remaining.is_some() || info.reset.is_some()).then_some(info)
+    }
+}
@@ -1,5 +1,11 @@
 //! Request options for customizing API calls.
 
+use time::format_description::BorrowedFormatItem;
+use time::macros::format_description;
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+use crate::error::RavelryError;
+
 /// Options that can be applied to individual API requests.
 #[derive(Clone, Debug, Default)]
 pub struct RequestOptions {
@@ -12,6 +18,33 @@ pub struct RequestOptions {
     ///
     /// If the resource hasn't changed, the API returns 304 Not Modified.
     pub if_none_match: Option<String>,
+
+    /// Date for conditional requests (If-Modified-Since header).
+    ///
+    /// If the resource hasn't changed since this time, the API returns
+    /// 304 Not Modified, surfaced the same way as an ETag match.
+    pub if_modified_since: Option<OffsetDateTime>,
+
+    /// Answer a 304 with the caller's previous result instead of an error.
+    ///
+    /// Only calls that take a previous value, such as
+    /// [`PatternsApi::show_or_previous`](crate::api::patterns::PatternsApi::show_or_previous),
+    /// can do this. A 304 only happens when the request carries
+    /// `If-None-Match`, set either by [`if_none_match`](Self::if_none_match)
+    /// or by the client's [`EtagStore`](crate::etag::EtagStore). A body held
+    /// by the `EtagStore` takes precedence over the previous value.
+    pub return_cached_on_304: bool,
+
+    /// Sort order applied to project list requests that don't set one.
+    pub default_project_sort: Option<String>,
+
+    /// Reject writes the OAuth2 token lacks the scope for, without sending them.
+    ///
+    /// Only applies when the authenticator knows its scopes (see
+    /// [`Authenticator::scopes`](crate::Authenticator::scopes)); the request
+    /// then fails with [`MissingScope`](crate::RavelryError::MissingScope)
+    /// instead of a 403 from the API.
+    pub check_scopes: bool,
 }
 
 impl RequestOptions {
@@ -31,4 +64,69 @@ impl RequestOptions {
         self.if_none_match = Some(etag.into());
         self
     }
+
+    /// Set the If-Modified-Since header for conditional requests.
+    ///
+    /// The time is sent as an HTTP-date in GMT, at whole-second precision.
+    pub fn if_modified_since(mut self, since: OffsetDateTime) -> Self {
+        self.if_modified_since = Some(since);
+        self
+    }
+
+    /// Check OAuth2 scopes before sending writes.
+    ///
+    /// See [`check_scopes`](Self#structfield.check_scopes).
+    pub fn check_scopes(mut self, enabled: bool) -> Self {
+        self.check_scopes = enabled;
+        self
+    }
+
+    /// Return the caller's previous result on 304 Not Modified.
+    ///
+    /// See [`return_cached_on_304`](Self#structfield.return_cached_on_304).
+    pub fn return_cached_on_304(mut self, enabled: bool) -> Self {
+        self.return_cached_on_304 = enabled;
+        self
+    }
+}
+
+/// The HTTP-date (IMF-fixdate) format, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`.
+const HTTP_DATE: &[BorrowedFormatItem<'_>] = format_description!(
+    "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+);
+
+/// Formats `time` as an HTTP-date.
+pub(crate) fn http_date(time: OffsetDateTime) -> Result<String, RavelryError> {
+    time.to_offset(UtcOffset::UTC)
+        .format(HTTP_DATE)
+        .map_err(|e| RavelryError::InvalidRequest(format!("invalid HTTP date: {e}")))
+}
+
+/// Parses an HTTP-date in the IMF-fixdate form; other forms return `None`.
+pub(crate) fn parse_http_date(value: &str) -> Option<OffsetDateTime> {
+    PrimitiveDateTime::parse(value.trim(), HTTP_DATE)
+        .ok()
+        .map(PrimitiveDateTime::assume_utc)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_http_date_converts_to_gmt() {
+        let time = datetime!(1994-11-06 10:49:37.5 +02:00);
+        assert_eq!(http_date(time).unwrap(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+
+    #[test]
+    fn test_parse_http_date() {
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(datetime!(1994-11-06 08:49:37 UTC))
+        );
+        assert_eq!(parse_http_date("120"), None);
+    }
 }
@@ -0,0 +1,289 @@
+//! Retry policy and backoff computation for transient API failures.
+//!
+//! A [`RetryPolicy`] describes how many attempts to make and how long to wait
+//! between them. Delays grow exponentially from `base_delay`, are capped at
+//! `max_delay`, and then have [`Jitter`] applied so that many clients retrying
+//! after a shared 429 window don't all hit the API at the same instant.
+//!
+//! # Example
+//!
+//! ```
+//! use std::time::Duration;
+//! use ravelry::retry::{Jitter, RetryPolicy};
+//!
+//! let policy = RetryPolicy::new()
+//!     .max_attempts(5)
+//!     .base_delay(Duration::from_millis(500))
+//!     .jitter(Jitter::Equal);
+//!
+//! for delay in policy.delays() {
+//!     println!("would wait {delay:?}");
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How randomness is applied to a computed backoff delay.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Jitter {
+    /// Use the exponential backoff delay as-is.
+    None,
+    /// Pick a delay uniformly between zero and the backoff delay.
+    #[default]
+    Full,
+    /// Keep half of the backoff delay and randomize the other half.
+    Equal,
+}
+
+/// Configuration for retrying failed requests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first request.
+    pub max_attempts: u32,
+
+    /// Delay before the first retry; doubled for each subsequent retry.
+    pub base_delay: Duration,
+
+    /// Upper bound on any single backoff delay (before jitter).
+    pub max_delay: Duration,
+
+    /// Jitter strategy applied to each backoff delay.
+    pub jitter: Jitter,
+
+    /// Upper bound on the total time spent on a request, including waits.
+    ///
+    /// A retry whose delay would exceed this budget is not attempted.
+    pub max_elapsed: Option<Duration>,
+
+    /// Whether to retry non-idempotent requests (e.g. `POST`) after a
+    /// timeout or connection error.
+    ///
+    /// Off by default: the server may already have handled a request whose
+    /// response was lost, so re-sending it could create duplicates. Rate
+    /// limit and 503 responses are retried for every method regardless.
+    pub retry_non_idempotent: bool,
+
+    /// Seed for the jitter RNG.
+    ///
+    /// Leave unset in production; set it in tests to get a deterministic
+    /// sequence of delays.
+    pub seed: Option<u64>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: Jitter::default(),
+            max_elapsed: None,
+            retry_non_idempotent: false,
+            seed: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the total number of attempts (including the first request).
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the maximum backoff delay.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the jitter strategy.
+    pub fn jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Cap the total time spent on a request, including retries.
+    pub fn max_elapsed(mut self, limit: Duration) -> Self {
+        self.max_elapsed = Some(limit);
+        self
+    }
+
+    /// Allow retrying non-idempotent requests after transport errors.
+    ///
+    /// See [`retry_non_idempotent`](Self::retry_non_idempotent) for the risk.
+    pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Seed the jitter RNG for deterministic delays.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns the exponential backoff delay for the given retry, before jitter.
+    ///
+    /// `retry` is zero-based: `0` is the delay before the first retry.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.checked_pow(retry).unwrap_or(u32::MAX);
+        self.base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+
+    /// Returns an iterator over the jittered delays between attempts.
+    ///
+    /// The iterator yields `max_attempts - 1` delays, one before each retry.
+    pub fn delays(&self) -> Delays {
+        Delays {
+            policy: self.clone(),
+            retry: 0,
+            rng: SplitMix64::new(self.seed.unwrap_or_else(random_seed)),
+        }
+    }
+}
+
+/// Iterator over the jittered delays of a [`RetryPolicy`].
+///
+/// Created by [`RetryPolicy::delays`].
+#[derive(Debug, Clone)]
+pub struct Delays {
+    policy: RetryPolicy,
+    retry: u32,
+    rng: SplitMix64,
+}
+
+impl Iterator for Delays {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if self.retry + 1 >= self.policy.max_attempts {
+            return None;
+        }
+
+        let backoff = self.policy.backoff(self.retry);
+        self.retry += 1;
+
+        let delay = match self.policy.jitter {
+            Jitter::None => backoff,
+            Jitter::Full => backoff.mul_f64(self.rng.next_f64()),
+            Jitter::Equal => {
+                let half = backoff / 2;
+                half + half.mul_f64(self.rng.next_f64())
+            }
+        };
+
+        Some(delay)
+    }
+}
+
+/// Small, fast PRNG used only for jitter (not cryptographically secure).
+#[derive(Debug, Clone)]
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a float in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Derive a seed from the clock and a process-wide counter.
+fn random_seed() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    nanos ^ COUNTER.fetch_add(1, Ordering::Relaxed).rotate_left(32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(jitter: Jitter) -> RetryPolicy {
+        RetryPolicy::new()
+            .max_attempts(6)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(1))
+            .jitter(jitter)
+            .seed(42)
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = policy(Jitter::None);
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(800));
+        assert_eq!(policy.backoff(4), Duration::from_secs(1));
+        assert_eq!(policy.backoff(40), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_no_jitter_yields_backoff() {
+        let policy = policy(Jitter::None);
+        let delays: Vec<_> = policy.delays().collect();
+        assert_eq!(delays.len(), 5);
+        for (retry, delay) in delays.into_iter().enumerate() {
+            assert_eq!(delay, policy.backoff(retry as u32));
+        }
+    }
+
+    #[test]
+    fn test_full_jitter_within_bounds() {
+        let policy = policy(Jitter::Full);
+        for (retry, delay) in policy.delays().enumerate() {
+            assert!(delay <= policy.backoff(retry as u32));
+        }
+    }
+
+    #[test]
+    fn test_equal_jitter_within_bounds() {
+        let policy = policy(Jitter::Equal);
+        for (retry, delay) in policy.delays().enumerate() {
+            let backoff = policy.backoff(retry as u32);
+            assert!(delay >= backoff / 2);
+            assert!(delay <= backoff);
+        }
+    }
+
+    #[test]
+    fn test_seed_is_deterministic() {
+        let a: Vec<_> = policy(Jitter::Full).delays().collect();
+        let b: Vec<_> = policy(Jitter::Full).delays().collect();
+        let c: Vec<_> = policy(Jitter::Full).seed(7).delays().collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+}
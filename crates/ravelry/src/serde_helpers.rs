@@ -0,0 +1,190 @@
+//! Serde helpers for Ravelry's wire formats.
+
+/// Serialize an `Option<bool>` as `1`/`0`.
+///
+/// Ravelry expects boolean query flags as `1`/`0`, but `serde_urlencoded`
+/// writes `bool` as `true`/`false`, which some endpoints ignore. Use this on
+/// optional boolean query fields together with
+/// `skip_serializing_if = "Option::is_none"`. Deserialization accepts either
+/// form.
+///
+/// # Example
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Params {
+///     #[serde(
+///         with = "ravelry::serde_helpers::bool_as_int",
+///         skip_serializing_if = "Option::is_none"
+///     )]
+///     personal_attributes: Option<bool>,
+/// }
+///
+/// let params = Params { personal_attributes: Some(true) };
+/// assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "personal_attributes=1");
+/// ```
+pub mod bool_as_int {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize `Some(true)` as `1` and `Some(false)` as `0`.
+    pub fn serialize<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(flag) => serializer.serialize_u8(u8::from(*flag)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize `1`/`0`, `true`/`false`, or their string forms.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Flag {
+            Bool(bool),
+            Int(u8),
+            Str(String),
+        }
+
+        match Option::<Flag>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(Flag::Bool(flag)) => Ok(Some(flag)),
+            Some(Flag::Int(0)) => Ok(Some(false)),
+            Some(Flag::Int(1)) => Ok(Some(true)),
+            Some(Flag::Str(s)) => match s.as_str() {
+                "1" | "true" => Ok(Some(true)),
+                "0" | "false" => Ok(Some(false)),
+                _ => Err(serde::de::Error::custom(format!(
+                    "invalid boolean flag: {s}"
+                ))),
+            },
+            Some(Flag::Int(n)) => Err(serde::de::Error::custom(format!(
+                "invalid boolean flag: {n}"
+            ))),
+        }
+    }
+}
+
+/// Serialize a `Vec<String>` as a single `|`-separated value.
+///
+/// Ravelry's search facets take multiple values joined by `|`
+/// (`weight=dk|worsted`). Use this on list fields together with
+/// `skip_serializing_if = "Vec::is_empty"`. Deserialization splits on `|`.
+///
+/// # Example
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Params {
+///     #[serde(
+///         with = "ravelry::serde_helpers::pipe_separated",
+///         skip_serializing_if = "Vec::is_empty"
+///     )]
+///     weight: Vec<String>,
+/// }
+///
+/// let params = Params { weight: vec!["dk".into(), "worsted".into()] };
+/// assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "weight=dk%7Cworsted");
+/// ```
+pub mod pipe_separated {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize the values joined by `|`.
+    pub fn serialize<S>(values: &[String], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&values.join("|"))
+    }
+
+    /// Deserialize a `|`-separated string; an empty string yields no values.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let joined = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+        Ok(joined
+            .split('|')
+            .filter(|value| !value.is_empty())
+            .map(String::from)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Params {
+        #[serde(
+            default,
+            with = "super::bool_as_int",
+            skip_serializing_if = "Option::is_none"
+        )]
+        flag: Option<bool>,
+    }
+
+    #[test]
+    fn test_serializes_as_int() {
+        let on = Params { flag: Some(true) };
+        let off = Params { flag: Some(false) };
+        let unset = Params { flag: None };
+        assert_eq!(serde_urlencoded::to_string(&on).unwrap(), "flag=1");
+        assert_eq!(serde_urlencoded::to_string(&off).unwrap(), "flag=0");
+        assert_eq!(serde_urlencoded::to_string(&unset).unwrap(), "");
+    }
+
+    #[test]
+    fn test_deserializes_either_form() {
+        for (json, expected) in [
+            (r#"{"flag":1}"#, Some(true)),
+            (r#"{"flag":0}"#, Some(false)),
+            (r#"{"flag":true}"#, Some(true)),
+            (r#"{"flag":"0"}"#, Some(false)),
+            (r#"{}"#, None),
+        ] {
+            let parsed: Params = serde_json::from_str(json).unwrap();
+            assert_eq!(parsed.flag, expected, "{json}");
+        }
+        assert!(serde_json::from_str::<Params>(r#"{"flag":2}"#).is_err());
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Facets {
+        #[serde(
+            default,
+            with = "super::pipe_separated",
+            skip_serializing_if = "Vec::is_empty"
+        )]
+        weight: Vec<String>,
+    }
+
+    #[test]
+    fn test_pipe_separated_round_trips() {
+        let facets = Facets {
+            weight: vec!["dk".to_string(), "worsted".to_string()],
+        };
+        assert_eq!(
+            serde_urlencoded::to_string(&facets).unwrap(),
+            "weight=dk%7Cworsted"
+        );
+        assert_eq!(
+            serde_urlencoded::to_string(&Facets { weight: vec![] }).unwrap(),
+            ""
+        );
+
+        let parsed: Facets = serde_json::from_str(r#"{"weight":"dk|worsted"}"#).unwrap();
+        assert_eq!(parsed, facets);
+        let empty: Facets = serde_json::from_str(r#"{"weight":""}"#).unwrap();
+        assert!(empty.weight.is_empty());
+    }
+}
@@ -5,7 +5,11 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::common::ExtraFields;
+use super::common::{string_enum, ExtraFields};
+use super::pattern::PatternList;
+use super::project::ProjectSmall;
+use super::user::UserSmall;
+use super::yarn::YarnList;
 
 /// Bookmark information returned in list responses.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -42,6 +46,21 @@ pub struct BookmarkList {
     pub extra: ExtraFields,
 }
 
+impl BookmarkList {
+    /// Returns the favorite's type as a typed value.
+    pub fn favorite_type(&self) -> Option<FavoriteType> {
+        self.type_name.as_deref().map(FavoriteType::from)
+    }
+
+    /// Returns the favorited item, typed according to [`type_name`](Self::type_name).
+    ///
+    /// Returns `None` if the favorite carries no `favorited` object. See
+    /// [`FavoritedItem::from_value`] for how the type is chosen.
+    pub fn favorited_item(&self) -> Option<FavoritedItem> {
+        favorited_item(self.type_name.as_deref(), self.favorited.as_ref())
+    }
+}
+
 /// Full bookmark details.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct BookmarkFull {
@@ -77,6 +96,120 @@ pub struct BookmarkFull {
     pub extra: ExtraFields,
 }
 
+impl BookmarkFull {
+    /// Returns the favorite's type as a typed value.
+    pub fn favorite_type(&self) -> Option<FavoriteType> {
+        self.type_name.as_deref().map(FavoriteType::from)
+    }
+
+    /// Returns the favorited item, typed according to [`type_name`](Self::type_name).
+    ///
+    /// Returns `None` if the favorite carries no `favorited` object. The raw
+    /// value stays available in [`favorited`](Self::favorited).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use ravelry::{RavelryClient, auth::BasicAuth};
+    /// use ravelry::types::FavoritedItem;
+    ///
+    /// # async fn example() -> Result<(), ravelry::RavelryError> {
+    /// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+    /// let favorite = client.favorites().show("username", 1).await?.favorite;
+    /// match favorite.favorited_item() {
+    ///     Some(FavoritedItem::Pattern(pattern)) => println!("Pattern: {}", pattern.name),
+    ///     Some(FavoritedItem::Yarn(yarn)) => println!("Yarn: {}", yarn.name),
+    ///     _ => {}
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn favorited_item(&self) -> Option<FavoritedItem> {
+        favorited_item(self.type_name.as_deref(), self.favorited.as_ref())
+    }
+}
+
+fn favorited_item(
+    type_name: Option<&str>,
+    favorited: Option<&serde_json::Value>,
+) -> Option<FavoritedItem> {
+    let value = favorited.filter(|value| !value.is_null())?.clone();
+    Some(FavoritedItem::from_value(
+        type_name.unwrap_or_default(),
+        value,
+    ))
+}
+
+string_enum! {
+    /// The kind of item a favorite refers to, as sent in its `type`.
+    pub enum FavoriteType {
+        /// A pattern.
+        Pattern => "pattern",
+        /// A yarn.
+        Yarn => "yarn",
+        /// A project.
+        Project => "project",
+        /// A stash entry.
+        Stash => "stash",
+        /// A forum post.
+        ForumPost => "forumpost",
+        /// A designer.
+        Designer => "designer",
+        /// A yarn brand.
+        YarnBrand => "yarnbrand",
+        /// A yarn shop.
+        Shop => "yarnshop",
+        /// A bundle.
+        Bundle => "bundle",
+        /// A Ravelry user.
+        Person => "user",
+    }
+}
+
+/// A favorited item, typed according to its favorite type.
+///
+/// Serializes as the underlying object, without a type tag.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum FavoritedItem {
+    /// A pattern.
+    Pattern(PatternList),
+    /// A yarn.
+    Yarn(YarnList),
+    /// A project.
+    Project(ProjectSmall),
+    /// A Ravelry user.
+    Person(UserSmall),
+    /// Any other type, or an object that didn't match its expected shape.
+    Other(serde_json::Value),
+}
+
+impl FavoritedItem {
+    /// Convert a raw favorited object using its type name (e.g. `"pattern"`).
+    ///
+    /// Unknown types, and objects that fail to parse as the expected type,
+    /// become [`FavoritedItem::Other`].
+    pub fn from_value(type_name: &str, value: serde_json::Value) -> Self {
+        fn parse<T: serde::de::DeserializeOwned>(
+            value: serde_json::Value,
+            wrap: fn(T) -> FavoritedItem,
+        ) -> FavoritedItem {
+            match T::deserialize(&value) {
+                Ok(item) => wrap(item),
+                Err(_) => FavoritedItem::Other(value),
+            }
+        }
+
+        match type_name.to_ascii_lowercase().as_str() {
+            "pattern" => parse(value, FavoritedItem::Pattern),
+            "yarn" => parse(value, FavoritedItem::Yarn),
+            "project" => parse(value, FavoritedItem::Project),
+            "user" | "person" => parse(value, FavoritedItem::Person),
+            _ => FavoritedItem::Other(value),
+        }
+    }
+}
+
 /// Bookmark data for creating or updating.
 #[derive(Serialize, Debug, Default, Clone)]
 pub struct BookmarkPost {
@@ -130,4 +263,13 @@ impl BookmarkPost {
         self.tag_names = Some(tags.into());
         self
     }
+
+    /// Set a favorite field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
 }
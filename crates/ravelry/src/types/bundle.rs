@@ -103,6 +103,15 @@ impl BundlePost {
         self.is_public = Some(public);
         self
     }
+
+    /// Set a bundle field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
 }
 
 /// Bundled item details (an item within a bundle).
@@ -119,6 +128,10 @@ pub struct BundledItemFull {
     #[serde(default)]
     pub favorite_id: Option<u64>,
 
+    /// The type of the bundled item (e.g., "pattern", "yarn").
+    #[serde(default)]
+    pub item_type: Option<String>,
+
     /// Position in the bundle.
     #[serde(default)]
     pub sort_order: Option<i32>,
@@ -131,3 +144,49 @@ pub struct BundledItemFull {
     #[serde(flatten)]
     pub extra: ExtraFields,
 }
+
+/// Bundled item data for adding a favorite to a bundle.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct BundledItemPost {
+    /// The bundle to add the item to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle_id: Option<u64>,
+
+    /// The bookmark/favorite to add.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favorite_id: Option<u64>,
+
+    /// Position in the bundle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<i32>,
+
+    /// Capture any additional fields for flexibility.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl BundledItemPost {
+    /// Create a post adding the favorite `favorite_id` to the bundle `bundle_id`.
+    pub fn new(bundle_id: u64, favorite_id: u64) -> Self {
+        Self {
+            bundle_id: Some(bundle_id),
+            favorite_id: Some(favorite_id),
+            ..Self::default()
+        }
+    }
+
+    /// Set the position in the bundle.
+    pub fn sort_order(mut self, sort_order: i32) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Set a bundled item field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
@@ -0,0 +1,140 @@
+//! Comment types for the Ravelry API.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::ExtraFields;
+use super::user::UserSmall;
+
+/// The kind of item a comment is attached to.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentParent {
+    /// A pattern.
+    Pattern,
+    /// A project.
+    Project,
+    /// A yarn.
+    Yarn,
+    /// A stash entry.
+    Stash,
+}
+
+impl CommentParent {
+    /// The path segment used for this parent type (e.g. "project").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommentParent::Pattern => "pattern",
+            CommentParent::Project => "project",
+            CommentParent::Yarn => "yarn",
+            CommentParent::Stash => "stash",
+        }
+    }
+}
+
+impl std::fmt::Display for CommentParent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A comment as returned when listing an item's comments.
+///
+/// Use [`CommentFull`] for complete comment details.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CommentList {
+    /// Unique comment ID.
+    pub id: u64,
+
+    /// Comment text.
+    #[serde(default)]
+    pub body: Option<String>,
+
+    /// When the comment was posted.
+    #[serde(default)]
+    pub created_at: Option<String>,
+
+    /// The comment's author.
+    #[serde(default)]
+    pub user: Option<UserSmall>,
+
+    /// Whether the item's owner has highlighted this comment.
+    #[serde(default)]
+    pub highlighted: Option<bool>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A comment on a project, pattern, yarn, stash entry, etc.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CommentFull {
+    /// Unique comment ID.
+    pub id: u64,
+
+    /// Comment text.
+    #[serde(default)]
+    pub body: Option<String>,
+
+    /// Comment text as HTML.
+    #[serde(default)]
+    pub html: Option<String>,
+
+    /// When the comment was posted.
+    #[serde(default)]
+    pub created_at: Option<String>,
+
+    /// The comment's author.
+    #[serde(default)]
+    pub user: Option<UserSmall>,
+
+    /// Whether the item's owner has highlighted this comment.
+    #[serde(default)]
+    pub highlighted: Option<bool>,
+
+    /// Type of the commented item (e.g., "project", "pattern").
+    #[serde(default)]
+    pub commentable_type: Option<String>,
+
+    /// ID of the commented item.
+    #[serde(default)]
+    pub commentable_id: Option<u64>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Comment data for creating or updating a comment.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct CommentPost {
+    /// Comment text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Capture any additional fields for flexibility.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl CommentPost {
+    /// Create a new empty comment post.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the comment text.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set a comment field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
@@ -2,8 +2,215 @@
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+use time::format_description::BorrowedFormatItem;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime};
+
 /// Type alias for capturing unknown JSON fields.
 ///
 /// This is used with `#[serde(flatten)]` to preserve any fields
 /// not explicitly defined in our structs.
 pub type ExtraFields = HashMap<String, serde_json::Value>;
+
+/// A monetary amount.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Money {
+    /// The amount, in major units (e.g. `12.5` for 12.50).
+    pub amount: f64,
+
+    /// ISO 4217 currency code (e.g. "USD"), if known.
+    #[serde(default)]
+    pub currency: Option<String>,
+}
+
+/// A single problem found by client-side validation of a post.
+///
+/// Returned in bulk by `validate` methods such as
+/// [`ProjectPost::validate`](super::ProjectPost::validate), so form UIs can
+/// report every problem at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// Name of the offending field, as sent to the API.
+    pub field: &'static str,
+
+    /// Human-readable description of the problem.
+    pub message: String,
+}
+
+impl ValidationIssue {
+    pub(crate) fn new(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Ravelry's timestamp format, e.g. `2024/01/15 10:30:00 -0500`.
+const TIMESTAMP_FORMAT: &[BorrowedFormatItem<'_>] = format_description!(
+    "[year]/[month]/[day] [hour]:[minute]:[second] [offset_hour sign:mandatory][offset_minute]"
+);
+
+/// Ravelry's date format, e.g. `2024/01/15`.
+const DATE_FORMAT: &[BorrowedFormatItem<'_>] = format_description!("[year]/[month]/[day]");
+
+/// Parse a timestamp or date string as returned by the Ravelry API.
+///
+/// Ravelry sends timestamps as `YYYY/MM/DD HH:MM:SS ±HHMM` (e.g.
+/// `2024/01/15 10:30:00 -0500`) and dates, such as a project's `started`
+/// and `completed`, as `YYYY/MM/DD`. Dates are returned as midnight UTC.
+///
+/// The typed accessors on response types (e.g.
+/// [`ProjectFull::started_at`](super::ProjectFull::started_at)) use this;
+/// call it directly for fields without one.
+///
+/// # Example
+///
+/// ```
+/// use ravelry::types::parse_timestamp;
+///
+/// let sent = parse_timestamp("2024/01/15 10:30:00 -0500").unwrap();
+/// assert_eq!(sent.offset().whole_hours(), -5);
+///
+/// let started = parse_timestamp("2024/01/15").unwrap();
+/// assert_eq!(started.hour(), 0);
+/// ```
+pub fn parse_timestamp(value: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    let value = value.trim();
+    match OffsetDateTime::parse(value, TIMESTAMP_FORMAT) {
+        Ok(timestamp) => Ok(timestamp),
+        Err(err) if value.len() > "YYYY/MM/DD".len() => Err(err),
+        Err(_) => Ok(Date::parse(value, DATE_FORMAT)?.midnight().assume_utc()),
+    }
+}
+
+/// Defines a string-valued enum that never fails to deserialize.
+///
+/// Each listed variant maps to its API string (matched case-insensitively).
+/// An `Other(String)` variant is added automatically and captures any value
+/// not known to this version of the crate, so new reference values added by
+/// Ravelry don't break deserialization. Serialization writes the API string
+/// back out unchanged, including for `Other`.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// A value not known to this version of the crate.
+            Other(String),
+        }
+
+        impl $name {
+            /// Returns the value as sent to and received from the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Other(value) => value,
+                }
+            }
+
+            /// Returns `true` if this is the [`Other`](Self::Other) fallback.
+            pub fn is_other(&self) -> bool {
+                matches!(self, Self::Other(_))
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                $(
+                    if value.eq_ignore_ascii_case($value) {
+                        return Self::$variant;
+                    }
+                )*
+                Self::Other(value.to_string())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self::from(value.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(value))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+                Ok(Self::from(value))
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp_with_offset() {
+        let parsed = parse_timestamp("2024/01/15 10:30:00 -0500").unwrap();
+        assert_eq!(parsed.unix_timestamp(), 1_705_332_600);
+        assert_eq!(parsed.offset().whole_hours(), -5);
+    }
+
+    #[test]
+    fn test_parse_date_only_is_midnight_utc() {
+        let parsed = parse_timestamp(" 2024/01/15 ").unwrap();
+        assert_eq!(parsed.date().to_string(), "2024-01-15");
+        assert_eq!(parsed.hour(), 0);
+        assert!(parsed.offset().is_utc());
+    }
+
+    #[test]
+    fn test_parse_timestamp_rejects_other_formats() {
+        assert!(parse_timestamp("2024-01-15").is_err());
+        assert!(parse_timestamp("2024/01/15 10:30:00").is_err());
+        assert!(parse_timestamp("").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+//! Forum types for the Ravelry API.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::ExtraFields;
+use super::user::UserSmall;
+
+/// A forum post, with its full content.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PostFull {
+    /// Unique post ID.
+    pub id: u64,
+
+    /// Post body (markdown/text).
+    #[serde(default)]
+    pub body: Option<String>,
+
+    /// Post body as HTML.
+    #[serde(default)]
+    pub body_html: Option<String>,
+
+    /// ID of the topic this post belongs to.
+    #[serde(default)]
+    pub topic_id: Option<u64>,
+
+    /// Position of the post within its topic.
+    #[serde(default)]
+    pub post_number: Option<u64>,
+
+    /// When the post was created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+
+    /// When the post was last edited.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+
+    /// The post's author.
+    #[serde(default)]
+    pub user: Option<UserSmall>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A forum topic (thread), as listed in a forum.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ForumTopic {
+    /// Unique topic ID.
+    pub id: u64,
+
+    /// Topic title.
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// ID of the forum this topic belongs to.
+    #[serde(default)]
+    pub forum_id: Option<u64>,
+
+    /// Number of posts in the topic.
+    #[serde(default)]
+    pub forum_posts_count: Option<u64>,
+
+    /// Whether the topic is pinned to the top of the forum.
+    #[serde(default)]
+    pub sticky: Option<bool>,
+
+    /// Whether the topic is closed to new replies.
+    #[serde(default)]
+    pub locked: Option<bool>,
+
+    /// When the topic was created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+
+    /// When the most recent post was made.
+    #[serde(default)]
+    pub last_post_at: Option<String>,
+
+    /// The user who started the topic.
+    #[serde(default)]
+    pub user: Option<UserSmall>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Data for posting a reply to a forum topic.
+///
+/// Requires the `forum-write` OAuth scope.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct PostPost {
+    /// Post body (markdown/text).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// Capture any additional fields for flexibility.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl PostPost {
+    /// Create a new empty post.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the post body.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set a forum post field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
@@ -1,8 +1,9 @@
 //! Friend/friendship types for the Ravelry API.
 
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 
-use super::common::ExtraFields;
+use super::common::{parse_timestamp, ExtraFields};
 use super::user::UserSmall;
 
 /// Friendship information.
@@ -28,6 +29,13 @@ pub struct Friendship {
     pub extra: ExtraFields,
 }
 
+impl Friendship {
+    /// Parses `created_at` (when it was created); see [`parse_timestamp`] for the format.
+    pub fn created_time(&self) -> Option<Result<OffsetDateTime, time::error::Parse>> {
+        self.created_at.as_deref().map(parse_timestamp)
+    }
+}
+
 /// Activity item from a friend's feed.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct FriendActivity {
@@ -55,3 +63,10 @@ pub struct FriendActivity {
     #[serde(flatten)]
     pub extra: ExtraFields,
 }
+
+impl FriendActivity {
+    /// Parses `created_at` (when it was created); see [`parse_timestamp`] for the format.
+    pub fn created_time(&self) -> Option<Result<OffsetDateTime, time::error::Parse>> {
+        self.created_at.as_deref().map(parse_timestamp)
+    }
+}
@@ -0,0 +1,80 @@
+//! Group types for the Ravelry API.
+//!
+//! Groups are communities with their own forums and membership.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::ExtraFields;
+use super::photo::PhotoSmall;
+
+/// Group information returned in search results.
+///
+/// Use [`GroupFull`] for complete group details.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GroupList {
+    /// Unique group ID.
+    pub id: u64,
+
+    /// Group name.
+    pub name: String,
+
+    /// URL-friendly unique identifier.
+    pub permalink: String,
+
+    /// Number of members.
+    #[serde(default)]
+    pub members_count: Option<u64>,
+
+    /// Short description.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// The group's badge photo.
+    #[serde(default)]
+    pub badge: Option<PhotoSmall>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Full group information returned when fetching a single group.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GroupFull {
+    /// Unique group ID.
+    pub id: u64,
+
+    /// Group name.
+    pub name: String,
+
+    /// URL-friendly unique identifier.
+    pub permalink: String,
+
+    /// Number of members.
+    #[serde(default)]
+    pub members_count: Option<u64>,
+
+    /// Description (plain text/markdown).
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Description rendered as HTML.
+    #[serde(default)]
+    pub description_html: Option<String>,
+
+    /// The group's badge photo.
+    #[serde(default)]
+    pub badge: Option<PhotoSmall>,
+
+    /// The group's forum ID, for use with the forums API.
+    #[serde(default)]
+    pub forum_id: Option<u64>,
+
+    /// When the group was created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
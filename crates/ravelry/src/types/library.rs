@@ -0,0 +1,75 @@
+//! Library types for the Ravelry API.
+//!
+//! A user's library holds the patterns, books, and magazines they own,
+//! including pattern store purchases.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::{ExtraFields, Money};
+use super::pattern::PatternList;
+
+/// An item in a user's library (a pattern, book, magazine, or booklet).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct LibraryVolume {
+    /// Unique volume ID.
+    pub id: u64,
+
+    /// Volume title.
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Author or designer name.
+    #[serde(default)]
+    pub author_name: Option<String>,
+
+    /// The pattern this volume holds, for single-pattern volumes.
+    #[serde(default)]
+    pub pattern_id: Option<u64>,
+
+    /// When the volume was added to the library.
+    #[serde(default)]
+    pub created_at: Option<String>,
+
+    /// Whether the volume has downloadable files.
+    #[serde(default)]
+    pub has_downloads: Option<bool>,
+
+    /// URL of a square thumbnail of the cover.
+    #[serde(default)]
+    pub square_image_url: Option<String>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Full details of a volume (a book, magazine, booklet, or single pattern).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VolumeFull {
+    /// Unique volume ID.
+    pub id: u64,
+
+    /// Volume title.
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Author or designer name.
+    #[serde(default, alias = "author")]
+    pub author_name: Option<String>,
+
+    /// The patterns in the volume, in publication order.
+    #[serde(default)]
+    pub patterns: Vec<PatternList>,
+
+    /// The volume's price, if it's sold.
+    #[serde(default)]
+    pub price: Option<Money>,
+
+    /// URL of a square thumbnail of the cover.
+    #[serde(default)]
+    pub square_image_url: Option<String>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
@@ -3,8 +3,9 @@
 //! Private messages between Ravelry users.
 
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 
-use super::common::ExtraFields;
+use super::common::{parse_timestamp, ExtraFields};
 use super::user::UserSmall;
 
 /// Message information returned in lists.
@@ -40,6 +41,13 @@ pub struct MessageList {
     pub extra: ExtraFields,
 }
 
+impl MessageList {
+    /// Parses `sent_at` (when the message was sent); see [`parse_timestamp`] for the format.
+    pub fn sent_time(&self) -> Option<Result<OffsetDateTime, time::error::Parse>> {
+        self.sent_at.as_deref().map(parse_timestamp)
+    }
+}
+
 /// Full message information including content.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct MessageFull {
@@ -82,6 +90,13 @@ pub struct MessageFull {
     pub extra: ExtraFields,
 }
 
+impl MessageFull {
+    /// Parses `sent_at` (when the message was sent); see [`parse_timestamp`] for the format.
+    pub fn sent_time(&self) -> Option<Result<OffsetDateTime, time::error::Parse>> {
+        self.sent_at.as_deref().map(parse_timestamp)
+    }
+}
+
 /// Message data for sending a new message.
 ///
 /// Requires the `message-write` OAuth scope.
@@ -137,4 +152,13 @@ impl MessagePost {
         self.recipient_username = Some(username.into());
         self
     }
+
+    /// Set a message field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
 }
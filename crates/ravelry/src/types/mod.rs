@@ -8,15 +8,44 @@
 //!
 //! All types use `#[serde(flatten)]` with a HashMap to capture unknown fields,
 //! making them resilient to API changes.
+//!
+//! ## Typed enums
+//!
+//! Reference values such as [`Craft`], [`ProjectStatus`] and [`YarnWeight`]
+//! are modeled as enums. Enums identified by name have an `Other(String)`
+//! variant: deserializing a name this crate doesn't know about yields `Other`
+//! instead of an error, and serializing `Other` writes the original string
+//! back. [`Craft`] and [`ProjectStatus`] can also arrive as a bare numeric
+//! ID, and IDs they don't know become `Unknown(u64)`. Reading a response never fails because
+//! Ravelry added a new craft, status, weight or sort option.
+//!
+//! ## Extra fields on post types
+//!
+//! Each post type (such as [`ProjectPost`] or [`MessagePost`]) has an
+//! `extra(key, value)` builder as an escape hatch for fields this crate
+//! doesn't model yet. Extra fields are sent at the top level of the request's
+//! `data` object, alongside the typed fields.
+//!
+//! A key that names a typed field (for example `"name"` on a
+//! [`ProjectPost`]) replaces that field's value rather than being sent
+//! twice, so prefer the typed setter where one exists.
 
 pub mod bookmark;
 pub mod bundle;
+pub mod comment;
 pub mod common;
+pub mod forum;
 pub mod friend;
+pub mod group;
+pub mod library;
 pub mod message;
 pub mod pattern;
 pub mod photo;
 pub mod project;
+pub mod queue;
+pub mod reference;
+pub mod saved_search;
+pub mod shop;
 pub mod stash;
 pub mod upload;
 pub mod user;
@@ -24,12 +53,20 @@ pub mod yarn;
 
 pub use bookmark::*;
 pub use bundle::*;
+pub use comment::*;
 pub use common::*;
+pub use forum::*;
 pub use friend::*;
+pub use group::*;
+pub use library::*;
 pub use message::*;
 pub use pattern::*;
 pub use photo::*;
 pub use project::*;
+pub use queue::*;
+pub use reference::*;
+pub use saved_search::*;
+pub use shop::*;
 pub use stash::*;
 pub use upload::*;
 pub use user::*;
@@ -2,8 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::common::ExtraFields;
+use super::common::{ExtraFields, Money};
 use super::photo::PhotoSmall;
+use super::reference::YarnWeight;
 
 /// Pattern information returned in search results and lists.
 ///
@@ -105,7 +106,98 @@ pub struct PatternFull {
     #[serde(default)]
     pub difficulty_count: Option<u64>,
 
+    /// Price of the pattern, if sold.
+    #[serde(default)]
+    pub price: Option<f64>,
+
+    /// ISO 4217 currency code for `price`.
+    #[serde(default)]
+    pub currency: Option<String>,
+
+    /// Yarn weight the pattern is written for.
+    #[serde(default)]
+    pub yarn_weight: Option<PatternYarnWeight>,
+
+    /// Yardage required (the smallest size, if the pattern has several).
+    #[serde(default)]
+    pub yardage: Option<u64>,
+
+    /// Yardage required for the largest size.
+    #[serde(default)]
+    pub yardage_max: Option<u64>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl PatternFull {
+    /// Returns the pattern's price as [`Money`], if it has one.
+    pub fn price(&self) -> Option<Money> {
+        self.price.map(|amount| Money {
+            amount,
+            currency: self.currency.clone(),
+        })
+    }
+
+    /// Project this pattern down to a [`PatternSummary`].
+    pub fn summary(&self) -> PatternSummary {
+        PatternSummary {
+            id: self.id,
+            name: self.name.clone(),
+            designer_name: self.designer_name.clone(),
+            first_photo: self.first_photo.clone(),
+            free: self.free,
+            price: self.price(),
+            yarn_weight: self
+                .yarn_weight
+                .as_ref()
+                .and_then(|weight| weight.name.as_deref())
+                .map(YarnWeight::from),
+        }
+    }
+}
+
+/// The yarn weight embedded in a pattern.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PatternYarnWeight {
+    /// Yarn weight ID.
+    #[serde(default)]
+    pub id: Option<u64>,
+
+    /// Yarn weight name (e.g., "Fingering", "DK").
+    #[serde(default)]
+    pub name: Option<String>,
+
     /// Capture any additional fields.
     #[serde(flatten)]
     pub extra: ExtraFields,
 }
+
+/// A compact view of a pattern, suited to link previews.
+///
+/// Built from a [`PatternFull`] with [`PatternFull::summary`], or fetched
+/// directly with `client.patterns().summary(id)`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PatternSummary {
+    /// Unique pattern ID.
+    pub id: u64,
+
+    /// Pattern name.
+    pub name: String,
+
+    /// The pattern designer's name.
+    pub designer_name: Option<String>,
+
+    /// The first/primary photo for this pattern.
+    pub first_photo: Option<PhotoSmall>,
+
+    /// Whether the pattern is free.
+    pub free: Option<bool>,
+
+    /// Price of the pattern, if sold.
+    pub price: Option<Money>,
+
+    /// Yarn weight the pattern is written for.
+    pub yarn_weight: Option<YarnWeight>,
+}
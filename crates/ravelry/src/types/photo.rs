@@ -34,3 +34,125 @@ pub struct PhotoSmall {
     #[serde(flatten)]
     pub extra: ExtraFields,
 }
+
+/// Full photo information, as found in a project's photo set.
+///
+/// Ravelry serves each size as a separate URL; sizes the API omits are
+/// `None`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PhotoFull {
+    /// Unique photo ID.
+    pub id: u64,
+
+    /// Sort order (lower numbers appear first).
+    #[serde(default)]
+    pub sort_order: Option<i32>,
+
+    /// Caption as plain text.
+    #[serde(default)]
+    pub caption: Option<String>,
+
+    /// Caption rendered as HTML.
+    #[serde(default)]
+    pub caption_html: Option<String>,
+
+    /// Horizontal offset of the square crop within the original image.
+    #[serde(default)]
+    pub x_offset: Option<i32>,
+
+    /// Vertical offset of the square crop within the original image.
+    #[serde(default)]
+    pub y_offset: Option<i32>,
+
+    /// Width of the original image, in pixels.
+    #[serde(default)]
+    pub width: Option<u32>,
+
+    /// Height of the original image, in pixels.
+    #[serde(default)]
+    pub height: Option<u32>,
+
+    /// Who holds the copyright, if credited.
+    #[serde(default)]
+    pub copyright_holder: Option<String>,
+
+    /// URL to the thumbnail version.
+    #[serde(default)]
+    pub thumbnail_url: Option<String>,
+
+    /// URL to the square version (cropped).
+    #[serde(default)]
+    pub square_url: Option<String>,
+
+    /// URL to the small version.
+    #[serde(default)]
+    pub small_url: Option<String>,
+
+    /// URL to the larger small version.
+    #[serde(default)]
+    pub small2_url: Option<String>,
+
+    /// URL to the medium version.
+    #[serde(default)]
+    pub medium_url: Option<String>,
+
+    /// URL to the larger medium version.
+    #[serde(default)]
+    pub medium2_url: Option<String>,
+
+    /// URL to the shelved (listing) version.
+    #[serde(default)]
+    pub shelved_url: Option<String>,
+
+    /// Original Flickr URL, for photos imported from Flickr.
+    #[serde(default)]
+    pub flickr_url: Option<String>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Editable photo fields, for [`PhotosApi::update`](crate::api::photos::PhotosApi::update).
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct PhotoPost {
+    /// Caption as plain text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+
+    /// Who holds the copyright.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copyright_holder: Option<String>,
+
+    /// Capture any additional fields for flexibility.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl PhotoPost {
+    /// Create an empty photo update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the caption.
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Set the copyright holder.
+    pub fn copyright_holder(mut self, holder: impl Into<String>) -> Self {
+        self.copyright_holder = Some(holder.into());
+        self
+    }
+
+    /// Set a photo field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
@@ -1,9 +1,11 @@
 //! Project types for the Ravelry API.
 
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 
-use super::common::ExtraFields;
-use super::photo::PhotoSmall;
+use super::common::{parse_timestamp, ExtraFields, ValidationIssue};
+use super::photo::{PhotoFull, PhotoSmall};
+use super::reference::{Craft, Happiness, ProjectStatus};
 
 /// Project information returned in search results and lists.
 ///
@@ -52,11 +54,40 @@ pub struct ProjectSmall {
     #[serde(default)]
     pub completed: Option<String>,
 
+    /// Number of comments, when the list endpoint reports it.
+    ///
+    /// Use [`ProjectsApi::comments_count`](crate::api::projects::ProjectsApi::comments_count)
+    /// to fetch it for a single project when this is `None`.
+    #[serde(default)]
+    pub comments_count: Option<u64>,
+
     /// Capture any additional fields.
     #[serde(flatten)]
     pub extra: ExtraFields,
 }
 
+impl ProjectSmall {
+    /// Parses `started` (the start date); see [`parse_timestamp`] for the format.
+    pub fn started_at(&self) -> Option<Result<OffsetDateTime, time::error::Parse>> {
+        self.started.as_deref().map(parse_timestamp)
+    }
+
+    /// Parses `completed` (the completion date); see [`parse_timestamp`] for the format.
+    pub fn completed_at(&self) -> Option<Result<OffsetDateTime, time::error::Parse>> {
+        self.completed.as_deref().map(parse_timestamp)
+    }
+
+    /// Returns the project status as a typed value.
+    ///
+    /// Uses `status_name` when present, falling back to `status_id`.
+    pub fn status(&self) -> Option<ProjectStatus> {
+        self.status_name
+            .as_deref()
+            .map(ProjectStatus::from)
+            .or_else(|| self.status_id.map(ProjectStatus::from))
+    }
+}
+
 /// Full project information returned when fetching a single project.
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ProjectFull {
@@ -117,11 +148,100 @@ pub struct ProjectFull {
     #[serde(default)]
     pub comments_count: Option<u64>,
 
+    /// Whether the project appears in the owner's public project showcase.
+    ///
+    /// `false` means the project is hidden from the showcase.
+    #[serde(default)]
+    pub include_in_showcase: Option<bool>,
+
+    /// Ravelry user ID of the person the project is made for.
+    #[serde(default)]
+    pub made_for_user_id: Option<u64>,
+
+    /// Name of the person the project is made for.
+    #[serde(rename = "made_for", default)]
+    pub made_for_name: Option<String>,
+
+    /// Whether the project was given away as a gift.
+    #[serde(default)]
+    pub gifted: Option<bool>,
+
+    /// Set when the API returns a tombstone for a deleted project.
+    #[serde(default)]
+    pub deleted: Option<bool>,
+
+    /// The project's photos, in the owner's order.
+    #[serde(default)]
+    pub photos: Vec<PhotoFull>,
+
     /// Capture any additional fields.
     #[serde(flatten)]
     pub extra: ExtraFields,
 }
 
+impl ProjectFull {
+    /// Parses `started` (the start date); see [`parse_timestamp`] for the format.
+    pub fn started_at(&self) -> Option<Result<OffsetDateTime, time::error::Parse>> {
+        self.started.as_deref().map(parse_timestamp)
+    }
+
+    /// Parses `completed` (the completion date); see [`parse_timestamp`] for the format.
+    pub fn completed_at(&self) -> Option<Result<OffsetDateTime, time::error::Parse>> {
+        self.completed.as_deref().map(parse_timestamp)
+    }
+
+    /// Returns the project status as a typed value.
+    ///
+    /// Uses `status_name` when present, falling back to `status_id`.
+    pub fn status(&self) -> Option<ProjectStatus> {
+        self.status_name
+            .as_deref()
+            .map(ProjectStatus::from)
+            .or_else(|| self.status_id.map(ProjectStatus::from))
+    }
+
+    /// Returns the happiness rating as a typed value.
+    pub fn happiness(&self) -> Option<Happiness> {
+        self.rating.and_then(Happiness::from_u32)
+    }
+}
+
+/// Serializes image IDs as the photo objects Ravelry expects in a project post.
+fn serialize_image_ids<S>(ids: &[u64], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    #[derive(Serialize)]
+    struct Image {
+        image_id: u64,
+    }
+
+    serializer.collect_seq(ids.iter().map(|&image_id| Image { image_id }))
+}
+
+/// Whether a project exists, was deleted, or never existed.
+///
+/// Returned by [`ProjectsApi::state`](crate::api::projects::ProjectsApi::state).
+#[derive(Debug, Clone)]
+pub enum ProjectState {
+    /// The project exists.
+    Active(Box<ProjectFull>),
+    /// The project existed but has been deleted.
+    Deleted,
+    /// No such project (HTTP 404).
+    NotFound,
+}
+
+impl ProjectState {
+    /// Returns the project if it is active.
+    pub fn active(self) -> Option<ProjectFull> {
+        match self {
+            Self::Active(project) => Some(*project),
+            _ => None,
+        }
+    }
+}
+
 /// Project data for creating or updating a project.
 #[derive(Serialize, Debug, Default, Clone)]
 pub struct ProjectPost {
@@ -157,13 +277,48 @@ pub struct ProjectPost {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rating: Option<u32>,
 
-    /// Craft ID (knitting, crochet, etc.).
+    /// Craft ID (see [`Craft::to_id`]).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub craft_id: Option<u64>,
 
+    /// Whether the project appears in the owner's public project showcase.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_in_showcase: Option<bool>,
+
+    /// Ravelry user ID of the person the project is made for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub made_for_user_id: Option<u64>,
+
+    /// Name of the person the project is made for.
+    #[serde(rename = "made_for", skip_serializing_if = "Option::is_none")]
+    pub made_for_name: Option<String>,
+
+    /// Whether the project was given away as a gift.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gifted: Option<bool>,
+
+    /// Uploaded images to attach as photos, sent as
+    /// `"photos": [{ "image_id": ... }]`.
+    #[serde(
+        rename = "photos",
+        serialize_with = "serialize_image_ids",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub photo_ids: Vec<u64>,
+
     /// Capture any additional fields for flexibility.
     #[serde(flatten)]
     pub extra: ExtraFields,
+
+    /// A craft passed to [`craft`](Self::craft) that has no ID, reported by
+    /// [`validate`](Self::validate).
+    #[serde(skip)]
+    unsendable_craft: Option<Craft>,
+
+    /// A status passed to [`status`](Self::status) that has no ID, reported
+    /// by [`validate`](Self::validate).
+    #[serde(skip)]
+    unsendable_status: Option<ProjectStatus>,
 }
 
 impl ProjectPost {
@@ -172,6 +327,55 @@ impl ProjectPost {
         Self::default()
     }
 
+    /// Check this post against the API's known constraints before sending it.
+    ///
+    /// Set `for_create` when the post will be used with
+    /// [`ProjectsApi::create`](crate::api::projects::ProjectsApi::create),
+    /// which additionally requires a name. All problems are returned at
+    /// once rather than stopping at the first.
+    pub fn validate(&self, for_create: bool) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+
+        if for_create && self.name.as_deref().is_none_or(|n| n.trim().is_empty()) {
+            issues.push(ValidationIssue::new("name", "is required"));
+        }
+        if let Some(progress) = self.progress {
+            if progress > 100 {
+                issues.push(ValidationIssue::new(
+                    "progress",
+                    format!("must be between 0 and 100, got {progress}"),
+                ));
+            }
+        }
+        if let Some(rating) = self.rating {
+            if !(1..=4).contains(&rating) {
+                issues.push(ValidationIssue::new(
+                    "rating",
+                    format!("must be between 1 and 4, got {rating}"),
+                ));
+            }
+        }
+
+        if let Some(craft) = &self.unsendable_craft {
+            issues.push(ValidationIssue::new(
+                "craft_id",
+                format!("craft \"{craft}\" has no known ID; use craft_id"),
+            ));
+        }
+        if let Some(status) = &self.unsendable_status {
+            issues.push(ValidationIssue::new(
+                "status_id",
+                format!("status \"{status}\" has no known ID; use status_id"),
+            ));
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
     /// Set the project name.
     pub fn name(mut self, name: impl Into<String>) -> Self {
         self.name = Some(name.into());
@@ -184,9 +388,43 @@ impl ProjectPost {
         self
     }
 
-    /// Set the status.
+    /// Set the craft.
+    ///
+    /// Every listed craft has an ID. An [`Other`](Craft::Other) name doesn't
+    /// and can't be sent: it clears `craft_id` and
+    /// [`validate`](Self::validate) reports it.
+    pub fn craft(mut self, craft: Craft) -> Self {
+        self.craft_id = craft.to_id();
+        self.unsendable_craft = self.craft_id.is_none().then_some(craft);
+        self
+    }
+
+    /// Set the craft by numeric ID.
+    pub fn craft_id(mut self, id: u64) -> Self {
+        self.craft_id = Some(id);
+        self.unsendable_craft = None;
+        self
+    }
+
+    /// Set the status by numeric ID.
+    ///
+    /// Prefer [`status`](Self::status); this is for IDs the crate doesn't
+    /// know about yet.
     pub fn status_id(mut self, id: u64) -> Self {
         self.status_id = Some(id);
+        self.unsendable_status = None;
+        self
+    }
+
+    /// Set the status.
+    ///
+    /// An [`Other`](ProjectStatus::Other) status has no numeric ID (see
+    /// [`ProjectStatus::to_id`]) and can't be sent: it clears `status_id`
+    /// and [`validate`](Self::validate) reports it. Use
+    /// [`status_id`](Self::status_id) to send an ID directly.
+    pub fn status(mut self, status: ProjectStatus) -> Self {
+        self.status_id = status.to_id();
+        self.unsendable_status = self.status_id.is_none().then_some(status);
         self
     }
 
@@ -195,4 +433,147 @@ impl ProjectPost {
         self.progress = Some(pct);
         self
     }
+
+    /// Set the happiness rating.
+    pub fn happiness(mut self, happiness: Happiness) -> Self {
+        self.rating = Some(happiness.to_u32());
+        self
+    }
+
+    /// Set whether the project appears in the public showcase.
+    ///
+    /// Pass `false` to create a private project that is hidden from the showcase.
+    pub fn include_in_showcase(mut self, include: bool) -> Self {
+        self.include_in_showcase = Some(include);
+        self
+    }
+
+    /// Set the Ravelry user the project is made for.
+    pub fn made_for_user_id(mut self, user_id: u64) -> Self {
+        self.made_for_user_id = Some(user_id);
+        self
+    }
+
+    /// Set the name of the person the project is made for.
+    pub fn made_for_name(mut self, name: impl Into<String>) -> Self {
+        self.made_for_name = Some(name.into());
+        self
+    }
+
+    /// Mark whether the project was given away as a gift.
+    pub fn gifted(mut self, gifted: bool) -> Self {
+        self.gifted = Some(gifted);
+        self
+    }
+
+    /// Attach uploaded images as project photos.
+    ///
+    /// Pass the `image_id`s from an
+    /// [`UploadApi::image`](crate::api::upload::UploadApi::image) response;
+    /// without this, uploaded images aren't linked to anything.
+    pub fn photo_ids(mut self, image_ids: Vec<u64>) -> Self {
+        self.photo_ids = image_ids;
+        self
+    }
+
+    /// Set a project field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_craft_builder_sets_id() {
+        assert_eq!(ProjectPost::new().craft(Craft::Knitting).craft_id, Some(2));
+        assert_eq!(ProjectPost::new().craft_id(5).craft_id, Some(5));
+
+        let post = ProjectPost::new().craft_id(2).craft(Craft::Weaving);
+        assert_eq!(post.craft_id, Some(5));
+        assert_eq!(ProjectPost::new().craft(Craft::from(8)).craft_id, Some(8));
+
+        let post = ProjectPost::new().craft(Craft::Other("Tatting".to_string()));
+        assert_eq!(post.craft_id, None);
+        let issues = post.validate(false).unwrap_err();
+        assert_eq!(issues[0].field, "craft_id");
+    }
+
+    #[test]
+    fn test_status_builder_sets_id() {
+        let post = ProjectPost::new().status(ProjectStatus::Finished);
+        assert_eq!(post.status_id, Some(2));
+
+        let post = ProjectPost::new().status(ProjectStatus::from(7));
+        assert_eq!(post.status_id, Some(7));
+
+        let post = ProjectPost::new()
+            .status_id(2)
+            .status(ProjectStatus::Other("Gifted away".to_string()));
+        assert_eq!(post.status_id, None);
+        let issues = post.validate(false).unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "status_id");
+
+        let post = post.status(ProjectStatus::Frogged);
+        assert_eq!(post.status_id, Some(4));
+        assert!(post.validate(false).is_ok());
+    }
+
+    #[test]
+    fn test_status_falls_back_to_id() {
+        let project: ProjectFull = serde_json::from_value(serde_json::json!({
+            "id": 1, "name": "Hat", "permalink": "hat", "status_id": 3
+        }))
+        .unwrap();
+        assert_eq!(project.status(), Some(ProjectStatus::Hibernating));
+
+        let project: ProjectFull = serde_json::from_value(serde_json::json!({
+            "id": 1, "name": "Hat", "permalink": "hat", "status_id": 42
+        }))
+        .unwrap();
+        assert_eq!(project.status(), Some(ProjectStatus::Unknown(42)));
+    }
+
+    #[test]
+    fn test_validate_collects_all_issues() {
+        let mut post = ProjectPost::new().progress(150);
+        post.rating = Some(0);
+        let issues = post.validate(true).unwrap_err();
+        let fields: Vec<_> = issues.iter().map(|i| i.field).collect();
+        assert_eq!(fields, ["name", "progress", "rating"]);
+    }
+
+    #[test]
+    fn test_validate_update_does_not_require_name() {
+        let mut post = ProjectPost::new().progress(100);
+        post.rating = Some(4);
+        assert!(post.validate(false).is_ok());
+        assert!(post.validate(true).is_err());
+        assert!(post.name("Socks").validate(true).is_ok());
+    }
+
+    #[test]
+    fn test_happiness_round_trips_through_rating() {
+        for happiness in Happiness::ALL {
+            let post = ProjectPost::new().happiness(happiness);
+            let value = serde_json::to_value(&post).unwrap();
+            assert_eq!(value["rating"], happiness.to_u32());
+
+            let project: ProjectFull = serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "name": "Hat",
+                "permalink": "hat",
+                "rating": value["rating"]
+            }))
+            .unwrap();
+            assert_eq!(project.happiness(), Some(happiness));
+        }
+    }
 }
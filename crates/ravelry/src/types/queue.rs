@@ -0,0 +1,153 @@
+//! Queue types for the Ravelry API.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::ExtraFields;
+use super::reference::YarnWeight;
+
+/// A queue entry, as returned in queue lists.
+///
+/// Use [`QueueFull`] for complete entry details.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QueueList {
+    /// Unique queue entry ID.
+    pub id: u64,
+
+    /// Name given to the queued project.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Queued pattern ID, if the entry is linked to a pattern.
+    #[serde(default)]
+    pub pattern_id: Option<u64>,
+
+    /// Queued pattern name.
+    #[serde(default)]
+    pub pattern_name: Option<String>,
+
+    /// Position in the queue (1 is the top).
+    #[serde(default)]
+    pub sort_order: Option<u32>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Full queue entry information returned when fetching a single entry.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct QueueFull {
+    /// Unique queue entry ID.
+    pub id: u64,
+
+    /// Name given to the queued project.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Queued pattern ID, if the entry is linked to a pattern.
+    #[serde(default)]
+    pub pattern_id: Option<u64>,
+
+    /// Queued pattern name.
+    #[serde(default)]
+    pub pattern_name: Option<String>,
+
+    /// Position in the queue (1 is the top).
+    #[serde(default)]
+    pub sort_order: Option<u32>,
+
+    /// Notes about this queue entry.
+    #[serde(default)]
+    pub notes: Option<String>,
+
+    /// Notes as HTML.
+    #[serde(default)]
+    pub notes_html: Option<String>,
+
+    /// When the entry was added to the queue.
+    #[serde(default)]
+    pub created_at: Option<String>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Queue data for creating or updating a queue entry.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct QueuePost {
+    /// Pattern to queue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern_id: Option<u64>,
+
+    /// Name for the queued project.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Notes about this queue entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+
+    /// Position in the queue (1 is the top).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<u32>,
+
+    /// Capture any additional fields for flexibility.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl QueuePost {
+    /// Create a new empty queue post.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Link to a pattern.
+    pub fn pattern_id(mut self, id: u64) -> Self {
+        self.pattern_id = Some(id);
+        self
+    }
+
+    /// Set the name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the notes.
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    /// Set the position in the queue.
+    pub fn sort_order(mut self, position: u32) -> Self {
+        self.sort_order = Some(position);
+        self
+    }
+
+    /// Set a queue field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Total yarn needed in one weight category, aggregated across patterns.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct YarnRequirement {
+    /// Yarn weight category, or `None` for patterns that don't specify one.
+    pub yarn_weight: Option<YarnWeight>,
+
+    /// Total yardage needed.
+    pub yardage: u64,
+
+    /// IDs of the patterns contributing to this requirement, in queue order.
+    ///
+    /// A pattern queued more than once appears once per entry.
+    pub pattern_ids: Vec<u64>,
+}
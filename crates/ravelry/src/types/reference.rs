@@ -0,0 +1,802 @@
+//! Typed reference values (crafts, project statuses, yarn weights, happiness,
+//! search sort orders).
+//!
+//! These mirror Ravelry's reference data. Enums read by name have an
+//! `Other(String)` variant, so names Ravelry adds later are captured rather
+//! than rejected; [`Craft`] and [`ProjectStatus`] also have an `Unknown(u64)`
+//! variant for IDs they don't recognize. [`Happiness`] is a fixed 1-4 scale and
+//! has neither.
+//!
+//! The structs at the end of this module ([`Needle`], [`YarnWeightInfo`],
+//! [`ColorFamily`], and the pattern taxonomies) are the full reference
+//! records returned by [`ReferenceApi`](crate::api::reference::ReferenceApi).
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use super::common::{string_enum, ExtraFields};
+
+/// A craft (knitting, crochet, ...).
+///
+/// Like [`ProjectStatus`], crafts can also be identified by a numeric ID
+/// alone: IDs this crate doesn't know become [`Unknown`](Self::Unknown),
+/// while unknown names become [`Other`](Self::Other).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Craft {
+    /// Hand knitting.
+    Knitting,
+    /// Crochet.
+    Crochet,
+    /// Loom knitting.
+    LoomKnitting,
+    /// Machine knitting.
+    MachineKnitting,
+    /// Weaving.
+    Weaving,
+    /// Spinning.
+    Spinning,
+    /// A craft name not known to this version of the crate.
+    Other(String),
+    /// A craft ID not known to this version of the crate.
+    Unknown(u64),
+}
+
+impl Craft {
+    /// Returns the craft name as sent to and received from the API.
+    ///
+    /// An [`Unknown`](Self::Unknown) craft has no name, so its ID is returned
+    /// as text instead.
+    pub fn as_str(&self) -> Cow<'_, str> {
+        match self {
+            Self::Knitting => Cow::Borrowed("Knitting"),
+            Self::Crochet => Cow::Borrowed("Crochet"),
+            Self::LoomKnitting => Cow::Borrowed("Loom Knitting"),
+            Self::MachineKnitting => Cow::Borrowed("Machine Knitting"),
+            Self::Weaving => Cow::Borrowed("Weaving"),
+            Self::Spinning => Cow::Borrowed("Spinning"),
+            Self::Other(value) => Cow::Borrowed(value),
+            Self::Unknown(id) => Cow::Owned(id.to_string()),
+        }
+    }
+
+    /// Returns `true` if this is the [`Other`](Self::Other) fallback.
+    pub fn is_other(&self) -> bool {
+        matches!(self, Self::Other(_))
+    }
+
+    /// Returns the numeric craft ID used by `craft_id`.
+    ///
+    /// [`Other`](Self::Other) names have no known ID and return `None`.
+    pub fn to_id(&self) -> Option<u64> {
+        match self {
+            Self::Crochet => Some(1),
+            Self::Knitting => Some(2),
+            Self::MachineKnitting => Some(3),
+            Self::LoomKnitting => Some(4),
+            Self::Weaving => Some(5),
+            Self::Spinning => Some(6),
+            Self::Other(_) => None,
+            Self::Unknown(id) => Some(*id),
+        }
+    }
+
+    /// Returns the URL permalink used by search filters (e.g. "loom-knitting").
+    pub fn permalink(&self) -> String {
+        self.as_str().to_ascii_lowercase().replace(' ', "-")
+    }
+}
+
+impl From<u64> for Craft {
+    /// Convert a numeric craft ID; unknown IDs become `Unknown(id)`.
+    fn from(id: u64) -> Self {
+        match id {
+            1 => Self::Crochet,
+            2 => Self::Knitting,
+            3 => Self::MachineKnitting,
+            4 => Self::LoomKnitting,
+            5 => Self::Weaving,
+            6 => Self::Spinning,
+            _ => Self::Unknown(id),
+        }
+    }
+}
+
+impl From<&str> for Craft {
+    /// Convert a craft name, or a numeric ID written as text (`"2"`).
+    fn from(value: &str) -> Self {
+        const NAMES: [(&str, Craft); 6] = [
+            ("Knitting", Craft::Knitting),
+            ("Crochet", Craft::Crochet),
+            ("Loom Knitting", Craft::LoomKnitting),
+            ("Machine Knitting", Craft::MachineKnitting),
+            ("Weaving", Craft::Weaving),
+            ("Spinning", Craft::Spinning),
+        ];
+        if let Some((_, craft)) = NAMES
+            .iter()
+            .find(|(name, _)| value.eq_ignore_ascii_case(name))
+        {
+            return craft.clone();
+        }
+        match value.parse::<u64>() {
+            Ok(id) => Self::from(id),
+            Err(_) => Self::Other(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Craft {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl std::str::FromStr for Craft {
+    type Err = std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
+impl std::fmt::Display for Craft {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.as_str())
+    }
+}
+
+impl Serialize for Craft {
+    /// Serializes as the craft name, or as the numeric ID for `Unknown`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(id) => serializer.serialize_u64(*id),
+            _ => serializer.serialize_str(&self.as_str()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Craft {
+    /// Accepts either a craft name or a numeric craft ID.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Id(u64),
+            Name(String),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Id(id) => Self::from(id),
+            Raw::Name(name) => Self::from(name),
+        })
+    }
+}
+
+/// A project's status.
+///
+/// Like [`Craft`], statuses can also be identified by a numeric ID alone: IDs this crate doesn't know become
+/// [`Unknown`](Self::Unknown), while unknown names become
+/// [`Other`](Self::Other).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProjectStatus {
+    /// Work in progress.
+    InProgress,
+    /// Completed.
+    Finished,
+    /// Set aside for now.
+    Hibernating,
+    /// Unravelled.
+    Frogged,
+    /// A status name not known to this version of the crate.
+    Other(String),
+    /// A status ID not known to this version of the crate.
+    Unknown(u64),
+}
+
+impl ProjectStatus {
+    /// Returns the status name as sent to and received from the API.
+    ///
+    /// An [`Unknown`](Self::Unknown) status has no name, so its ID is
+    /// returned as text instead.
+    pub fn as_str(&self) -> Cow<'_, str> {
+        match self {
+            Self::InProgress => Cow::Borrowed("In progress"),
+            Self::Finished => Cow::Borrowed("Finished"),
+            Self::Hibernating => Cow::Borrowed("Hibernating"),
+            Self::Frogged => Cow::Borrowed("Frogged"),
+            Self::Other(value) => Cow::Borrowed(value),
+            Self::Unknown(id) => Cow::Owned(id.to_string()),
+        }
+    }
+
+    /// Returns `true` if this is the [`Other`](Self::Other) fallback.
+    pub fn is_other(&self) -> bool {
+        matches!(self, Self::Other(_))
+    }
+
+    /// Returns the numeric status ID used by `status_id`.
+    ///
+    /// [`Other`](Self::Other) names have no known ID and return `None`.
+    pub fn to_id(&self) -> Option<u64> {
+        match self {
+            Self::InProgress => Some(1),
+            Self::Finished => Some(2),
+            Self::Hibernating => Some(3),
+            Self::Frogged => Some(4),
+            Self::Other(_) => None,
+            Self::Unknown(id) => Some(*id),
+        }
+    }
+}
+
+impl From<u64> for ProjectStatus {
+    /// Convert a numeric status ID; unknown IDs become `Unknown(id)`.
+    fn from(id: u64) -> Self {
+        match id {
+            1 => Self::InProgress,
+            2 => Self::Finished,
+            3 => Self::Hibernating,
+            4 => Self::Frogged,
+            _ => Self::Unknown(id),
+        }
+    }
+}
+
+impl From<&str> for ProjectStatus {
+    /// Convert a status name, or a numeric ID written as text (`"2"`).
+    fn from(value: &str) -> Self {
+        const NAMES: [(&str, ProjectStatus); 4] = [
+            ("In progress", ProjectStatus::InProgress),
+            ("Finished", ProjectStatus::Finished),
+            ("Hibernating", ProjectStatus::Hibernating),
+            ("Frogged", ProjectStatus::Frogged),
+        ];
+        if let Some((_, status)) = NAMES
+            .iter()
+            .find(|(name, _)| value.eq_ignore_ascii_case(name))
+        {
+            return status.clone();
+        }
+        match value.parse::<u64>() {
+            Ok(id) => Self::from(id),
+            Err(_) => Self::Other(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for ProjectStatus {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl std::str::FromStr for ProjectStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(value))
+    }
+}
+
+impl std::fmt::Display for ProjectStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.as_str())
+    }
+}
+
+impl Serialize for ProjectStatus {
+    /// Serializes as the status name, or as the numeric ID for `Unknown`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown(id) => serializer.serialize_u64(*id),
+            _ => serializer.serialize_str(&self.as_str()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ProjectStatus {
+    /// Accepts either a status name or a numeric status ID.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Id(u64),
+            Name(String),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Id(id) => Self::from(id),
+            Raw::Name(name) => Self::from(name),
+        })
+    }
+}
+
+string_enum! {
+    /// A yarn weight category.
+    pub enum YarnWeight {
+        /// Thread.
+        Thread => "Thread",
+        /// Cobweb.
+        Cobweb => "Cobweb",
+        /// Lace.
+        Lace => "Lace",
+        /// Light fingering.
+        LightFingering => "Light Fingering",
+        /// Fingering.
+        Fingering => "Fingering",
+        /// Sport.
+        Sport => "Sport",
+        /// DK.
+        Dk => "DK",
+        /// Worsted.
+        Worsted => "Worsted",
+        /// Aran.
+        Aran => "Aran",
+        /// Bulky.
+        Bulky => "Bulky",
+        /// Super bulky.
+        SuperBulky => "Super Bulky",
+        /// Jumbo.
+        Jumbo => "Jumbo",
+    }
+}
+
+impl YarnWeight {
+    /// All known weights, from thinnest to thickest.
+    pub const ALL: [YarnWeight; 12] = [
+        Self::Thread,
+        Self::Cobweb,
+        Self::Lace,
+        Self::LightFingering,
+        Self::Fingering,
+        Self::Sport,
+        Self::Dk,
+        Self::Worsted,
+        Self::Aran,
+        Self::Bulky,
+        Self::SuperBulky,
+        Self::Jumbo,
+    ];
+
+    /// Returns the permalink used by search filters (e.g. "light-fingering").
+    pub fn permalink(&self) -> String {
+        self.as_str().to_ascii_lowercase().replace(' ', "-")
+    }
+}
+
+/// How happy a user is with a finished project, on Ravelry's 1-4 scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Happiness {
+    /// 1: hated it.
+    Hated,
+    /// 2: mixed feelings.
+    Mixed,
+    /// 3: liked it.
+    Liked,
+    /// 4: loved it.
+    Loved,
+}
+
+impl Happiness {
+    /// All values, from least to most happy.
+    pub const ALL: [Happiness; 4] = [Self::Hated, Self::Mixed, Self::Liked, Self::Loved];
+
+    /// Convert an API rating (1-4) to a happiness value.
+    pub fn from_u32(rating: u32) -> Option<Self> {
+        match rating {
+            1 => Some(Self::Hated),
+            2 => Some(Self::Mixed),
+            3 => Some(Self::Liked),
+            4 => Some(Self::Loved),
+            _ => None,
+        }
+    }
+
+    /// Returns the API rating (1-4).
+    pub fn to_u32(self) -> u32 {
+        match self {
+            Self::Hated => 1,
+            Self::Mixed => 2,
+            Self::Liked => 3,
+            Self::Loved => 4,
+        }
+    }
+
+    /// Returns a short label, e.g. "Loved it".
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Hated => "Hated it",
+            Self::Mixed => "Mixed feelings",
+            Self::Liked => "Liked it",
+            Self::Loved => "Loved it",
+        }
+    }
+
+    /// Returns an emoji matching Ravelry's happiness faces.
+    pub fn emoji(self) -> &'static str {
+        match self {
+            Self::Hated => "😣",
+            Self::Mixed => "😐",
+            Self::Liked => "🙂",
+            Self::Loved => "😍",
+        }
+    }
+}
+
+impl std::fmt::Display for Happiness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+string_enum! {
+    /// A sort order for [`PatternSearchParams`](crate::api::patterns::PatternSearchParams).
+    pub enum PatternSort {
+        /// Ravelry's relevance ranking (the default).
+        BestMatch => "best",
+        /// Popular in the last few days.
+        RecentlyPopular => "recently-popular",
+        /// Most popular overall.
+        Popularity => "popularity",
+        /// Newest first.
+        Date => "date",
+        /// Most favorited first.
+        Favorites => "favorites",
+        /// Most projects first.
+        Projects => "projects",
+        /// Most queued first.
+        Queues => "queues",
+        /// Highest rated first.
+        Rating => "rating",
+        /// Easiest first.
+        Difficulty => "difficulty",
+        /// Alphabetical by name.
+        Name => "name",
+    }
+}
+
+string_enum! {
+    /// A sort order for [`YarnSearchParams`](crate::api::yarns::YarnSearchParams).
+    pub enum YarnSort {
+        /// Ravelry's relevance ranking (the default).
+        BestMatch => "best",
+        /// Highest rated first.
+        Rating => "rating",
+        /// Most projects first.
+        Projects => "projects",
+        /// Most stashed first.
+        Stashes => "stashes",
+        /// Alphabetical by name.
+        Name => "name",
+        /// Thinnest weight first.
+        Weight => "weight",
+    }
+}
+
+/// A needle or hook size, from [`ReferenceApi::needles`](crate::api::reference::ReferenceApi::needles).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Needle {
+    /// Unique needle size ID.
+    pub id: u64,
+
+    /// Display name (e.g. "US 6 - 4.0 mm").
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// US size (e.g. "6").
+    #[serde(default)]
+    pub us: Option<String>,
+
+    /// Metric size in millimeters.
+    #[serde(default)]
+    pub metric: Option<f64>,
+
+    /// Metric size formatted for display (e.g. "4.0").
+    #[serde(default)]
+    pub pretty_metric: Option<String>,
+
+    /// US steel crochet hook size, if any.
+    #[serde(default)]
+    pub us_steel: Option<String>,
+
+    /// Crochet hook letter size (e.g. "G-6").
+    #[serde(default)]
+    pub hook: Option<String>,
+
+    /// Whether this size is used for knitting needles.
+    #[serde(default)]
+    pub knitting: Option<bool>,
+
+    /// Whether this size is used for crochet hooks.
+    #[serde(default)]
+    pub crochet: Option<bool>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A yarn weight with its typical gauges, from
+/// [`ReferenceApi::yarn_weights`](crate::api::reference::ReferenceApi::yarn_weights).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct YarnWeightInfo {
+    /// Unique yarn weight ID.
+    pub id: u64,
+
+    /// Weight name (e.g. "Worsted").
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Ply equivalent (e.g. "10").
+    #[serde(default)]
+    pub ply: Option<String>,
+
+    /// Wraps per inch.
+    #[serde(default)]
+    pub wpi: Option<String>,
+
+    /// Typical knitting gauge, as a stitch count range per 4 inches.
+    #[serde(default)]
+    pub knit_gauge: Option<String>,
+
+    /// Typical crochet gauge, as a stitch count range per 4 inches.
+    #[serde(default)]
+    pub crochet_gauge: Option<String>,
+
+    /// Smallest typical stitch count per 4 inches.
+    #[serde(default)]
+    pub min_gauge: Option<f64>,
+
+    /// Largest typical stitch count per 4 inches.
+    #[serde(default)]
+    pub max_gauge: Option<f64>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl YarnWeightInfo {
+    /// Returns the weight as a typed [`YarnWeight`], if it has a name.
+    pub fn weight(&self) -> Option<YarnWeight> {
+        self.name.as_deref().map(YarnWeight::from)
+    }
+}
+
+/// A color family used to classify colorways, from
+/// [`ReferenceApi::color_families`](crate::api::reference::ReferenceApi::color_families).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ColorFamily {
+    /// Unique color family ID.
+    pub id: u64,
+
+    /// Family name (e.g. "Blue-green").
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// URL-friendly identifier.
+    #[serde(default)]
+    pub permalink: Option<String>,
+
+    /// Representative color as a hex string (e.g. "#3a7d7b").
+    #[serde(default)]
+    pub color: Option<String>,
+
+    /// Position in the color spectrum, for ordering.
+    #[serde(default)]
+    pub spectrum_order: Option<i32>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A node in the pattern category tree, from
+/// [`ReferenceApi::pattern_categories`](crate::api::reference::ReferenceApi::pattern_categories).
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PatternCategory {
+    /// Unique category ID.
+    pub id: u64,
+
+    /// Category name (e.g. "Hat").
+    pub name: String,
+
+    /// URL-friendly identifier, as used by the `pc` search filter.
+    #[serde(default)]
+    pub permalink: Option<String>,
+
+    /// Subcategories.
+    #[serde(default)]
+    pub children: Vec<PatternCategory>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A group of pattern attributes, from
+/// [`ReferenceApi::pattern_attribute_groups`](crate::api::reference::ReferenceApi::pattern_attribute_groups).
+///
+/// Groups nest: a group may hold both attributes and subgroups.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PatternAttributeGroup {
+    /// Unique group ID.
+    pub id: u64,
+
+    /// Group name (e.g. "Construction").
+    pub name: String,
+
+    /// URL-friendly identifier.
+    #[serde(default)]
+    pub permalink: Option<String>,
+
+    /// Subgroups.
+    #[serde(default)]
+    pub children: Vec<PatternAttributeGroup>,
+
+    /// Attributes directly in this group.
+    #[serde(default)]
+    pub pattern_attributes: Vec<PatternAttribute>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A single pattern attribute (e.g. "seamless").
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PatternAttribute {
+    /// Unique attribute ID.
+    pub id: u64,
+
+    /// Attribute name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// URL-friendly identifier, as used by the `pa` search filter.
+    #[serde(default)]
+    pub permalink: Option<String>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_values_deserialize() {
+        let craft: Craft = serde_json::from_str("\"Crochet\"").unwrap();
+        assert_eq!(craft, Craft::Crochet);
+
+        let status: ProjectStatus = serde_json::from_str("\"in progress\"").unwrap();
+        assert_eq!(status, ProjectStatus::InProgress);
+
+        let weight: YarnWeight = serde_json::from_str("\"DK\"").unwrap();
+        assert_eq!(weight, YarnWeight::Dk);
+    }
+
+    #[test]
+    fn test_unknown_values_are_captured() {
+        let craft: Craft = serde_json::from_str("\"Tatting\"").unwrap();
+        assert_eq!(craft, Craft::Other("Tatting".to_string()));
+        assert!(craft.is_other());
+
+        let status: ProjectStatus = serde_json::from_str("\"Gifted away\"").unwrap();
+        assert_eq!(status, ProjectStatus::Other("Gifted away".to_string()));
+
+        let weight: YarnWeight = serde_json::from_str("\"Ultra Bulky\"").unwrap();
+        assert_eq!(weight, YarnWeight::Other("Ultra Bulky".to_string()));
+    }
+
+    #[test]
+    fn test_values_round_trip() {
+        for craft in [Craft::LoomKnitting, Craft::Other("Tatting".to_string())] {
+            let json = serde_json::to_string(&craft).unwrap();
+            assert_eq!(serde_json::from_str::<Craft>(&json).unwrap(), craft);
+        }
+        assert_eq!(
+            serde_json::to_string(&YarnWeight::SuperBulky).unwrap(),
+            "\"Super Bulky\""
+        );
+        assert_eq!(YarnWeight::Other("Ultra".to_string()).to_string(), "Ultra");
+    }
+
+    #[test]
+    fn test_yarn_weights_round_trip() {
+        for weight in YarnWeight::ALL {
+            assert!(!weight.is_other());
+            let json = serde_json::to_string(&weight).unwrap();
+            assert_eq!(serde_json::from_str::<YarnWeight>(&json).unwrap(), weight);
+            assert_eq!(YarnWeight::from(weight.as_str()), weight);
+        }
+
+        let other = YarnWeight::Other("Ultra Bulky".to_string());
+        let json = serde_json::to_string(&other).unwrap();
+        assert_eq!(json, "\"Ultra Bulky\"");
+        assert_eq!(serde_json::from_str::<YarnWeight>(&json).unwrap(), other);
+    }
+
+    #[test]
+    fn test_happiness_round_trips() {
+        for happiness in Happiness::ALL {
+            assert_eq!(Happiness::from_u32(happiness.to_u32()), Some(happiness));
+        }
+        assert_eq!(Happiness::Hated.to_u32(), 1);
+        assert_eq!(Happiness::Loved.to_u32(), 4);
+        assert_eq!(Happiness::from_u32(0), None);
+        assert_eq!(Happiness::from_u32(5), None);
+        assert_eq!(Happiness::Liked.to_string(), "Liked it");
+    }
+
+    #[test]
+    fn test_project_status_ids_round_trip() {
+        for id in 1..=4 {
+            let status = ProjectStatus::from(id);
+            assert!(!status.is_other());
+            assert_eq!(status.to_id(), Some(id));
+        }
+        assert_eq!(ProjectStatus::from(2), ProjectStatus::Finished);
+
+        assert_eq!(ProjectStatus::from("2"), ProjectStatus::Finished);
+
+        let unknown = ProjectStatus::from(9);
+        assert_eq!(unknown, ProjectStatus::Unknown(9));
+        assert_eq!(ProjectStatus::from("9"), unknown);
+        assert_eq!(unknown.to_id(), Some(9));
+        assert_eq!(unknown.to_string(), "9");
+        assert_eq!(unknown.as_str(), "9");
+        assert_eq!(ProjectStatus::Finished.as_str(), "Finished");
+        assert_eq!(ProjectStatus::from("Gifted away").to_id(), None);
+
+        let json = serde_json::to_string(&unknown).unwrap();
+        assert_eq!(json, "9");
+        assert_eq!(
+            serde_json::from_str::<ProjectStatus>(&json).unwrap(),
+            unknown
+        );
+    }
+
+    #[test]
+    fn test_craft_ids_and_permalinks() {
+        for (id, craft) in [
+            (1, Craft::Crochet),
+            (2, Craft::Knitting),
+            (3, Craft::MachineKnitting),
+            (4, Craft::LoomKnitting),
+            (5, Craft::Weaving),
+            (6, Craft::Spinning),
+        ] {
+            assert_eq!(craft.to_id(), Some(id));
+            assert_eq!(Craft::from(id), craft);
+            assert_eq!(Craft::from(craft.as_str().as_ref()), craft);
+        }
+
+        let unknown = Craft::from(8);
+        assert_eq!(unknown, Craft::Unknown(8));
+        assert_eq!(Craft::from("8"), unknown);
+        assert_eq!(unknown.to_id(), Some(8));
+        assert_eq!(unknown.as_str(), "8");
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "8");
+        assert_eq!(serde_json::from_str::<Craft>("8").unwrap(), unknown);
+        assert_eq!(Craft::from("Tatting").to_id(), None);
+
+        assert_eq!(Craft::Knitting.permalink(), "knitting");
+        assert_eq!(Craft::MachineKnitting.permalink(), "machine-knitting");
+    }
+}
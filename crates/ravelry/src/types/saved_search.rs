@@ -0,0 +1,84 @@
+//! Saved search types for the Ravelry API.
+//!
+//! A saved search stores a search's filters under a name so it can be rerun.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::ExtraFields;
+
+/// A search a user has saved.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SavedSearch {
+    /// Unique saved search ID.
+    pub id: u64,
+
+    /// Name the user gave the search.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// The search's filters as a URL query string (e.g., "craft=knitting&weight=dk").
+    #[serde(default)]
+    pub query_string: Option<String>,
+
+    /// What is searched (e.g., "patterns", "yarns").
+    #[serde(default)]
+    pub search_type: Option<String>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Saved search data for creating a saved search.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct SavedSearchPost {
+    /// Name for the search.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The search's filters as a URL query string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_string: Option<String>,
+
+    /// What is searched (e.g., "patterns", "yarns").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_type: Option<String>,
+
+    /// Capture any additional fields for flexibility.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl SavedSearchPost {
+    /// Create a new empty saved search post.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the search name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the search's filters as a URL query string.
+    pub fn query_string(mut self, query_string: impl Into<String>) -> Self {
+        self.query_string = Some(query_string.into());
+        self
+    }
+
+    /// Set what is searched.
+    pub fn search_type(mut self, search_type: impl Into<String>) -> Self {
+        self.search_type = Some(search_type.into());
+        self
+    }
+
+    /// Set a saved search field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
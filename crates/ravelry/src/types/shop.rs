@@ -0,0 +1,33 @@
+//! Shop (yarn store) types for the Ravelry API.
+
+use serde::{Deserialize, Serialize};
+
+use super::common::ExtraFields;
+
+/// A yarn shop.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Shop {
+    /// Unique shop ID.
+    #[serde(default)]
+    pub id: Option<u64>,
+
+    /// Shop name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// URL-friendly identifier.
+    #[serde(default)]
+    pub permalink: Option<String>,
+
+    /// The shop's website.
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// Human-readable location (e.g., "Portland, OR").
+    #[serde(default)]
+    pub location: Option<String>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
@@ -4,7 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::common::ExtraFields;
+use super::common::{ExtraFields, ValidationIssue};
 use super::photo::PhotoSmall;
 
 /// Stash entry information returned in lists.
@@ -133,6 +133,10 @@ pub struct StashPost {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
 
+    /// When it was acquired (`YYYY/MM/DD`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acquired: Option<String>,
+
     /// Personal rating (1-5).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub personal_rating: Option<u32>,
@@ -148,6 +152,48 @@ impl StashPost {
         Self::default()
     }
 
+    /// Check this post against the API's known constraints before sending it.
+    ///
+    /// Set `for_create` when the post will be used with
+    /// [`StashApi::create`](crate::api::stash::StashApi::create), which
+    /// additionally requires either a yarn ID or a name. All problems are
+    /// returned at once rather than stopping at the first.
+    pub fn validate(&self, for_create: bool) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+
+        if for_create
+            && self.yarn_id.is_none()
+            && self.name.as_deref().is_none_or(|n| n.trim().is_empty())
+        {
+            issues.push(ValidationIssue::new(
+                "yarn_id",
+                "either a yarn ID or a name is required",
+            ));
+        }
+        if let Some(skeins) = self.skeins {
+            if !skeins.is_finite() || skeins < 0.0 {
+                issues.push(ValidationIssue::new(
+                    "skeins",
+                    format!("must be a non-negative number, got {skeins}"),
+                ));
+            }
+        }
+        if let Some(rating) = self.personal_rating {
+            if !(1..=5).contains(&rating) {
+                issues.push(ValidationIssue::new(
+                    "personal_rating",
+                    format!("must be between 1 and 5, got {rating}"),
+                ));
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
     /// Link to a yarn.
     pub fn yarn_id(mut self, id: u64) -> Self {
         self.yarn_id = Some(id);
@@ -171,4 +217,81 @@ impl StashPost {
         self.skeins = Some(count);
         self
     }
+
+    /// Set the notes.
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    /// Set where it was acquired.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Set when it was acquired (`YYYY/MM/DD`).
+    pub fn acquired(mut self, date: impl Into<String>) -> Self {
+        self.acquired = Some(date.into());
+        self
+    }
+
+    /// Set the personal rating (1-5).
+    pub fn personal_rating(mut self, rating: u32) -> Self {
+        self.personal_rating = Some(rating);
+        self
+    }
+
+    /// Set a stash field this crate doesn't model yet.
+    ///
+    /// See [extra fields](crate::types#extra-fields-on-post-types) for how
+    /// it is sent and how it interacts with the typed fields.
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_collects_all_issues() {
+        let mut post = StashPost::new().skeins(-1.0);
+        post.personal_rating = Some(9);
+        let issues = post.validate(true).unwrap_err();
+        let fields: Vec<_> = issues.iter().map(|i| i.field).collect();
+        assert_eq!(fields, ["yarn_id", "skeins", "personal_rating"]);
+    }
+
+    #[test]
+    fn test_validate_accepts_yarn_id_for_create() {
+        assert!(StashPost::new()
+            .yarn_id(7)
+            .skeins(2.5)
+            .validate(true)
+            .is_ok());
+        assert!(StashPost::new().validate(false).is_ok());
+    }
+
+    #[test]
+    fn test_builders_set_optional_fields() {
+        let post = StashPost::new()
+            .yarn_id(7)
+            .notes("Gift from a friend")
+            .location("Rhinebeck")
+            .acquired("2024/10/19")
+            .personal_rating(5);
+        assert_eq!(
+            serde_json::to_value(&post).unwrap(),
+            serde_json::json!({
+                "yarn_id": 7,
+                "notes": "Gift from a friend",
+                "location": "Rhinebeck",
+                "acquired": "2024/10/19",
+                "personal_rating": 5
+            })
+        );
+    }
 }
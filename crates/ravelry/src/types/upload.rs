@@ -2,8 +2,12 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use super::common::ExtraFields;
+use crate::api::upload::MAX_UPLOAD_FILES;
+use crate::client::RavelryClient;
+use crate::error::RavelryError;
 
 /// A file to upload via the upload API.
 #[derive(Debug, Clone)]
@@ -64,3 +68,213 @@ pub struct UploadResult {
     #[serde(flatten)]
     pub extra: ExtraFields,
 }
+
+/// An in-progress upload that can be persisted and resumed.
+///
+/// Uploading takes several requests (token, images, status). A session
+/// records the upload token and which files have been uploaded so far, and
+/// is serializable so it can be written to disk between steps. After a
+/// crash, load the saved session and call [`resume`](Self::resume): it asks
+/// Ravelry which files already arrived and uploads only the rest.
+///
+/// # Example
+///
+/// ```no_run
+/// # use ravelry::{RavelryClient, auth::BasicAuth};
+/// use ravelry::types::UploadSession;
+///
+/// # async fn example() -> Result<(), ravelry::RavelryError> {
+/// # let client = RavelryClient::builder(BasicAuth::new("", "")).build()?;
+/// let mut session = UploadSession::start(&client, ["front.jpg", "back.jpg"]).await?;
+/// session
+///     .resume_with(&client, |session| {
+///         std::fs::write("upload.json", serde_json::to_vec(session)?)?;
+///         Ok(())
+///     })
+///     .await?;
+/// println!("image ids: {:?}", session.image_ids());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UploadSession {
+    /// The upload token all files are uploaded with.
+    pub upload_token: String,
+
+    /// The files in this upload, in the order given.
+    pub files: Vec<UploadSessionFile>,
+
+    /// Number of image requests sent so far.
+    #[serde(default)]
+    pub batches_sent: usize,
+}
+
+/// A file tracked by an [`UploadSession`].
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct UploadSessionFile {
+    /// Path the file is read from when it is uploaded.
+    pub path: PathBuf,
+
+    /// The content type (e.g., "image/jpeg"). If None, will be inferred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+
+    /// Where the file was last sent: the image request's index and the file's
+    /// form field name within it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sent_as: Option<(usize, String)>,
+
+    /// The image ID, once the upload is confirmed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_id: Option<u64>,
+}
+
+impl UploadSessionFile {
+    /// Track a file by path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            content_type: None,
+            sent_as: None,
+            image_id: None,
+        }
+    }
+
+    /// Set the content type.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+impl UploadSession {
+    /// Create a session for files to upload with an existing token.
+    pub fn new(
+        upload_token: impl Into<String>,
+        files: impl IntoIterator<Item = UploadSessionFile>,
+    ) -> Self {
+        Self {
+            upload_token: upload_token.into(),
+            files: files.into_iter().collect(),
+            batches_sent: 0,
+        }
+    }
+
+    /// Request a new upload token and create a session for `paths`.
+    ///
+    /// Nothing is uploaded until [`resume`](Self::resume) is called.
+    pub async fn start(
+        client: &RavelryClient,
+        paths: impl IntoIterator<Item = impl AsRef<Path>>,
+    ) -> Result<Self, RavelryError> {
+        let token = client.upload().request_token().await?.upload_token;
+        let files = paths
+            .into_iter()
+            .map(|path| UploadSessionFile::new(path.as_ref()));
+        Ok(Self::new(token, files))
+    }
+
+    /// Returns `true` once every file has an image ID.
+    pub fn is_complete(&self) -> bool {
+        self.files.iter().all(|file| file.image_id.is_some())
+    }
+
+    /// Returns the image IDs confirmed so far, in file order.
+    pub fn image_ids(&self) -> Vec<u64> {
+        self.files.iter().filter_map(|file| file.image_id).collect()
+    }
+
+    /// Upload every file that doesn't have an image ID yet.
+    ///
+    /// Files that were sent before but never confirmed are first looked up
+    /// with [`image_status`](crate::api::upload::UploadApi::image_status); only
+    /// files Ravelry doesn't know about are uploaded again, up to
+    /// [`MAX_UPLOAD_FILES`] per request.
+    pub async fn resume(&mut self, client: &RavelryClient) -> Result<(), RavelryError> {
+        self.resume_with(client, |_| Ok(())).await
+    }
+
+    /// Like [`resume`](Self::resume), calling `checkpoint` whenever the
+    /// session changes so it can be persisted.
+    ///
+    /// `checkpoint` runs before each image request is sent and after its
+    /// results are recorded. An error from `checkpoint` stops the upload.
+    pub async fn resume_with<F>(
+        &mut self,
+        client: &RavelryClient,
+        mut checkpoint: F,
+    ) -> Result<(), RavelryError>
+    where
+        F: FnMut(&UploadSession) -> Result<(), RavelryError>,
+    {
+        if self
+            .files
+            .iter()
+            .any(|file| file.image_id.is_none() && file.sent_as.is_some())
+        {
+            let status = client.upload().image_status(&self.upload_token).await?;
+            self.record(&status.uploads, None);
+            checkpoint(self)?;
+        }
+
+        loop {
+            let pending: Vec<usize> = (0..self.files.len())
+                .filter(|&i| self.files[i].image_id.is_none())
+                .take(MAX_UPLOAD_FILES)
+                .collect();
+            if pending.is_empty() {
+                return Ok(());
+            }
+
+            let batch = self.batches_sent;
+            let mut uploads = Vec::with_capacity(pending.len());
+            for (field, &i) in pending.iter().enumerate() {
+                let file = &mut self.files[i];
+                let filename = file.path.file_name().unwrap_or(file.path.as_os_str());
+                let mut upload = UploadFile::new(
+                    filename.to_string_lossy().into_owned(),
+                    std::fs::read(&file.path)?,
+                );
+                upload.content_type = file.content_type.clone();
+                file.sent_as = Some((batch, format!("file{}", field)));
+                uploads.push(upload);
+            }
+            self.batches_sent += 1;
+            checkpoint(self)?;
+
+            let response = client.upload().image(&self.upload_token, uploads).await?;
+            self.record(&response.uploads, Some(batch));
+            checkpoint(self)?;
+
+            if pending.iter().any(|&i| self.files[i].image_id.is_none()) {
+                return Err(RavelryError::InvalidRequest(format!(
+                    "upload response is missing results for batch {}",
+                    batch
+                )));
+            }
+        }
+    }
+
+    /// Fill in image IDs from upload results.
+    ///
+    /// `uploads` is either a status response, indexed by batch, or a single
+    /// image response for `batch`.
+    fn record(&mut self, uploads: &[HashMap<String, UploadResult>], batch: Option<usize>) {
+        for file in &mut self.files {
+            let Some((sent_batch, field)) = &file.sent_as else {
+                continue;
+            };
+            if file.image_id.is_some() {
+                continue;
+            }
+            let results = match batch {
+                Some(batch) if batch == *sent_batch => uploads.first(),
+                Some(_) => None,
+                None => uploads.get(*sent_batch),
+            };
+            if let Some(result) = results.and_then(|results| results.get(field)) {
+                file.image_id = Some(result.image_id);
+            }
+        }
+    }
+}
@@ -25,6 +25,30 @@ pub struct UserFull {
     #[serde(default)]
     pub tiny_photo_url: Option<String>,
 
+    /// First name, if the user shares it.
+    #[serde(default)]
+    pub first_name: Option<String>,
+
+    /// Last name, if the user shares it.
+    #[serde(default)]
+    pub last_name: Option<String>,
+
+    /// Location as entered on the profile.
+    #[serde(default)]
+    pub location: Option<String>,
+
+    /// The "about me" profile text, rendered as HTML.
+    #[serde(default)]
+    pub about_me_html: Option<String>,
+
+    /// Number of patterns the user has designed.
+    #[serde(default)]
+    pub pattern_count: Option<u64>,
+
+    /// Number of favorites the user has saved.
+    #[serde(default)]
+    pub favorites_count: Option<u64>,
+
     /// Capture any additional fields not explicitly defined.
     #[serde(flatten)]
     pub extra: ExtraFields,
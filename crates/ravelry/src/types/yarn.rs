@@ -2,8 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::common::ExtraFields;
+use super::common::{ExtraFields, Money};
 use super::photo::PhotoSmall;
+use super::reference::YarnWeight;
+use super::shop::Shop;
 
 /// Yarn information returned in search results and lists.
 ///
@@ -89,6 +91,95 @@ pub struct YarnFull {
     #[serde(default)]
     pub discontinued: Option<bool>,
 
+    /// Where the yarn can be bought.
+    ///
+    /// Only present when requested with `include=availability`.
+    #[serde(default)]
+    pub availability: Option<Vec<YarnAvailability>>,
+
+    /// The yarn's colorways.
+    ///
+    /// Only present when requested with `include=colorways`.
+    #[serde(default)]
+    pub colorways: Option<Vec<Colorway>>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl YarnFull {
+    /// Returns the yarn weight as a typed value.
+    ///
+    /// Weights Ravelry adds later come back as [`YarnWeight::Other`].
+    pub fn weight(&self) -> Option<YarnWeight> {
+        self.yarn_weight_name.as_deref().map(YarnWeight::from)
+    }
+}
+
+/// A shop listing where a yarn can be bought.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct YarnAvailability {
+    /// Name of the shop.
+    #[serde(default)]
+    pub shop_name: Option<String>,
+
+    /// Link to the yarn in the shop.
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// Whether the shop currently has the yarn in stock.
+    #[serde(default)]
+    pub in_stock: Option<bool>,
+
+    /// The shop's price for the yarn.
+    #[serde(default)]
+    pub price: Option<Money>,
+
+    /// The shop's details, when Ravelry includes them.
+    #[serde(default)]
+    pub shop: Option<Shop>,
+
+    /// Capture any additional fields.
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl YarnAvailability {
+    /// Returns the shop behind this listing.
+    ///
+    /// Uses the nested shop record when present, otherwise builds one from
+    /// `shop_name` and `url`. Returns `None` if the listing names no shop.
+    pub fn to_shop(&self) -> Option<Shop> {
+        if let Some(shop) = &self.shop {
+            return Some(shop.clone());
+        }
+        let name = self.shop_name.clone()?;
+        Some(Shop {
+            id: None,
+            name: Some(name),
+            permalink: None,
+            url: self.url.clone(),
+            location: None,
+            extra: ExtraFields::default(),
+        })
+    }
+}
+
+/// A colorway (named color) of a yarn.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Colorway {
+    /// Unique colorway ID.
+    pub id: u64,
+
+    /// Colorway name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Photos of this colorway.
+    #[serde(default)]
+    pub photos: Option<Vec<PhotoSmall>>,
+
     /// Capture any additional fields.
     #[serde(flatten)]
     pub extra: ExtraFields,
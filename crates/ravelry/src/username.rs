@@ -0,0 +1,80 @@
+//! Username normalization.
+
+use crate::error::RavelryError;
+
+/// Normalize a username before using it in a request path.
+///
+/// Surrounding whitespace is trimmed and the result is lowercased; Ravelry
+/// matches usernames case-insensitively in URLs. Usernames may contain only
+/// ASCII letters, digits, `_` and `-`. Numeric user IDs pass unchanged, so
+/// this is also safe for endpoints that accept either.
+///
+/// # Errors
+///
+/// Returns [`RavelryError::InvalidRequest`] if the username is empty or
+/// contains any other character, rather than sending a request that can only
+/// 404.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(ravelry::normalize_username("  KnitWit ").unwrap(), "knitwit");
+/// assert!(ravelry::normalize_username("knit/wit").is_err());
+/// ```
+pub fn normalize_username(username: &str) -> Result<String, RavelryError> {
+    let trimmed = username.trim();
+    if trimmed.is_empty() {
+        return Err(RavelryError::InvalidRequest(
+            "username must not be empty".to_string(),
+        ));
+    }
+    if let Some(c) = trimmed
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '_' || *c == '-'))
+    {
+        return Err(RavelryError::InvalidRequest(format!(
+            "invalid character {:?} in username {:?}",
+            c, trimmed
+        )));
+    }
+    Ok(trimmed.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_usernames() {
+        assert_eq!(normalize_username("knitwit").unwrap(), "knitwit");
+        assert_eq!(normalize_username("Purl_Soho-2").unwrap(), "purl_soho-2");
+        assert_eq!(normalize_username("12345").unwrap(), "12345");
+    }
+
+    #[test]
+    fn test_whitespace_is_trimmed() {
+        assert_eq!(normalize_username("  alice\n").unwrap(), "alice");
+        assert_eq!(normalize_username("\tBob ").unwrap(), "bob");
+    }
+
+    #[test]
+    fn test_invalid_usernames() {
+        for username in [
+            "",
+            "   ",
+            "a b",
+            "alice/../bob",
+            "bob.json",
+            "café",
+            "a?b=1",
+        ] {
+            assert!(
+                matches!(
+                    normalize_username(username),
+                    Err(RavelryError::InvalidRequest(_))
+                ),
+                "{username:?}"
+            );
+        }
+    }
+}
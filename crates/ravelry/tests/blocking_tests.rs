@@ -0,0 +1,70 @@
+//! Integration tests for the blocking client.
+#![cfg(feature = "blocking")]
+
+use ravelry::api::patterns::PatternSearchParams;
+use ravelry::api::projects::ProjectsListParams;
+use ravelry::auth::BasicAuth;
+use ravelry::blocking::RavelryClient;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Start a mock server on its own runtime, outside the test thread.
+fn start_server(runtime: &tokio::runtime::Runtime) -> MockServer {
+    runtime.block_on(async {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/current_user.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "user": { "id": 1, "username": "knitter" }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/patterns/search.json"))
+            .and(query_param("query", "socks"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "patterns": [{ "id": 7, "name": "Vanilla Socks", "permalink": "vanilla-socks" }],
+                "paginator": { "page_count": 1, "page": 1, "page_size": 50, "results": 1, "last_page": 1 }
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/projects/knitter/list.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "projects": [{ "id": 3, "name": "Hat", "permalink": "hat" }],
+                "paginator": { "page_count": 1, "page": 1, "page_size": 50, "results": 1, "last_page": 1 }
+            })))
+            .mount(&server)
+            .await;
+
+        server
+    })
+}
+
+#[test]
+fn test_blocking_client_calls_endpoints() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let server = start_server(&runtime);
+
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .build_blocking()
+        .unwrap();
+
+    let user = client.root().current_user().unwrap().user;
+    assert_eq!(user.username, "knitter");
+
+    let params = PatternSearchParams::new().query("socks");
+    let patterns = client.patterns().search(&params).unwrap().patterns;
+    assert_eq!(patterns[0].name, "Vanilla Socks");
+
+    let projects = client
+        .projects()
+        .list("knitter", &ProjectsListParams::new())
+        .unwrap()
+        .projects;
+    assert_eq!(projects[0].name, "Hat");
+}
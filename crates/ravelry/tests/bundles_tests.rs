@@ -0,0 +1,200 @@
+//! Integration tests for the bundles API.
+
+mod common;
+
+use ravelry::api::bundles::BundledItemsParams;
+use ravelry::types::{BundledItemPost, FavoritedItem};
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_export_resolves_bundle_items() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/bundles/9.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bundle": { "id": 9, "name": "Gift ideas", "bundled_items_count": 3 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/bundles/9/bundled_items.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bundled_items": [
+                {
+                    "id": 100,
+                    "bundle_id": 9,
+                    "item_type": "pattern",
+                    "item": { "id": 1, "name": "Cabled Hat", "permalink": "cabled-hat" }
+                },
+                { "id": 101, "bundle_id": 9, "item_type": "yarn" },
+                {
+                    "id": 102,
+                    "bundle_id": 9,
+                    "item_type": "shop",
+                    "item": { "id": 5, "name": "Local Yarn Store" }
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // The yarn isn't embedded in the list, so it's resolved individually
+    Mock::given(method("GET"))
+        .and(path("/bundled_items/101.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bundled_item": { "id": 101, "bundle_id": 9, "item_type": "yarn" },
+            "item": { "id": 2, "name": "Merino Worsted", "permalink": "merino-worsted" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let export = client.bundles().export("testuser", 9).await.unwrap();
+
+    assert_eq!(export.bundle.name.as_deref(), Some("Gift ideas"));
+    assert_eq!(export.items.len(), 3);
+    assert!(matches!(&export.items[0], FavoritedItem::Pattern(p) if p.name == "Cabled Hat"));
+    assert!(matches!(&export.items[1], FavoritedItem::Yarn(y) if y.id == 2));
+    assert!(matches!(&export.items[2], FavoritedItem::Other(v) if v["id"] == 5));
+}
+
+#[tokio::test]
+async fn test_export_fetches_every_page_of_items() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/bundles/9.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bundle": { "id": 9, "name": "Gift ideas", "bundled_items_count": 2 }
+        })))
+        .mount(&server)
+        .await;
+
+    for page in [1, 2] {
+        Mock::given(method("GET"))
+            .and(path("/people/testuser/bundles/9/bundled_items.json"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "bundled_items": [{
+                    "id": 100 + page,
+                    "bundle_id": 9,
+                    "item_type": "pattern",
+                    "item": { "id": page, "name": format!("Pattern {page}"), "permalink": "p" }
+                }],
+                "paginator": { "page": page, "page_count": 2, "page_size": 1, "results": 2, "last_page": 2 }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = common::test_client(&server);
+    let export = client.bundles().export("testuser", 9).await.unwrap();
+
+    assert_eq!(export.items.len(), 2);
+    assert!(matches!(&export.items[0], FavoritedItem::Pattern(p) if p.name == "Pattern 1"));
+    assert!(matches!(&export.items[1], FavoritedItem::Pattern(p) if p.name == "Pattern 2"));
+}
+
+#[tokio::test]
+async fn test_export_keeps_unresolved_items() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/bundles/9.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bundle": { "id": 9, "name": "Gift ideas", "bundled_items_count": 1 }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/bundles/9/bundled_items.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bundled_items": [{ "id": 101, "bundle_id": 9, "item_type": "yarn" }]
+        })))
+        .mount(&server)
+        .await;
+
+    // Neither the list nor the show response embeds the yarn
+    Mock::given(method("GET"))
+        .and(path("/bundled_items/101.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bundled_item": { "id": 101, "bundle_id": 9, "item_type": "yarn" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let export = client.bundles().export("testuser", 9).await.unwrap();
+
+    assert_eq!(export.items.len(), 1);
+    assert!(matches!(
+        &export.items[0],
+        FavoritedItem::Other(v) if v["bundled_item_id"] == 101 && v["item_type"] == "yarn"
+    ));
+}
+
+#[tokio::test]
+async fn test_items_paginates() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/bundles/9/bundled_items.json"))
+        .and(query_param("page", "2"))
+        .and(query_param("page_size", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bundled_items": [
+                { "id": 101, "bundle_id": 9, "favorite_id": 55, "sort_order": 2 }
+            ],
+            "paginator": { "page": 2, "page_count": 3, "page_size": 1, "results": 3, "last_page": 3 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = BundledItemsParams::new().page(2).page_size(1);
+    let response = client
+        .bundles()
+        .items("testuser", 9, &params)
+        .await
+        .unwrap();
+
+    assert_eq!(response.bundled_items.len(), 1);
+    assert_eq!(response.bundled_items[0].favorite_id, Some(55));
+    let paginator = response.paginator.unwrap();
+    assert_eq!(paginator.page, 2);
+    assert_eq!(paginator.page_count, 3);
+}
+
+#[tokio::test]
+async fn test_create_bundled_item() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/bundled_items/create.json"))
+        .and(body_json(serde_json::json!({
+            "data": { "bundle_id": 9, "favorite_id": 55, "sort_order": 3 }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "bundled_item": { "id": 200, "bundle_id": 9, "favorite_id": 55, "sort_order": 3 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let item = BundledItemPost::new(9, 55).sort_order(3);
+    let response = client.bundled_items().create(&item).await.unwrap();
+
+    assert_eq!(response.bundled_item.id, 200);
+    assert_eq!(response.bundled_item.sort_order, Some(3));
+}
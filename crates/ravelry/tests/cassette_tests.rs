@@ -0,0 +1,66 @@
+//! Integration tests for cassette record/replay (`test-util` feature).
+
+#![cfg(feature = "test-util")]
+
+use ravelry::auth::BasicAuth;
+use ravelry::cassette::{Cassette, CassetteMode};
+use ravelry::RavelryClient;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_record_then_replay_current_user() {
+    let cassette_path = std::env::temp_dir().join(format!(
+        "ravelry-cassette-{}-current-user.json",
+        std::process::id()
+    ));
+
+    // Record against a live mock server.
+    {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/current_user.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "user": { "id": 7, "username": "knitter" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let cassette = Cassette::record(&cassette_path);
+        let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+            .base_url(server.uri().parse().unwrap())
+            .cassette(cassette.clone())
+            .build()
+            .unwrap();
+
+        let response = client.root().current_user().await.unwrap();
+        assert_eq!(response.user.username, "knitter");
+
+        let recorded = cassette.interactions();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].method, "GET");
+        assert_eq!(recorded[0].path, "/current_user.json");
+        cassette.save().unwrap();
+    }
+
+    // Replay with the server gone, against an unreachable base URL.
+    let cassette = Cassette::replay(&cassette_path).unwrap();
+    assert_eq!(cassette.mode(), CassetteMode::Replay);
+    let client = RavelryClient::builder(BasicAuth::new("", ""))
+        .base_url_str("http://127.0.0.1:9/")
+        .unwrap()
+        .cassette(cassette)
+        .build()
+        .unwrap();
+
+    let response = client.root().current_user().await.unwrap();
+    assert_eq!(response.user.id, 7);
+    assert_eq!(response.user.username, "knitter");
+
+    // Each recording is served once.
+    let err = client.root().current_user().await.unwrap_err();
+    assert!(err.to_string().contains("no recorded interaction"));
+
+    std::fs::remove_file(&cassette_path).unwrap();
+}
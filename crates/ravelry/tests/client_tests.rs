@@ -0,0 +1,152 @@
+//! Integration tests for client builder settings.
+
+use std::time::Duration;
+
+use ravelry::auth::BasicAuth;
+use ravelry::retry::RetryPolicy;
+use ravelry::{RavelryClient, RavelryError};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_request_timeout() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_delay(Duration::from_millis(500))
+                .set_body_json(serde_json::json!({
+                    "user": { "id": 1, "username": "slow" }
+                })),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .timeout(Duration::from_millis(50))
+        .retry(
+            RetryPolicy::new()
+                .max_attempts(2)
+                .base_delay(Duration::from_millis(1)),
+        )
+        .build()
+        .unwrap();
+
+    let err = client.root().current_user().await.unwrap_err();
+    assert!(matches!(&err, RavelryError::Http(e) if e.is_timeout()));
+    assert!(err.is_retryable());
+    assert_eq!(client.last_attempts(), 2);
+}
+
+#[tokio::test]
+async fn test_requests_go_through_proxy() {
+    let proxy = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 1, "username": "proxied" }
+        })))
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    // The API host doesn't resolve, so only the proxy can answer.
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url_str("http://api.ravelry.invalid/")
+        .unwrap()
+        .proxy_url(&proxy.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let user = client.root().current_user().await.unwrap().user;
+    assert_eq!(user.username, "proxied");
+}
+
+#[test]
+fn test_invalid_proxy_url() {
+    let result =
+        RavelryClient::builder(BasicAuth::new("test_user", "test_key")).proxy_url("not a url");
+    assert!(matches!(result, Err(RavelryError::Url(_))));
+}
+
+#[cfg(all(feature = "gzip", feature = "brotli"))]
+#[tokio::test]
+async fn test_compressed_responses_accepted_by_default() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 1, "username": "knitter" }
+        })))
+        .mount(&server)
+        .await;
+
+    let accept_encoding = |request: &wiremock::Request| {
+        request
+            .headers
+            .get("accept-encoding")
+            .map(|v| v.to_str().unwrap().to_string())
+            .unwrap_or_default()
+    };
+
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .build()
+        .unwrap();
+    client.root().current_user().await.unwrap();
+
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .gzip(false)
+        .brotli(false)
+        .build()
+        .unwrap();
+    client.root().current_user().await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let default = accept_encoding(&requests[0]);
+    assert!(default.contains("gzip"), "{default}");
+    assert!(default.contains("br"), "{default}");
+    assert_eq!(accept_encoding(&requests[1]), "");
+}
+
+#[tokio::test]
+async fn test_user_agent_prefixes_crate_agent() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .and(header(
+            "user-agent",
+            concat!("myapp/1.0 (ravelry/", env!("CARGO_PKG_VERSION"), ")"),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 1, "username": "knitter" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .user_agent("myapp/1.0")
+        .build()
+        .unwrap();
+    client.root().current_user().await.unwrap();
+}
+
+#[test]
+fn test_invalid_user_agent() {
+    let err = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .user_agent("bad\nagent")
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, RavelryError::InvalidRequest(_)));
+}
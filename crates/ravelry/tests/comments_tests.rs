@@ -0,0 +1,164 @@
+//! Integration tests for the people comments and comments APIs.
+
+mod common;
+
+use ravelry::api::comments::CommentsListParams;
+use ravelry::api::people::UserCommentsParams;
+use ravelry::types::{CommentParent, CommentPost};
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_list_user_comments() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/comments/list.json"))
+        .and(query_param("page_size", "25"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "comments": [
+                {
+                    "id": 901,
+                    "body": "Gorgeous colorwork!",
+                    "created_at": "2024/01/15 10:30:00 -0500",
+                    "commentable_type": "project",
+                    "commentable_id": 42,
+                    "user": { "id": 1, "username": "testuser" }
+                }
+            ],
+            "paginator": {
+                "page": 1,
+                "page_count": 1,
+                "page_size": 25,
+                "results": 1,
+                "last_page": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = UserCommentsParams::new().page_size(25);
+    let response = client.people().comments("testuser", &params).await.unwrap();
+
+    assert_eq!(response.comments.len(), 1);
+    let comment = &response.comments[0];
+    assert_eq!(comment.id, 901);
+    assert_eq!(comment.body.as_deref(), Some("Gorgeous colorwork!"));
+    assert_eq!(comment.commentable_type.as_deref(), Some("project"));
+    assert_eq!(comment.commentable_id, Some(42));
+    assert_eq!(response.paginator.results, 1);
+}
+
+#[tokio::test]
+async fn test_delete_comment() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/comments/901.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "comment": { "id": 901, "body": "Gorgeous colorwork!" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let response = client.comments().delete(901).await.unwrap();
+    assert_eq!(response.comment.id, 901);
+}
+
+#[tokio::test]
+async fn test_list_item_comments() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/comments/project/42/list.json"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "comments": [
+                {
+                    "id": 902,
+                    "body": "What yarn is this?",
+                    "created_at": "2024/01/16 09:00:00 -0500",
+                    "highlighted": true,
+                    "user": { "id": 2, "username": "curious" }
+                }
+            ],
+            "paginator": {
+                "page": 2,
+                "page_count": 2,
+                "page_size": 25,
+                "results": 26,
+                "last_page": 2
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = CommentsListParams::new().page(2);
+    let response = client
+        .comments()
+        .list(CommentParent::Project, 42, &params)
+        .await
+        .unwrap();
+
+    let comment = &response.comments[0];
+    assert_eq!(comment.id, 902);
+    assert_eq!(comment.highlighted, Some(true));
+    assert_eq!(comment.user.as_ref().unwrap().username, "curious");
+}
+
+#[tokio::test]
+async fn test_create_and_update_comment() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/comments/stash/7/create.json"))
+        .and(body_json(serde_json::json!({
+            "data": { "body": "Such a nice colorway" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "comment": { "id": 903, "body": "Such a nice colorway" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/comments/903.json"))
+        .and(body_json(serde_json::json!({
+            "data": { "body": "Such a nice colourway" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "comment": { "id": 903, "body": "Such a nice colourway" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let created = client
+        .comments()
+        .create(
+            CommentParent::Stash,
+            7,
+            &CommentPost::new().body("Such a nice colorway"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(created.comment.id, 903);
+
+    let updated = client
+        .comments()
+        .update(903, &CommentPost::new().body("Such a nice colourway"))
+        .await
+        .unwrap();
+    assert_eq!(
+        updated.comment.body.as_deref(),
+        Some("Such a nice colourway")
+    );
+}
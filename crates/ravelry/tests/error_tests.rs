@@ -2,10 +2,33 @@
 
 mod common;
 
-use ravelry::RavelryError;
+use ravelry::auth::{AuthKind, Authenticator, BasicAuth};
+use ravelry::{RavelryClient, RavelryError};
+use reqwest::{RequestBuilder, StatusCode};
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
+#[tokio::test]
+async fn test_retry_after_http_date() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(
+            ResponseTemplate::new(503)
+                .insert_header("retry-after", "Sun, 06 Nov 1994 08:49:37 GMT"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let err = client.root().current_user().await.unwrap_err();
+
+    // A date in the past allows an immediate retry
+    assert_eq!(err.retry_after(), Some(std::time::Duration::ZERO));
+}
+
 #[tokio::test]
 async fn test_rate_limited_error() {
     let server = MockServer::start().await;
@@ -37,6 +60,52 @@ async fn test_rate_limited_error() {
     }
 }
 
+#[tokio::test]
+async fn test_service_unavailable_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(
+            ResponseTemplate::new(503)
+                .insert_header("retry-after", "120")
+                .set_body_string("Down for maintenance"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let err = client.root().current_user().await.unwrap_err();
+
+    assert!(err.is_retryable());
+    assert_eq!(err.retry_after(), Some(std::time::Duration::from_secs(120)));
+    match err {
+        RavelryError::ServiceUnavailable { retry_after } => {
+            assert_eq!(retry_after, Some(std::time::Duration::from_secs(120)));
+        }
+        other => panic!("Expected ServiceUnavailable, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_internal_server_error_is_not_service_unavailable() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(500).insert_header("retry-after", "120"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let err = client.root().current_user().await.unwrap_err();
+
+    assert!(!err.is_retryable());
+    assert!(matches!(err, RavelryError::ApiStatus { status, .. } if status.as_u16() == 500));
+}
+
 #[tokio::test]
 async fn test_not_modified_error() {
     let server = MockServer::start().await;
@@ -81,6 +150,9 @@ async fn test_api_error_response() {
     assert!(result.is_err());
     let err = result.unwrap_err();
 
+    assert_eq!(err.status_code(), Some(StatusCode::NOT_FOUND));
+    assert_eq!(err.api_message(), Some("Pattern not found"));
+
     match err {
         RavelryError::ApiStatus { status, body } => {
             assert_eq!(status.as_u16(), 404);
@@ -90,6 +162,79 @@ async fn test_api_error_response() {
     }
 }
 
+#[tokio::test]
+async fn test_api_error_with_non_json_body() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/1.json"))
+        .respond_with(ResponseTemplate::new(502).set_body_string("<html>Bad Gateway</html>"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let err = client.patterns().show(1).await.unwrap_err();
+
+    assert_eq!(err.status_code(), Some(StatusCode::BAD_GATEWAY));
+    assert_eq!(err.api_message(), None);
+    match err {
+        RavelryError::ApiStatus { body, .. } => {
+            assert_eq!(body["raw"], "<html>Bad Gateway</html>");
+        }
+        other => panic!("Expected ApiStatus, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_unauthorized_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+            "error": "Invalid credentials"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let err = client.root().current_user().await.unwrap_err();
+
+    assert_eq!(err.status_code(), Some(StatusCode::UNAUTHORIZED));
+    assert_eq!(err.api_message(), Some("Invalid credentials"));
+    assert!(!err.is_retryable());
+    assert!(
+        matches!(err, RavelryError::Unauthorized { .. }),
+        "got {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_forbidden_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/5.json"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "error": "Not allowed"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let err = client.patterns().show(5).await.unwrap_err();
+
+    assert_eq!(err.status_code(), Some(StatusCode::FORBIDDEN));
+    assert!(!err.is_retryable());
+    match err {
+        RavelryError::Forbidden { body } => assert_eq!(body["error"], "Not allowed"),
+        other => panic!("Expected Forbidden, got {:?}", other),
+    }
+}
+
 #[tokio::test]
 async fn test_error_is_retryable() {
     let rate_limited = RavelryError::RateLimited {
@@ -105,3 +250,130 @@ async fn test_error_is_retryable() {
     let not_modified = RavelryError::NotModified { etag: None };
     assert!(!not_modified.is_retryable());
 }
+
+/// An authenticator whose credentials are never usable.
+struct BrokenAuth;
+
+impl Authenticator for BrokenAuth {
+    fn apply(&self, _req: RequestBuilder) -> Result<RequestBuilder, RavelryError> {
+        Err(RavelryError::Auth("credentials unavailable".to_string()))
+    }
+
+    fn kind(&self) -> AuthKind {
+        AuthKind::Basic
+    }
+}
+
+#[tokio::test]
+async fn test_authenticator_error_fails_before_sending() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = RavelryClient::builder(BrokenAuth)
+        .base_url(server.uri().parse().unwrap())
+        .build()
+        .unwrap();
+
+    let err = client.root().current_user().await.unwrap_err();
+    assert!(matches!(err, RavelryError::Auth(ref msg) if msg == "credentials unavailable"));
+}
+
+#[tokio::test]
+async fn test_basic_auth_rejects_colon_in_access_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = RavelryClient::builder(BasicAuth::new("bad:key", "secret"))
+        .base_url(server.uri().parse().unwrap())
+        .build()
+        .unwrap();
+
+    let err = client.root().current_user().await.unwrap_err();
+    assert!(matches!(err, RavelryError::Auth(_)));
+}
+
+#[tokio::test]
+async fn test_transport_error_redacts_credential_query_params() {
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url_str("http://127.0.0.1:9/")
+        .unwrap()
+        .interceptor(|req: RequestBuilder| req.query(&[("access_key", "secret"), ("page", "2")]))
+        .build()
+        .unwrap();
+
+    let err = client.root().current_user().await.unwrap_err();
+    assert!(matches!(err, RavelryError::Http(_)));
+    for rendered in [err.to_string(), format!("{err:?}")] {
+        assert!(!rendered.contains("secret"), "{rendered}");
+    }
+    assert!(
+        err.to_string().contains("access_key=[REDACTED]&page=2"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn test_api_error_body_redacts_credentials() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+            "error": "Bad request to /current_user.json?access_key=secret&page=2",
+            "params": { "access_token": "tok123", "page": "2" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/1.json"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("<p>client_secret=shh failed</p>"))
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+
+    let err = client.root().current_user().await.unwrap_err();
+    let rendered = err.to_string();
+    assert!(!rendered.contains("=secret"), "{rendered}");
+    assert!(!rendered.contains("tok123"), "{rendered}");
+    assert!(
+        rendered.contains("access_key=[REDACTED]&page=2"),
+        "{rendered}"
+    );
+
+    let err = client.patterns().show(1).await.unwrap_err();
+    let rendered = format!("{err:?}");
+    assert!(!rendered.contains("shh"), "{rendered}");
+}
+
+#[tokio::test]
+async fn test_api_error_body_keeps_code_field() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+            "error": "Redirected from /oauth2/callback?code=abc123&state=xyz",
+            "code": "invalid_parameter"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let err = client.root().current_user().await.unwrap_err();
+    let rendered = err.to_string();
+    assert!(rendered.contains("invalid_parameter"), "{rendered}");
+    assert!(rendered.contains("code=[REDACTED]&state=xyz"), "{rendered}");
+    assert!(!rendered.contains("abc123"), "{rendered}");
+}
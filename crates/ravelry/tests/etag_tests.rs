@@ -0,0 +1,349 @@
+//! Integration tests for user-supplied ETag stores.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use ravelry::auth::{BasicAuth, OAuth2Auth};
+use ravelry::etag::{EtagCache, EtagStore};
+use ravelry::request_options::RequestOptions;
+use ravelry::{Conditional, RavelryClient, RavelryError};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+/// Stored ETag and body, keyed by request path.
+type Entries = HashMap<String, (String, Vec<u8>)>;
+
+/// An in-memory store whose contents the test can inspect.
+#[derive(Clone, Default)]
+struct MemoryStore(Arc<Mutex<Entries>>);
+
+impl EtagStore for MemoryStore {
+    fn get(&self, path: &str) -> Option<String> {
+        self.0
+            .lock()
+            .unwrap()
+            .get(path)
+            .map(|(etag, _)| etag.clone())
+    }
+
+    fn put(&self, path: &str, etag: &str, body: &[u8]) {
+        let entry = (etag.to_string(), body.to_vec());
+        self.0.lock().unwrap().insert(path.to_string(), entry);
+    }
+
+    fn body(&self, path: &str) -> Option<Vec<u8>> {
+        self.0
+            .lock()
+            .unwrap()
+            .get(path)
+            .map(|(_, body)| body.clone())
+    }
+}
+
+#[tokio::test]
+async fn test_etag_store_serves_not_modified_from_cache() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/1.json"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304).insert_header("ETag", "\"v1\""))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/1.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_json(serde_json::json!({
+                    "pattern": { "id": 1, "name": "Cowl", "permalink": "cowl" }
+                })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let store = MemoryStore::default();
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .etag_store(store.clone())
+        .build()
+        .unwrap();
+
+    let first = client.patterns().show(1).await.unwrap();
+    assert_eq!(first.pattern.name, "Cowl");
+    assert_eq!(store.get("/patterns/1.json").as_deref(), Some("\"v1\""));
+
+    // The second request is conditional; the 304 is answered from the store.
+    let second = client.patterns().show(1).await.unwrap();
+    assert_eq!(second.pattern.name, "Cowl");
+}
+
+#[tokio::test]
+async fn test_etag_cache_serves_repeated_shows() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/1.json"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304).insert_header("ETag", "\"v1\""))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/1.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_json(serde_json::json!({
+                    "pattern": { "id": 1, "name": "Cowl", "permalink": "cowl" }
+                })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cache = EtagCache::new();
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .etag_cache(cache.clone())
+        .build()
+        .unwrap();
+
+    for _ in 0..3 {
+        let response = client.patterns().show(1).await.unwrap();
+        assert_eq!(response.pattern.name, "Cowl");
+    }
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.get("/patterns/1.json").as_deref(), Some("\"v1\""));
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[tokio::test]
+async fn test_show_conditional_returns_etag_and_not_modified() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/7.json"))
+        .and(header("if-none-match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304).insert_header("etag", "\"v1\""))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/7.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"v1\"")
+                .set_body_json(serde_json::json!({
+                    "pattern": { "id": 7, "name": "Hat", "permalink": "hat" }
+                })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Without a store, so the conditional flow is driven by the caller alone.
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .build()
+        .unwrap();
+
+    let first = client.patterns().show_conditional(7, None).await.unwrap();
+    assert!(first.is_modified());
+    let etag = first.etag().map(String::from);
+    assert_eq!(etag.as_deref(), Some("\"v1\""));
+    assert_eq!(first.into_body().unwrap().pattern.name, "Hat");
+
+    let second = client
+        .patterns()
+        .show_conditional(7, etag.as_deref())
+        .await
+        .unwrap();
+    assert!(matches!(
+        second,
+        Conditional::NotModified { etag: Some(ref e) } if e == "\"v1\""
+    ));
+}
+
+#[tokio::test]
+async fn test_show_or_previous_returns_previous_on_304() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/7.json"))
+        .and(header("if-none-match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304).insert_header("etag", "\"v1\""))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let previous: ravelry::api::patterns::PatternShowResponse =
+        serde_json::from_value(serde_json::json!({
+            "pattern": { "id": 7, "name": "Hat", "permalink": "hat" }
+        }))
+        .unwrap();
+
+    let options = RequestOptions::new().if_none_match("\"v1\"");
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .request_options(options.clone().return_cached_on_304(true))
+        .build()
+        .unwrap();
+
+    let response = client
+        .patterns()
+        .show_or_previous(7, Some(previous.clone()))
+        .await
+        .unwrap();
+    assert_eq!(response.pattern.name, "Hat");
+
+    // Without the flag the 304 is still an error.
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .request_options(options)
+        .build()
+        .unwrap();
+
+    let err = client
+        .patterns()
+        .show_or_previous(7, Some(previous))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RavelryError::NotModified { .. }));
+}
+
+/// Matches an exact `If-Modified-Since` value. wiremock's `header` matcher
+/// splits values on commas, which HTTP-dates contain.
+fn if_modified_since(expected: &'static str) -> impl Fn(&Request) -> bool {
+    move |req: &Request| {
+        req.headers
+            .get("If-Modified-Since")
+            .is_some_and(|value| value == expected)
+    }
+}
+
+#[tokio::test]
+async fn test_if_modified_since_maps_304_to_not_modified() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/8.json"))
+        .and(if_modified_since("Tue, 15 Oct 2024 09:30:00 GMT"))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let options = RequestOptions::new()
+        .if_modified_since(time::macros::datetime!(2024-10-15 11:30:00 +02:00));
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .request_options(options)
+        .build()
+        .unwrap();
+
+    let err = client.patterns().show(8).await.unwrap_err();
+    assert!(matches!(err, RavelryError::NotModified { etag: None }));
+}
+
+#[tokio::test]
+async fn test_etag_cache_revalidates_by_last_modified() {
+    let server = MockServer::start().await;
+    let last_modified = "Tue, 15 Oct 2024 09:30:00 GMT";
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/9.json"))
+        .and(if_modified_since(last_modified))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/9.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Last-Modified", last_modified)
+                .set_body_json(serde_json::json!({
+                    "pattern": { "id": 9, "name": "Mitts", "permalink": "mitts" }
+                })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cache = EtagCache::new();
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .etag_cache(cache.clone())
+        .build()
+        .unwrap();
+
+    for _ in 0..2 {
+        let response = client.patterns().show(9).await.unwrap();
+        assert_eq!(response.pattern.name, "Mitts");
+    }
+    assert!(cache.get("/patterns/9.json").is_none());
+    assert_eq!(
+        cache.last_modified("/patterns/9.json").as_deref(),
+        Some(last_modified)
+    );
+}
+
+#[tokio::test]
+async fn test_with_auth_does_not_share_etag_cache() {
+    let server = MockServer::start().await;
+    let last_modified = "Tue, 15 Oct 2024 09:30:00 GMT";
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .and(if_modified_since(last_modified))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .and(header("Authorization", "Bearer other_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 2, "username": "other" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Last-Modified", last_modified)
+                .set_body_json(serde_json::json!({
+                    "user": { "id": 1, "username": "first" }
+                })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .etag_cache(EtagCache::new())
+        .build()
+        .unwrap();
+    client.root().current_user().await.unwrap();
+
+    let other = client.with_auth(OAuth2Auth::new("other_token"));
+    let user = other.root().current_user().await.unwrap().user;
+    assert_eq!(user.username, "other");
+}
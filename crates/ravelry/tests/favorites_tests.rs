@@ -3,8 +3,8 @@
 mod common;
 
 use ravelry::api::favorites::FavoritesListParams;
-use ravelry::types::BookmarkPost;
-use wiremock::matchers::{method, path};
+use ravelry::types::{BookmarkPost, FavoriteType, FavoritedItem};
+use wiremock::matchers::{body_partial_json, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -43,6 +43,77 @@ async fn test_list_favorites() {
     assert_eq!(response.favorites[0].type_name, Some("pattern".to_string()));
 }
 
+#[tokio::test]
+async fn test_list_favorites_filtered_by_type() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/favorites/list.json"))
+        .and(query_param("type", "yarnshop"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "favorites": [
+                { "id": 1, "type": "yarnshop", "favorited_id": 9 },
+                { "id": 2, "type": "spinning-wheel", "favorited_id": 10 }
+            ],
+            "paginator": {
+                "page": 1,
+                "page_count": 1,
+                "page_size": 10,
+                "results": 2,
+                "last_page": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = FavoritesListParams::new().type_filter_enum(FavoriteType::Shop);
+    let response = client.favorites().list("testuser", &params).await.unwrap();
+
+    assert_eq!(
+        response.favorites[0].favorite_type(),
+        Some(FavoriteType::Shop)
+    );
+    assert_eq!(
+        response.favorites[1].favorite_type(),
+        Some(FavoriteType::Other("spinning-wheel".to_string()))
+    );
+}
+
+#[tokio::test]
+async fn test_show_favorite_typed_item() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/favorites/3.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "favorite": {
+                "id": 3,
+                "type": "pattern",
+                "favorited_id": 42,
+                "favorited": { "id": 42, "name": "Hitchhiker", "permalink": "hitchhiker" }
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let favorite = client
+        .favorites()
+        .show("testuser", 3)
+        .await
+        .unwrap()
+        .favorite;
+
+    match favorite.favorited_item() {
+        Some(FavoritedItem::Pattern(pattern)) => assert_eq!(pattern.name, "Hitchhiker"),
+        other => panic!("Expected a pattern, got {other:?}"),
+    }
+    assert!(favorite.favorited.is_some());
+}
+
 #[tokio::test]
 async fn test_create_favorite() {
     let server = MockServer::start().await;
@@ -94,3 +165,56 @@ async fn test_add_favorite_to_bundle() {
         .unwrap();
     assert_eq!(response.favorite.id, 100);
 }
+
+#[tokio::test]
+async fn test_create_many_reports_partial_failure() {
+    let server = MockServer::start().await;
+
+    for id in [1, 3] {
+        Mock::given(method("POST"))
+            .and(path("/people/testuser/favorites/create.json"))
+            .and(body_partial_json(serde_json::json!({
+                "data": { "favorited_id": id }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "favorite": { "id": 100 + id, "type": "pattern", "favorited_id": id }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("POST"))
+        .and(path("/people/testuser/favorites/create.json"))
+        .and(body_partial_json(serde_json::json!({
+            "data": { "favorited_id": 2 }
+        })))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "error": "Pattern not found"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let bookmarks: Vec<BookmarkPost> = [1, 2, 3]
+        .into_iter()
+        .map(|id| BookmarkPost::new().type_name("pattern").favorited_id(id))
+        .collect();
+
+    let result = client
+        .favorites()
+        .create_many("testuser", &bookmarks)
+        .await
+        .unwrap();
+
+    assert!(!result.is_complete());
+    let created: Vec<(usize, u64)> = result
+        .succeeded
+        .iter()
+        .map(|(index, favorite)| (*index, favorite.id))
+        .collect();
+    assert_eq!(created, vec![(0, 101), (2, 103)]);
+    assert_eq!(result.failed.len(), 1);
+    assert_eq!(result.failed[0].0, 1);
+}
@@ -0,0 +1,133 @@
+//! Integration tests for the forum posts and topics APIs.
+
+mod common;
+
+use ravelry::api::forums::ForumPageParams;
+use ravelry::types::PostPost;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_show_post() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/forum_posts/555.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "post": {
+                "id": 555,
+                "topic_id": 42,
+                "post_number": 3,
+                "body": "Try a **tubular** cast-on.",
+                "body_html": "<p>Try a <strong>tubular</strong> cast-on.</p>",
+                "user": { "id": 7, "username": "knitter" }
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let post = client.posts().show(555).await.unwrap().post;
+
+    assert_eq!(post.id, 555);
+    assert_eq!(post.topic_id, Some(42));
+    assert_eq!(post.body.as_deref(), Some("Try a **tubular** cast-on."));
+    assert!(post.body_html.unwrap().contains("<strong>tubular</strong>"));
+    assert_eq!(post.user.unwrap().username, "knitter");
+}
+
+#[tokio::test]
+async fn test_reply_to_topic() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/topics/42/reply.json"))
+        .and(body_json(serde_json::json!({
+            "data": { "body": "Thanks, that worked!" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "post": { "id": 556, "topic_id": 42, "body": "Thanks, that worked!" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let reply = PostPost::new().body("Thanks, that worked!");
+    let post = client.topics().reply(42, &reply).await.unwrap().post;
+
+    assert_eq!(post.id, 556);
+    assert_eq!(post.topic_id, Some(42));
+}
+
+#[tokio::test]
+async fn test_list_forum_topics() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/forums/777/topics.json"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "topics": [
+                {
+                    "id": 42,
+                    "title": "Cast-on advice",
+                    "forum_id": 777,
+                    "forum_posts_count": 12,
+                    "sticky": false,
+                    "user": { "id": 7, "username": "knitter" }
+                }
+            ],
+            "paginator": { "page": 2, "page_count": 2, "page_size": 25, "results": 26, "last_page": 2 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = ForumPageParams::new().page(2);
+    let response = client.forums().topics(777, &params).await.unwrap();
+
+    let topic = &response.topics[0];
+    assert_eq!(topic.title.as_deref(), Some("Cast-on advice"));
+    assert_eq!(topic.forum_posts_count, Some(12));
+    assert_eq!(topic.user.as_ref().unwrap().username, "knitter");
+    assert_eq!(response.paginator.unwrap().page, 2);
+}
+
+#[tokio::test]
+async fn test_list_topic_posts() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/topics/42/posts.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "posts": [
+                {
+                    "id": 555,
+                    "post_number": 1,
+                    "body_html": "<p>How do I cast on?</p>",
+                    "created_at": "2024/01/02 10:00:00 -0500",
+                    "user": { "id": 7, "username": "knitter" }
+                },
+                { "id": 556, "post_number": 2, "body_html": "<p>Long-tail!</p>" }
+            ],
+            "paginator": { "page": 1, "page_count": 1, "page_size": 25, "results": 2, "last_page": 1 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let response = client
+        .forums()
+        .posts(42, &ForumPageParams::new())
+        .await
+        .unwrap();
+
+    assert_eq!(response.posts.len(), 2);
+    assert_eq!(response.posts[0].user.as_ref().unwrap().username, "knitter");
+    assert!(response.posts[0].created_at.is_some());
+    assert!(response.posts[1].user.is_none());
+}
@@ -0,0 +1,37 @@
+//! Integration tests for the friends API.
+
+mod common;
+
+use ravelry::api::friends::FriendsActivityParams;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_activity_since_last_seen_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/friends/activity.json"))
+        .and(query_param("since", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "activity": [
+                { "id": 102, "type": "project_added" },
+                { "id": 101, "type": "pattern_favorited" }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = FriendsActivityParams::new().since(100);
+    let response = client
+        .friends()
+        .activity("testuser", &params)
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = response.activity.iter().filter_map(|a| a.id).collect();
+    assert_eq!(ids, vec![102, 101]);
+    assert!(ids.iter().all(|&id| id > 100));
+}
@@ -0,0 +1,71 @@
+//! Integration tests for the groups API.
+
+mod common;
+
+use ravelry::api::groups::GroupSearchParams;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_search_groups() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/groups/search.json"))
+        .and(query_param("query", "brioche"))
+        .and(query_param("page_size", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "groups": [
+                {
+                    "id": 10,
+                    "name": "Brioche Lovers",
+                    "permalink": "brioche-lovers",
+                    "members_count": 5120,
+                    "description": "Two-color brioche and beyond"
+                },
+                { "id": 11, "name": "Brioche Beginners", "permalink": "brioche-beginners" }
+            ],
+            "paginator": { "page": 1, "page_count": 4, "page_size": 2, "results": 8, "last_page": 4 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = GroupSearchParams::new().query("brioche").page_size(2);
+    let response = client.groups().search(&params).await.unwrap();
+
+    assert_eq!(response.groups.len(), 2);
+    assert_eq!(response.groups[0].members_count, Some(5120));
+    assert!(response.groups[1].description.is_none());
+    assert_eq!(response.paginator.results, 8);
+}
+
+#[tokio::test]
+async fn test_show_group() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/groups/10.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "group": {
+                "id": 10,
+                "name": "Brioche Lovers",
+                "permalink": "brioche-lovers",
+                "members_count": 5120,
+                "description": "Two-color brioche and beyond",
+                "forum_id": 777,
+                "moderators_count": 3
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let group = client.groups().show(10).await.unwrap().group;
+
+    assert_eq!(group.permalink, "brioche-lovers");
+    assert_eq!(group.forum_id, Some(777));
+    assert_eq!(group.extra["moderators_count"], 3);
+}
@@ -0,0 +1,65 @@
+//! Integration tests for request interceptors.
+
+use std::sync::{Arc, Mutex};
+
+use ravelry::auth::BasicAuth;
+use ravelry::interceptor::RequestInterceptor;
+use ravelry::RavelryClient;
+use reqwest::RequestBuilder;
+use wiremock::matchers::{header, header_exists, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Records the URL of each request it sees.
+#[derive(Clone, Default)]
+struct UrlLog(Arc<Mutex<Vec<String>>>);
+
+impl RequestInterceptor for UrlLog {
+    fn intercept(&self, req: RequestBuilder) -> RequestBuilder {
+        if let Some(Ok(request)) = req.try_clone().map(|r| r.build()) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(request.url().path().to_string());
+        }
+        req
+    }
+}
+
+#[tokio::test]
+async fn test_interceptors_run_in_order_after_auth() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/1.json"))
+        .and(header_exists("Authorization"))
+        .and(header("X-Request-Id", "abc123"))
+        .and(header("X-Chain", "first+second"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "pattern": { "id": 1, "name": "Cowl", "permalink": "cowl" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let log = UrlLog::default();
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .interceptor(|req: RequestBuilder| req.header("X-Request-Id", "abc123"))
+        .interceptor(|req: RequestBuilder| req.header("X-Chain", "first"))
+        .interceptor(|req: RequestBuilder| {
+            // Replacing the header shows this ran after the previous one.
+            let (client, request) = req.build_split();
+            let mut request = request.unwrap();
+            request
+                .headers_mut()
+                .insert("X-Chain", "first+second".parse().unwrap());
+            RequestBuilder::from_parts(client, request)
+        })
+        .interceptor(log.clone())
+        .build()
+        .unwrap();
+
+    let pattern = client.patterns().show(1).await.unwrap().pattern;
+    assert_eq!(pattern.id, 1);
+    assert_eq!(*log.0.lock().unwrap(), vec!["/patterns/1.json".to_string()]);
+}
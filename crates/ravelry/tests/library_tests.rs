@@ -0,0 +1,46 @@
+//! Integration tests for the library API.
+
+mod common;
+
+use ravelry::api::library::LibrarySearchParams;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_search_library() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/library/search.json"))
+        .and(query_param("type", "pattern"))
+        .and(query_param("query", "socks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "volumes": [
+                {
+                    "id": 31,
+                    "title": "Vanilla Socks",
+                    "author_name": "Jane Doe",
+                    "pattern_id": 1001,
+                    "created_at": "2024/03/01 09:00:00 -0500",
+                    "has_downloads": true
+                },
+                { "id": 32, "title": "Sock Anthology" }
+            ],
+            "paginator": { "page": 1, "page_count": 1, "page_size": 50, "results": 2, "last_page": 1 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = LibrarySearchParams::new()
+        .query("socks")
+        .type_filter("pattern");
+    let response = client.library().search("testuser", &params).await.unwrap();
+
+    assert_eq!(response.volumes.len(), 2);
+    assert_eq!(response.volumes[0].author_name.as_deref(), Some("Jane Doe"));
+    assert_eq!(response.volumes[0].pattern_id, Some(1001));
+    assert!(response.volumes[1].pattern_id.is_none());
+    assert_eq!(response.paginator.unwrap().results, 2);
+}
@@ -3,8 +3,10 @@
 mod common;
 
 use ravelry::api::messages::{MessageFolder, MessagesListParams};
+use ravelry::auth::{OAuth2Auth, Scope};
 use ravelry::types::MessagePost;
-use wiremock::matchers::{body_json, method, path};
+use ravelry::{RavelryClient, RavelryError};
+use wiremock::matchers::{body_json, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -47,6 +49,98 @@ async fn test_list_messages() {
     assert_eq!(response.messages[1].subject, "World");
 }
 
+#[tokio::test]
+async fn test_list_unread_only_sent_as_int() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/list.json"))
+        .and(query_param("unread_only", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "messages": [],
+            "paginator": {
+                "page": 1,
+                "page_count": 1,
+                "page_size": 20,
+                "results": 0,
+                "last_page": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = MessagesListParams::new()
+        .folder(MessageFolder::Inbox)
+        .unread_only(true);
+    let response = client.messages().list(&params).await.unwrap();
+
+    assert!(response.messages.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_full_includes_content() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/list.json"))
+        .and(query_param("folder", "inbox"))
+        .and(query_param("output_format", "full"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "messages": [{
+                "id": 5,
+                "subject": "Yarn swap?",
+                "content": "Want to trade skeins?",
+                "content_html": "<p>Want to trade skeins?</p>"
+            }],
+            "paginator": {
+                "page": 1,
+                "page_count": 1,
+                "page_size": 20,
+                "results": 1,
+                "last_page": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = MessagesListParams::new().folder(MessageFolder::Inbox);
+    let response = client.messages().list_full(&params).await.unwrap();
+
+    let message = &response.messages[0];
+    assert_eq!(message.content.as_deref(), Some("Want to trade skeins?"));
+    assert!(!message.extra.contains_key("content"));
+}
+
+#[tokio::test]
+async fn test_list_full_without_messages() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/list.json"))
+        .and(query_param("output_format", "full"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "paginator": {
+                "page": 1,
+                "page_count": 0,
+                "page_size": 20,
+                "results": 0,
+                "last_page": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = MessagesListParams::new().folder(MessageFolder::Inbox);
+    let response = client.messages().list_full(&params).await.unwrap();
+    assert!(response.messages.is_empty());
+}
+
 #[tokio::test]
 async fn test_create_message() {
     let server = MockServer::start().await;
@@ -123,3 +217,113 @@ async fn test_unarchive_message() {
     let response = client.messages().unarchive(123).await.unwrap();
     assert_eq!(response.message.id, 123);
 }
+
+#[tokio::test]
+async fn test_send_without_message_write_is_missing_scope() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/create.json"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "error": "Forbidden"
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let post = MessagePost::new()
+        .recipient_username("friend")
+        .subject("Hi")
+        .content("Hello!");
+
+    let oauth = RavelryClient::builder(OAuth2Auth::new("token"))
+        .base_url(server.uri().parse().unwrap())
+        .build()
+        .unwrap();
+    let err = oauth.messages().create(&post).await.unwrap_err();
+    match err {
+        RavelryError::MissingScope { required } => assert_eq!(required, Scope::MessageWrite),
+        other => panic!("Expected MissingScope, got {:?}", other),
+    }
+
+    // Basic auth has no scopes, so a 403 there is a plain Forbidden.
+    let basic = common::test_client(&server);
+    let err = basic.messages().create(&post).await.unwrap_err();
+    assert!(matches!(err, RavelryError::Forbidden { .. }));
+}
+
+#[tokio::test]
+async fn test_forbidden_with_granted_scope_is_not_missing_scope() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/create.json"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "error": "Recipient does not accept messages from you"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let post = MessagePost::new()
+        .recipient_username("friend")
+        .subject("Hi")
+        .content("Hello!");
+
+    let writer =
+        RavelryClient::builder(OAuth2Auth::new("token").granted_scopes([Scope::MessageWrite]))
+            .base_url(server.uri().parse().unwrap())
+            .build()
+            .unwrap();
+    let err = writer.messages().create(&post).await.unwrap_err();
+    assert!(matches!(err, RavelryError::Forbidden { .. }));
+}
+
+#[tokio::test]
+async fn test_check_scopes_rejects_write_before_sending() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/create.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "message": { "id": 1, "subject": "Hi" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let post = MessagePost::new()
+        .recipient_username("friend")
+        .subject("Hi")
+        .content("Hello!");
+
+    let read_only =
+        RavelryClient::builder(OAuth2Auth::new("token").granted_scopes([Scope::Offline]))
+            .base_url(server.uri().parse().unwrap())
+            .check_scopes(true)
+            .build()
+            .unwrap();
+    assert_eq!(read_only.has_scope(&Scope::MessageWrite), Some(false));
+    let err = read_only.messages().create(&post).await.unwrap_err();
+    assert!(matches!(
+        err,
+        RavelryError::MissingScope {
+            required: Scope::MessageWrite
+        }
+    ));
+
+    // With the scope granted the request goes out.
+    let writer =
+        RavelryClient::builder(OAuth2Auth::new("token").granted_scopes([Scope::MessageWrite]))
+            .base_url(server.uri().parse().unwrap())
+            .check_scopes(true)
+            .build()
+            .unwrap();
+    assert_eq!(writer.has_scope(&Scope::MessageWrite), Some(true));
+    writer.messages().create(&post).await.unwrap();
+
+    assert_eq!(
+        common::test_client(&server).has_scope(&Scope::MessageWrite),
+        None
+    );
+}
@@ -0,0 +1,219 @@
+//! Integration tests for OAuth2 token refresh.
+
+use std::sync::{Arc, Mutex};
+
+use ravelry::auth::{
+    FileTokenStore, OAuth2Token, PkceVerifier, RavelryOAuth2Client, RefreshingOAuth2Auth,
+    TokenStore,
+};
+use ravelry::{RavelryClient, RavelryError};
+use time::OffsetDateTime;
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn oauth_client(server: &MockServer) -> RavelryOAuth2Client {
+    RavelryOAuth2Client::new("client_id", "client_secret", "http://localhost/callback")
+        .unwrap()
+        .token_url(format!("{}/oauth2/token", server.uri()).parse().unwrap())
+}
+
+fn expired_token(refresh_token: Option<&str>) -> OAuth2Token {
+    OAuth2Token {
+        access_token: "old_access".to_string(),
+        refresh_token: refresh_token.map(String::from),
+        expires_at: Some(OffsetDateTime::now_utc() - time::Duration::minutes(1)),
+        scope: None,
+        token_type: Some("Bearer".to_string()),
+    }
+}
+
+#[tokio::test]
+async fn test_expired_token_is_refreshed_once() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth2/token"))
+        .and(body_string_contains("refresh_token=refresh_me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "new_access",
+            "token_type": "Bearer",
+            "expires_in": 86400
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .and(header("Authorization", "Bearer new_access"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 1, "username": "knitter" }
+        })))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let saved = Arc::new(Mutex::new(Vec::new()));
+    let auth = RefreshingOAuth2Auth::new(oauth_client(&server), expired_token(Some("refresh_me")))
+        .on_refresh({
+            let saved = saved.clone();
+            move |token| saved.lock().unwrap().push(token.clone())
+        });
+    let client = RavelryClient::builder(auth)
+        .base_url(server.uri().parse().unwrap())
+        .build()
+        .unwrap();
+
+    let root = client.root();
+    let (a, b, c) = tokio::join!(
+        root.current_user(),
+        root.current_user(),
+        root.current_user()
+    );
+    for result in [a, b, c] {
+        assert_eq!(result.unwrap().user.username, "knitter");
+    }
+
+    let saved = saved.lock().unwrap();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].access_token, "new_access");
+    // The refresh token is kept when the response doesn't rotate it.
+    assert_eq!(saved[0].refresh_token.as_deref(), Some("refresh_me"));
+}
+
+#[tokio::test]
+async fn test_expired_token_without_refresh_token_fails() {
+    let server = MockServer::start().await;
+
+    let auth = RefreshingOAuth2Auth::new(oauth_client(&server), expired_token(None));
+    let client = RavelryClient::builder(auth)
+        .base_url(server.uri().parse().unwrap())
+        .build()
+        .unwrap();
+
+    let err = client.root().current_user().await.unwrap_err();
+    assert!(matches!(err, RavelryError::Auth(_)));
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_valid_token_within_custom_skew_is_refreshed() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth2/token"))
+        .and(body_string_contains("refresh_token=refresh_me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "new_access",
+            "token_type": "Bearer",
+            "expires_in": 86400
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .and(header("Authorization", "Bearer new_access"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 1, "username": "knitter" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Still valid for 10 minutes: outside the default 5 minute skew, but
+    // inside the configured 15 minute one.
+    let token = OAuth2Token {
+        expires_at: Some(OffsetDateTime::now_utc() + time::Duration::minutes(10)),
+        ..expired_token(Some("refresh_me"))
+    };
+    let auth = RefreshingOAuth2Auth::new(oauth_client(&server), token)
+        .refresh_skew(std::time::Duration::from_secs(15 * 60));
+    assert_eq!(
+        auth.refresh_skew_value(),
+        std::time::Duration::from_secs(15 * 60)
+    );
+    let client = RavelryClient::builder(auth)
+        .base_url(server.uri().parse().unwrap())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        client.root().current_user().await.unwrap().user.username,
+        "knitter"
+    );
+}
+
+#[tokio::test]
+async fn test_refreshed_token_is_saved_to_store() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth2/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "new_access",
+            "refresh_token": "rotated",
+            "expires_in": 86400
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .and(header("Authorization", "Bearer new_access"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 1, "username": "knitter" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = std::env::temp_dir().join(format!("ravelry-oauth2-tests-{}", std::process::id()));
+    let store = FileTokenStore::new(dir.join("token.json"));
+    store
+        .save(&expired_token(Some("refresh_me")))
+        .await
+        .unwrap();
+
+    let auth = RefreshingOAuth2Auth::from_store(oauth_client(&server), store.clone())
+        .await
+        .unwrap();
+    let client = RavelryClient::builder(auth)
+        .base_url(server.uri().parse().unwrap())
+        .build()
+        .unwrap();
+    client.root().current_user().await.unwrap();
+
+    let saved = store.load().await.unwrap();
+    assert_eq!(saved.access_token, "new_access");
+    assert_eq!(saved.refresh_token.as_deref(), Some("rotated"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_exchange_code_pkce_sends_verifier() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/oauth2/token"))
+        .and(body_string_contains("grant_type=authorization_code"))
+        .and(body_string_contains("code=abc"))
+        .and(body_string_contains("code_verifier=the-verifier"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "access_token": "pkce_access",
+            "token_type": "Bearer"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let verifier = PkceVerifier::new("the-verifier");
+    let token = oauth_client(&server)
+        .exchange_code_pkce("abc", &verifier)
+        .await
+        .unwrap();
+    assert_eq!(token.access_token, "pkce_access");
+}
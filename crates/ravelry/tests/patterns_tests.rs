@@ -0,0 +1,173 @@
+//! Integration tests for the patterns API.
+
+mod common;
+
+use ravelry::api::patterns::PatternSearchParams;
+use ravelry::types::{PatternFull, PatternSort, YarnWeight};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_pattern_summary() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/123.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "pattern": {
+                "id": 123,
+                "name": "Hitchhiker",
+                "permalink": "hitchhiker",
+                "designer_name": "Martina Behm",
+                "free": false,
+                "price": 5.5,
+                "currency": "EUR",
+                "yarn_weight": { "id": 1, "name": "Fingering", "ply": "4" },
+                "notes_html": "<p>Long notes</p>",
+                "projects_count": 40000
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let summary = client.patterns().summary(123).await.unwrap();
+
+    assert_eq!(summary.id, 123);
+    assert_eq!(summary.name, "Hitchhiker");
+    assert_eq!(summary.designer_name.as_deref(), Some("Martina Behm"));
+    assert_eq!(summary.free, Some(false));
+    let price = summary.price.clone().expect("price");
+    assert_eq!(price.amount, 5.5);
+    assert_eq!(price.currency.as_deref(), Some("EUR"));
+    assert_eq!(summary.yarn_weight, Some(YarnWeight::Fingering));
+
+    let value = serde_json::to_value(&summary).unwrap();
+    assert!(value.get("notes_html").is_none());
+    assert!(value.get("projects_count").is_none());
+}
+
+#[tokio::test]
+async fn test_patterns_by_designer() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/search.json"))
+        .and(query_param("designer", "martina-behm"))
+        .and(query_param("sort", "date"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "patterns": [
+                { "id": 1, "name": "Hitchhiker", "permalink": "hitchhiker", "designer_name": "Martina Behm" },
+                { "id": 2, "name": "Lintilla", "permalink": "lintilla", "designer_name": "Martina Behm" }
+            ],
+            "paginator": {
+                "page": 1,
+                "page_count": 1,
+                "page_size": 50,
+                "results": 2,
+                "last_page": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = PatternSearchParams::new().sort("date");
+    let response = client
+        .patterns()
+        .by_designer("martina-behm", &params)
+        .await
+        .unwrap();
+
+    assert_eq!(response.patterns.len(), 2);
+    assert_eq!(response.patterns[1].name, "Lintilla");
+    assert_eq!(
+        response.patterns[0].designer_name.as_deref(),
+        Some("Martina Behm")
+    );
+}
+
+#[tokio::test]
+async fn test_search_with_facets() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/search.json"))
+        .and(query_param("craft", "knitting"))
+        .and(query_param("weight", "light-fingering|dk"))
+        .and(query_param("pa", "seamless"))
+        .and(query_param("pc", "hat|cowl"))
+        .and(query_param("availability", "free"))
+        .and(query_param("fit", "adult"))
+        .and(query_param("color_family", "blue-green"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "patterns": [{ "id": 1, "name": "Seamless Hat", "permalink": "seamless-hat" }],
+            "paginator": {
+                "page": 1,
+                "page_count": 1,
+                "page_size": 50,
+                "results": 1,
+                "last_page": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = PatternSearchParams::new()
+        .craft("knitting")
+        .weight_enums([YarnWeight::LightFingering, YarnWeight::Dk])
+        .pattern_attributes(["seamless"])
+        .pattern_category(vec!["hat".to_string(), "cowl".to_string()])
+        .availability(["free"])
+        .fit(["adult"])
+        .color_family(["blue-green"]);
+    let response = client.patterns().search(&params).await.unwrap();
+
+    assert_eq!(response.patterns[0].name, "Seamless Hat");
+}
+
+#[tokio::test]
+async fn test_search_with_typed_sort() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/search.json"))
+        .and(query_param("sort", "recently-popular"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "patterns": [],
+            "paginator": {
+                "page_count": 0,
+                "page": 1,
+                "page_size": 20,
+                "results": 0,
+                "last_page": 0
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = PatternSearchParams::new().sort_by(PatternSort::RecentlyPopular);
+    client.patterns().search(&params).await.unwrap();
+
+    assert_eq!(PatternSort::from("best"), PatternSort::BestMatch);
+    assert!(PatternSort::from("made-up").is_other());
+}
+
+#[test]
+fn test_yarn_weight_without_name_deserializes() {
+    let pattern: PatternFull = serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "name": "Hat",
+        "permalink": "hat",
+        "yarn_weight": { "id": 5, "name": null }
+    }))
+    .unwrap();
+
+    assert_eq!(pattern.yarn_weight.unwrap().name, None);
+}
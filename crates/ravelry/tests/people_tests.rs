@@ -0,0 +1,100 @@
+//! Integration tests for the people API.
+
+mod common;
+
+use ravelry::api::people::PeopleSearchParams;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_user(server: &MockServer, key: &str, id: u64, username: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("/people/{key}.json")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": id, "username": username }
+        })))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_resolve_ids() {
+    let server = MockServer::start().await;
+    mount_user(&server, "alice", 1, "alice").await;
+    mount_user(&server, "bob", 2, "bob").await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/nobody.json"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let ids = client
+        .people()
+        .resolve_ids(&["alice", "bob", "nobody"])
+        .await
+        .unwrap();
+
+    assert_eq!(ids.len(), 2);
+    assert_eq!(ids["alice"], 1);
+    assert_eq!(ids["bob"], 2);
+
+    // Second lookup is served from the cache (mocks expect one call each)
+    let ids = client.people().resolve_ids(&["alice"]).await.unwrap();
+    assert_eq!(ids["alice"], 1);
+}
+
+#[tokio::test]
+async fn test_resolve_usernames() {
+    let server = MockServer::start().await;
+    mount_user(&server, "1", 1, "alice").await;
+    mount_user(&server, "2", 2, "bob").await;
+
+    let client = common::test_client(&server);
+    let names = client.people().resolve_usernames(&[1, 2]).await.unwrap();
+
+    assert_eq!(names.len(), 2);
+    assert_eq!(names[&1], "alice");
+    assert_eq!(names[&2], "bob");
+
+    // Cached in both directions
+    let ids = client.people().resolve_ids(&["Alice"]).await.unwrap();
+    assert_eq!(ids["Alice"], 1);
+}
+
+#[tokio::test]
+async fn test_search_people_populates_cache() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/search.json"))
+        .and(query_param("query", "knit"))
+        .and(query_param("sort", "username"))
+        .and(query_param("page_size", "5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "users": [
+                { "id": 7, "username": "knitwit", "tiny_photo_url": null },
+                { "id": 8, "username": "KnitOne" }
+            ],
+            "paginator": { "page_count": 1, "page": 1, "page_size": 5, "results": 2, "last_page": 1 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = PeopleSearchParams::new()
+        .query("knit")
+        .sort("username")
+        .page_size(5);
+    let response = client.people().search(&params).await.unwrap();
+
+    assert_eq!(response.users.len(), 2);
+    assert_eq!(response.users[1].username, "KnitOne");
+    assert_eq!(response.paginator.results, 2);
+
+    // No profile mocks are mounted, so these must come from the cache.
+    let ids = client.people().resolve_ids(&["knitone"]).await.unwrap();
+    assert_eq!(ids["knitone"], 8);
+}
@@ -0,0 +1,62 @@
+//! Integration tests for the photos API.
+
+mod common;
+
+use ravelry::api::photos::PhotoParent;
+use ravelry::types::PhotoPost;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_update_photo_caption() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/photos/10.json"))
+        .and(body_json(serde_json::json!({
+            "data": { "caption": "Front", "copyright_holder": "Me" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "photo": { "id": 10, "caption": "Front", "copyright_holder": "Me" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let update = PhotoPost::new().caption("Front").copyright_holder("Me");
+    let response = client.photos().update(10, &update).await.unwrap();
+
+    assert_eq!(response.photo.id, 10);
+    assert_eq!(response.photo.caption.as_deref(), Some("Front"));
+}
+
+#[tokio::test]
+async fn test_set_sort_order_sends_space_delimited_ids() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/projects/testuser/5/reorder_photos.json"))
+        .and(body_json(serde_json::json!({ "sort_order": "30 10 20" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "photos": [
+                { "id": 30, "sort_order": 1 },
+                { "id": 10, "sort_order": 2 },
+                { "id": 20, "sort_order": 3 }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let parent = PhotoParent::project("testuser", 5);
+    let response = client
+        .photos()
+        .set_sort_order(&parent, &[30, 10, 20])
+        .await
+        .unwrap();
+
+    let ids: Vec<u64> = response.photos.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![30, 10, 20]);
+}
@@ -0,0 +1,540 @@
+//! Integration tests for the projects API.
+
+mod common;
+
+use ravelry::api::projects::{ProjectSearchParams, ProjectsListParams};
+use ravelry::request_options::RequestOptions;
+use ravelry::types::{Craft, ProjectPost, ProjectState, ProjectStatus};
+use wiremock::matchers::{body_json, method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_create_private_project() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/projects/testuser/create.json"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "name": "Secret Gift",
+                "include_in_showcase": false
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": {
+                "id": 42,
+                "name": "Secret Gift",
+                "permalink": "secret-gift",
+                "include_in_showcase": false
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let post = ProjectPost::new()
+        .name("Secret Gift")
+        .include_in_showcase(false);
+
+    let response = client.projects().create("testuser", &post).await.unwrap();
+    assert_eq!(response.project.id, 42);
+    assert_eq!(response.project.include_in_showcase, Some(false));
+}
+
+#[tokio::test]
+async fn test_create_project_with_extra_field() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/projects/testuser/create.json"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "name": "Mittens",
+                "size": "Adult M",
+                "needle_sizes": [{ "metric": 3.5 }]
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": {
+                "id": 43,
+                "name": "Mittens",
+                "permalink": "mittens",
+                "size": "Adult M"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let post = ProjectPost::new()
+        .name("Mittens")
+        .extra("size", "Adult M")
+        .extra("needle_sizes", serde_json::json!([{ "metric": 3.5 }]));
+
+    let response = client.projects().create("testuser", &post).await.unwrap();
+    assert_eq!(response.project.id, 43);
+    assert_eq!(response.project.extra["size"], "Adult M");
+}
+
+#[tokio::test]
+async fn test_extra_field_overrides_typed_field() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/projects/testuser/create.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": { "id": 44, "name": "Socks", "permalink": "socks" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let post = ProjectPost::new().name("Mittens").extra("name", "Socks");
+    client.projects().create("testuser", &post).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let body = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert_eq!(body.matches("\"name\"").count(), 1);
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["data"]["name"], "Socks");
+}
+
+#[tokio::test]
+async fn test_comments_count_without_include() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/42.json"))
+        .and(query_param_is_missing("include"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": {
+                "id": 42,
+                "name": "Cardigan",
+                "permalink": "cardigan",
+                "comments_count": 7
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let count = client
+        .projects()
+        .comments_count("testuser", "42")
+        .await
+        .unwrap();
+    assert_eq!(count, Some(7));
+}
+
+#[tokio::test]
+async fn test_list_reads_comments_count() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/list.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "projects": [
+                { "id": 1, "name": "Hat", "permalink": "hat", "comments_count": 3 },
+                { "id": 2, "name": "Scarf", "permalink": "scarf" }
+            ],
+            "paginator": { "page_count": 1, "page": 1, "page_size": 50, "results": 2, "last_page": 1 }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let response = client
+        .projects()
+        .list("testuser", &Default::default())
+        .await
+        .unwrap();
+    assert_eq!(response.projects[0].comments_count, Some(3));
+    assert_eq!(response.projects[1].comments_count, None);
+}
+
+#[tokio::test]
+async fn test_search_projects() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/search.json"))
+        .and(query_param("query", "fair isle"))
+        .and(query_param("craft", "knitting"))
+        .and(query_param("status", "finished"))
+        .and(query_param("sort", "completed"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "projects": [
+                { "id": 9, "name": "Yoke Sweater", "permalink": "yoke-sweater" }
+            ],
+            "paginator": { "page_count": 3, "page": 2, "page_size": 1, "results": 3, "last_page": 3 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = ProjectSearchParams::new()
+        .query("fair isle")
+        .craft_enum(Craft::Knitting)
+        .status("finished")
+        .sort("completed")
+        .page(2);
+    let response = client.projects().search(&params).await.unwrap();
+    assert_eq!(response.projects.len(), 1);
+    assert_eq!(response.projects[0].name, "Yoke Sweater");
+    assert_eq!(response.paginator.page, 2);
+}
+
+#[tokio::test]
+async fn test_made_for_fields_round_trip() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/projects/testuser/create.json"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "name": "Baby Blanket",
+                "made_for_user_id": 77,
+                "made_for": "Niece",
+                "gifted": true
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": { "id": 43, "name": "Baby Blanket", "permalink": "baby-blanket" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/43.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": {
+                "id": 43,
+                "name": "Baby Blanket",
+                "permalink": "baby-blanket",
+                "made_for_user_id": 77,
+                "made_for": "Niece",
+                "gifted": true
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let post = ProjectPost::new()
+        .name("Baby Blanket")
+        .made_for_user_id(77)
+        .made_for_name("Niece")
+        .gifted(true);
+    client.projects().create("testuser", &post).await.unwrap();
+
+    let project = client
+        .projects()
+        .show("testuser", "43", &Default::default())
+        .await
+        .unwrap()
+        .project;
+    assert_eq!(project.made_for_user_id, Some(77));
+    assert_eq!(project.made_for_name.as_deref(), Some("Niece"));
+    assert_eq!(project.gifted, Some(true));
+    assert!(!project.extra.contains_key("made_for"));
+}
+
+#[tokio::test]
+async fn test_unknown_status_is_captured() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/44.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": {
+                "id": 44,
+                "name": "Shawl",
+                "permalink": "shawl",
+                "status_name": "Blocking"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let project = client
+        .projects()
+        .show("testuser", "44", &Default::default())
+        .await
+        .unwrap()
+        .project;
+    assert_eq!(
+        project.status(),
+        Some(ProjectStatus::Other("Blocking".to_string()))
+    );
+}
+
+fn sorted_client(server: &MockServer) -> ravelry::RavelryClient {
+    ravelry::RavelryClient::builder(ravelry::auth::BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .default_project_sort("created")
+        .build()
+        .unwrap()
+}
+
+fn empty_project_list() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "projects": [],
+        "paginator": { "page_count": 1, "page": 1, "page_size": 50, "results": 0, "last_page": 1 }
+    }))
+}
+
+#[tokio::test]
+async fn test_default_project_sort_applied() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/list.json"))
+        .and(query_param("sort", "created"))
+        .respond_with(empty_project_list())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = sorted_client(&server);
+    client
+        .projects()
+        .list("testuser", &Default::default())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_request_options_keep_earlier_builder_settings() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/list.json"))
+        .and(query_param("sort", "created"))
+        .and(query_param("debug", "1"))
+        .respond_with(empty_project_list())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client =
+        ravelry::RavelryClient::builder(ravelry::auth::BasicAuth::new("test_user", "test_key"))
+            .base_url(server.uri().parse().unwrap())
+            .debug(true)
+            .default_project_sort("created")
+            .request_options(RequestOptions::new().return_cached_on_304(true))
+            .build()
+            .unwrap();
+    client
+        .projects()
+        .list("testuser", &Default::default())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_explicit_project_sort_wins() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/list.json"))
+        .and(query_param("sort", "name"))
+        .respond_with(empty_project_list())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = sorted_client(&server);
+    let params = ProjectsListParams::new().sort("name");
+    client.projects().list("testuser", &params).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let sorts: Vec<_> = requests[0]
+        .url
+        .query_pairs()
+        .filter(|(k, _)| k == "sort")
+        .collect();
+    assert_eq!(sorts.len(), 1);
+}
+
+#[tokio::test]
+async fn test_list_me_resolves_and_caches_username() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 5, "username": "me_knits" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/me_knits/list.json"))
+        .respond_with(empty_project_list())
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = ProjectsListParams::new();
+    client.projects().list_me(&params).await.unwrap();
+    // The second call reuses the cached username.
+    client.projects().list_me(&params).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_project_state_distinguishes_deleted() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/live.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": { "id": 1, "name": "Live", "permalink": "live" }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/tombstone.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": { "id": 2, "name": "Gone", "permalink": "tombstone", "deleted": true }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/gone.json"))
+        .respond_with(ResponseTemplate::new(410))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/missing.json"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "error": "Not found"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let projects = client.projects();
+
+    let live = projects.state("testuser", "live").await.unwrap();
+    assert_eq!(live.active().map(|p| p.id), Some(1));
+    assert!(matches!(
+        projects.state("testuser", "tombstone").await.unwrap(),
+        ProjectState::Deleted
+    ));
+    assert!(matches!(
+        projects.state("testuser", "gone").await.unwrap(),
+        ProjectState::Deleted
+    ));
+    assert!(matches!(
+        projects.state("testuser", "missing").await.unwrap(),
+        ProjectState::NotFound
+    ));
+}
+
+#[tokio::test]
+async fn test_photos_sorted_with_all_sizes() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/projects/testuser/my-sweater.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": {
+                "id": 1,
+                "name": "My Sweater",
+                "permalink": "my-sweater",
+                "photos": [
+                    {
+                        "id": 20,
+                        "sort_order": 2,
+                        "caption": "Back",
+                        "medium_url": "https://images.example/20_medium.jpg"
+                    },
+                    {
+                        "id": 10,
+                        "sort_order": 1,
+                        "caption": "Front",
+                        "caption_html": "<p>Front</p>",
+                        "x_offset": 0,
+                        "y_offset": -12,
+                        "width": 640,
+                        "height": 480,
+                        "thumbnail_url": "https://images.example/10_thumbnail.jpg",
+                        "square_url": "https://images.example/10_square.jpg",
+                        "small_url": "https://images.example/10_small.jpg",
+                        "small2_url": "https://images.example/10_small2.jpg",
+                        "medium_url": "https://images.example/10_medium.jpg",
+                        "medium2_url": "https://images.example/10_medium2.jpg",
+                        "shelved_url": "https://images.example/10_shelved.jpg"
+                    }
+                ]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let photos = client
+        .projects()
+        .photos("testuser", "my-sweater")
+        .await
+        .unwrap();
+
+    assert_eq!(photos.len(), 2);
+    let front = &photos[0];
+    assert_eq!(front.id, 10);
+    assert_eq!(front.caption.as_deref(), Some("Front"));
+    assert_eq!(front.y_offset, Some(-12));
+    assert_eq!((front.width, front.height), (Some(640), Some(480)));
+    assert_eq!(
+        front.medium2_url.as_deref(),
+        Some("https://images.example/10_medium2.jpg")
+    );
+    assert_eq!(photos[1].id, 20);
+    assert!(photos[1].square_url.is_none());
+}
+
+#[tokio::test]
+async fn test_create_project_with_uploaded_photos() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/projects/testuser/create.json"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "name": "Mittens",
+                "photos": [{ "image_id": 501 }, { "image_id": 502 }]
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "project": {
+                "id": 43,
+                "name": "Mittens",
+                "permalink": "mittens",
+                "photos": [{ "id": 9001, "sort_order": 1 }, { "id": 9002, "sort_order": 2 }]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let post = ProjectPost::new().name("Mittens").photo_ids(vec![501, 502]);
+
+    let response = client.projects().create("testuser", &post).await.unwrap();
+    assert_eq!(response.project.photos.len(), 2);
+}
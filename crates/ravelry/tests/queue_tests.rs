@@ -0,0 +1,150 @@
+//! Integration tests for the queue API.
+
+mod common;
+
+use ravelry::api::queue::QueueListParams;
+use ravelry::types::{QueuePost, YarnWeight};
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_shopping_list_aggregates_queue() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/knitter/queue/list.json"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "queued_projects": [
+                { "id": 1, "pattern_id": 10, "pattern_name": "Socks" },
+                { "id": 2, "pattern_id": 20, "pattern_name": "Shawl" },
+                { "id": 3, "name": "Freestyle hat" }
+            ],
+            "paginator": {
+                "page": 1,
+                "page_count": 1,
+                "page_size": 100,
+                "results": 3,
+                "last_page": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/10.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "pattern": {
+                "id": 10,
+                "name": "Socks",
+                "permalink": "socks",
+                "yardage": 350,
+                "yardage_max": 420,
+                "yarn_weight": { "id": 1, "name": "Fingering" }
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/20.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "pattern": {
+                "id": 20,
+                "name": "Shawl",
+                "permalink": "shawl",
+                "yardage": 400,
+                "yarn_weight": { "id": 1, "name": "Fingering" }
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let list = client.queue().shopping_list("knitter").await.unwrap();
+
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].yarn_weight, Some(YarnWeight::Fingering));
+    assert_eq!(list[0].yardage, 820);
+    assert_eq!(list[0].pattern_ids, vec![10, 20]);
+}
+
+#[tokio::test]
+async fn test_list_queue() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/queue/list.json"))
+        .and(query_param("page_size", "10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "queued_projects": [
+                {
+                    "id": 1,
+                    "name": "Winter hat",
+                    "pattern_id": 12345,
+                    "pattern_name": "Barley",
+                    "sort_order": 1
+                }
+            ],
+            "paginator": {
+                "page": 1,
+                "page_count": 1,
+                "page_size": 10,
+                "results": 1,
+                "last_page": 1
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = QueueListParams::new().page_size(10);
+    let response = client.queue().list("testuser", &params).await.unwrap();
+
+    assert_eq!(response.queued_projects.len(), 1);
+    assert_eq!(response.queued_projects[0].pattern_id, Some(12345));
+    assert_eq!(response.queued_projects[0].sort_order, Some(1));
+}
+
+#[tokio::test]
+async fn test_create_queue_entry() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/people/testuser/queue/create.json"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "pattern_id": 12345,
+                "notes": "Use the leftover merino",
+                "sort_order": 2
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "queued_project": {
+                "id": 999,
+                "pattern_id": 12345,
+                "notes": "Use the leftover merino",
+                "sort_order": 2
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let post = QueuePost::new()
+        .pattern_id(12345)
+        .notes("Use the leftover merino")
+        .sort_order(2);
+
+    let response = client.queue().create("testuser", &post).await.unwrap();
+    assert_eq!(response.queued_project.id, 999);
+    assert_eq!(
+        response.queued_project.notes.as_deref(),
+        Some("Use the leftover merino")
+    );
+}
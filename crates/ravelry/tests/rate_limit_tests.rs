@@ -0,0 +1,50 @@
+//! Integration tests for rate limit status tracking.
+
+mod common;
+
+use ravelry::RateLimitInfo;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_rate_limit_status_tracks_latest_response() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-ratelimit-limit", "1000")
+                .insert_header("x-ratelimit-remaining", "998")
+                .insert_header("x-ratelimit-reset", "3600")
+                .set_body_json(serde_json::json!({
+                    "user": { "id": 1, "username": "knitter" }
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/1.json"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "error": "Not found"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    assert_eq!(client.rate_limit_status(), None);
+
+    client.root().current_user().await.unwrap();
+    let expected = RateLimitInfo {
+        limit: Some(1000),
+        remaining: Some(998),
+        reset: Some(3600),
+    };
+    assert_eq!(client.rate_limit_status(), Some(expected));
+    assert_eq!(client.last_rate_limit(), Some(expected));
+
+    // A response without rate limit headers keeps the last known status.
+    assert!(client.patterns().show(1).await.is_err());
+    assert_eq!(client.rate_limit_status(), Some(expected));
+}
@@ -0,0 +1,94 @@
+//! Integration tests for the raw `get_json`/`post_json` escape hatch.
+
+mod common;
+
+use ravelry::RavelryError;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use wiremock::matchers::{body_json, header_exists, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[derive(Deserialize)]
+struct ShopsResponse {
+    shops: Vec<Shop>,
+}
+
+#[derive(Deserialize)]
+struct Shop {
+    id: u64,
+    name: String,
+}
+
+#[tokio::test]
+async fn test_get_json_sends_query_and_auth() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/shops/search.json"))
+        .and(query_param("query", "portland"))
+        .and(header_exists("Authorization"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "shops": [{ "id": 3, "name": "Knit Shop" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let response: ShopsResponse = client
+        .get_json("shops/search.json", &[("query", "portland")])
+        .await
+        .unwrap();
+
+    assert_eq!(response.shops.len(), 1);
+    assert_eq!(response.shops[0].id, 3);
+    assert_eq!(response.shops[0].name, "Knit Shop");
+}
+
+#[tokio::test]
+async fn test_post_json_sends_body_as_is() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/shops/3/follow.json"))
+        .and(body_json(serde_json::json!({ "data": { "notify": true } })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "success": true })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let response: serde_json::Value = client
+        .post_json(
+            "shops/3/follow.json",
+            &serde_json::json!({ "data": { "notify": true } }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response["success"], true);
+}
+
+#[tokio::test]
+async fn test_get_json_maps_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/shops/999.json"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(serde_json::json!({ "error": "not found" })),
+        )
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let err = client
+        .get_json::<serde_json::Value, _>("shops/999.json", &())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RavelryError::ApiStatus { .. }));
+    assert_eq!(err.status_code(), Some(StatusCode::NOT_FOUND));
+}
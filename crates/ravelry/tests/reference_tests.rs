@@ -0,0 +1,196 @@
+//! Integration tests for the reference data API.
+
+mod common;
+
+use ravelry::types::YarnWeight;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_needles() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/needles/sizes.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "needle_sizes": [
+                {
+                    "id": 5,
+                    "name": "US 6 - 4.0 mm",
+                    "us": "6",
+                    "metric": 4.0,
+                    "pretty_metric": "4.0",
+                    "hook": "G-6",
+                    "knitting": true,
+                    "crochet": true
+                },
+                { "id": 1, "metric": 0.6, "us_steel": "14", "knitting": false, "crochet": true }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let needles = client.reference().needles().await.unwrap();
+
+    assert_eq!(needles.len(), 2);
+    assert_eq!(needles[0].us.as_deref(), Some("6"));
+    assert_eq!(needles[0].metric, Some(4.0));
+    assert_eq!(needles[1].us_steel.as_deref(), Some("14"));
+    assert_eq!(needles[1].knitting, Some(false));
+}
+
+#[tokio::test]
+async fn test_yarn_weights() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/yarn_weights.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "yarn_weights": [
+                {
+                    "id": 1,
+                    "name": "Worsted",
+                    "ply": "10",
+                    "wpi": "9",
+                    "knit_gauge": "16-20",
+                    "min_gauge": 16.0,
+                    "max_gauge": 20.0
+                },
+                { "id": 12, "name": "Thread" }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let weights = client.reference().yarn_weights().await.unwrap();
+
+    assert_eq!(weights.len(), 2);
+    assert_eq!(weights[0].weight(), Some(YarnWeight::Worsted));
+    assert_eq!(weights[0].knit_gauge.as_deref(), Some("16-20"));
+    assert_eq!(weights[1].weight(), Some(YarnWeight::Thread));
+    assert!(weights[1].ply.is_none());
+}
+
+#[tokio::test]
+async fn test_color_families() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/color_families.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "color_families": [
+                {
+                    "id": 3,
+                    "name": "Blue-green",
+                    "permalink": "blue-green",
+                    "color": "#3a7d7b",
+                    "spectrum_order": 7
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let families = client.reference().color_families().await.unwrap();
+
+    assert_eq!(families.len(), 1);
+    assert_eq!(families[0].name.as_deref(), Some("Blue-green"));
+    assert_eq!(families[0].color.as_deref(), Some("#3a7d7b"));
+    assert_eq!(families[0].spectrum_order, Some(7));
+}
+
+#[tokio::test]
+async fn test_pattern_categories_tree() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/pattern_categories/list.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "pattern_categories": {
+                "id": 1,
+                "name": "Categories",
+                "children": [
+                    {
+                        "id": 300,
+                        "name": "Accessories",
+                        "permalink": "accessories",
+                        "children": [
+                            {
+                                "id": 320,
+                                "name": "Hat",
+                                "permalink": "hat",
+                                "children": [
+                                    { "id": 321, "name": "Beanie, Toque", "permalink": "beanie-toque" }
+                                ]
+                            }
+                        ]
+                    },
+                    { "id": 400, "name": "Home", "permalink": "home" }
+                ]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let categories = client.reference().pattern_categories().await.unwrap();
+
+    assert_eq!(categories.len(), 2);
+    let hat = &categories[0].children[0];
+    assert_eq!(hat.permalink.as_deref(), Some("hat"));
+    assert_eq!(hat.children[0].name, "Beanie, Toque");
+    assert!(hat.children[0].children.is_empty());
+    assert!(categories[1].children.is_empty());
+}
+
+#[tokio::test]
+async fn test_pattern_attribute_groups() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/pattern_attributes/groups.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "attribute_groups": [
+                {
+                    "id": 10,
+                    "name": "Construction",
+                    "permalink": "construction",
+                    "pattern_attributes": [
+                        { "id": 7, "name": "Seamless", "permalink": "seamless" }
+                    ],
+                    "children": [
+                        {
+                            "id": 11,
+                            "name": "Direction",
+                            "pattern_attributes": [
+                                { "id": 8, "name": "Top-down", "permalink": "top-down" }
+                            ]
+                        }
+                    ]
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let groups = client.reference().pattern_attribute_groups().await.unwrap();
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(
+        groups[0].pattern_attributes[0].permalink.as_deref(),
+        Some("seamless")
+    );
+    let direction = &groups[0].children[0];
+    assert_eq!(direction.name, "Direction");
+    assert!(direction.children.is_empty());
+    assert_eq!(direction.pattern_attributes[0].id, 8);
+}
@@ -0,0 +1,163 @@
+//! Integration tests for automatic retries.
+
+use std::time::Duration;
+
+use ravelry::auth::BasicAuth;
+use ravelry::retry::{Jitter, RetryPolicy};
+use ravelry::{RavelryClient, RavelryError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn retrying_client(server: &MockServer, policy: RetryPolicy) -> RavelryClient {
+    RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .retry(policy)
+        .build()
+        .unwrap()
+}
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::new()
+        .max_attempts(3)
+        .base_delay(Duration::from_millis(1))
+        .jitter(Jitter::None)
+}
+
+fn current_user_ok() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "user": { "id": 1, "username": "knitter" }
+    }))
+}
+
+#[tokio::test]
+async fn test_retries_rate_limit_then_succeeds() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(current_user_ok())
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = retrying_client(&server, fast_policy());
+    let response = client.root().current_user().await.unwrap();
+
+    assert_eq!(response.user.username, "knitter");
+    assert_eq!(client.last_attempts(), 3);
+}
+
+#[tokio::test]
+async fn test_gives_up_after_max_attempts() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let client = retrying_client(&server, fast_policy());
+    let err = client.root().current_user().await.unwrap_err();
+
+    assert!(matches!(err, RavelryError::ServiceUnavailable { .. }));
+    assert_eq!(client.last_attempts(), 3);
+}
+
+#[tokio::test]
+async fn test_non_retryable_error_is_not_retried() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = retrying_client(&server, fast_policy());
+    let err = client.root().current_user().await.unwrap_err();
+
+    assert!(matches!(err, RavelryError::ApiStatus { .. }));
+    assert_eq!(client.last_attempts(), 1);
+}
+
+#[tokio::test]
+async fn test_retry_after_beyond_max_elapsed_is_not_waited_for() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "60"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let policy = fast_policy().max_elapsed(Duration::from_secs(1));
+    let client = retrying_client(&server, policy);
+    let err = client.root().current_user().await.unwrap_err();
+
+    assert_eq!(err.retry_after(), Some(Duration::from_secs(60)));
+    assert_eq!(client.last_attempts(), 1);
+}
+
+#[tokio::test]
+async fn test_post_timeout_is_not_retried() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/create.json"))
+        .respond_with(current_user_ok().set_delay(Duration::from_millis(500)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .retry(fast_policy())
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+    let err = client
+        .post_json::<serde_json::Value, _>("messages/create.json", &serde_json::json!({}))
+        .await
+        .unwrap_err();
+
+    assert!(err.is_retryable());
+    assert_eq!(client.last_attempts(), 1);
+}
+
+#[tokio::test]
+async fn test_post_timeout_is_retried_when_opted_in() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/create.json"))
+        .respond_with(current_user_ok().set_delay(Duration::from_millis(500)))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "test_key"))
+        .base_url(server.uri().parse().unwrap())
+        .retry(fast_policy().retry_non_idempotent(true))
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+    let err = client
+        .post_json::<serde_json::Value, _>("messages/create.json", &serde_json::json!({}))
+        .await
+        .unwrap_err();
+
+    assert!(err.is_retryable());
+    assert_eq!(client.last_attempts(), 3);
+}
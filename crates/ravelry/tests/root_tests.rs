@@ -0,0 +1,171 @@
+//! Integration tests for the root API.
+
+mod common;
+
+use ravelry::api::root::Dashboard;
+use ravelry::auth::{AuthKind, OAuth2Auth};
+use ravelry::RavelryError;
+use wiremock::matchers::{header, header_exists, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_dashboard_aggregates_counts() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 5, "username": "me_knits" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/list.json"))
+        .and(query_param("folder", "inbox"))
+        .and(query_param("unread_only", "1"))
+        .and(query_param("page_size", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "messages": [{ "id": 1, "subject": "Hello" }],
+            "paginator": { "page_count": 3, "page": 1, "page_size": 1, "results": 3, "last_page": 3 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/me_knits/friends/activity.json"))
+        .and(query_param("since", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "activity": [
+                { "id": 104, "type": "project_added" },
+                { "id": 102, "type": "pattern_favorited" }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let dashboard = client.root().dashboard(Some(100)).await.unwrap();
+
+    assert_eq!(
+        dashboard,
+        Dashboard {
+            username: "me_knits".to_string(),
+            unread_messages: 3,
+            new_activity: 2,
+            latest_activity_id: Some(104),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_with_auth_swaps_credentials() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .and(header("Authorization", "Bearer new_token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 5, "username": "me_knits" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let swapped = client.with_auth(OAuth2Auth::new("new_token"));
+
+    assert_eq!(swapped.auth_kind(), AuthKind::OAuth2);
+    assert_eq!(swapped.base_url(), client.base_url());
+    let user = swapped.root().current_user().await.unwrap().user;
+    assert_eq!(user.username, "me_knits");
+    assert_eq!(client.auth_kind(), AuthKind::Basic);
+}
+
+#[test]
+fn test_dashboard_deserializes_sample() {
+    let dashboard: Dashboard = serde_json::from_value(serde_json::json!({
+        "username": "me_knits",
+        "unread_messages": 12,
+        "new_activity": 0
+    }))
+    .unwrap();
+
+    assert_eq!(dashboard.unread_messages, 12);
+    assert_eq!(dashboard.new_activity, 0);
+    assert_eq!(dashboard.latest_activity_id, None);
+}
+
+#[tokio::test]
+async fn test_current_user_profile_fields() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": {
+                "id": 7,
+                "username": "knitter",
+                "first_name": "Kay",
+                "last_name": "Nitt",
+                "location": "Shetland",
+                "about_me_html": "<p>Lace enthusiast</p>",
+                "pattern_count": 12,
+                "favorites_count": 340,
+                "fave_colors": "blue"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let user = client.root().current_user().await.unwrap().user;
+
+    assert_eq!(user.first_name.as_deref(), Some("Kay"));
+    assert_eq!(user.last_name.as_deref(), Some("Nitt"));
+    assert_eq!(user.location.as_deref(), Some("Shetland"));
+    assert_eq!(
+        user.about_me_html.as_deref(),
+        Some("<p>Lace enthusiast</p>")
+    );
+    assert_eq!(user.pattern_count, Some(12));
+    assert_eq!(user.favorites_count, Some(340));
+    assert!(user.extra.contains_key("fave_colors"));
+    assert!(!user.extra.contains_key("location"));
+}
+
+#[tokio::test]
+async fn test_ping_sends_authenticated_head() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("HEAD"))
+        .and(path("/current_user.json"))
+        .and(header_exists("Authorization"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    client.ping().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_ping_maps_unauthorized_to_auth_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("HEAD"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let err = client.ping().await.unwrap_err();
+    assert!(matches!(err, RavelryError::Auth(_)), "got {err:?}");
+}
@@ -0,0 +1,103 @@
+//! Integration tests for the saved searches API.
+
+mod common;
+
+use ravelry::types::SavedSearchPost;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_list_saved_searches() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/people/testuser/saved_searches/list.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "saved_searches": [
+                {
+                    "id": 1,
+                    "name": "DK cardigans",
+                    "query_string": "pc=cardigan&weight=dk",
+                    "search_type": "patterns"
+                },
+                { "id": 2, "name": "Sock yarn", "search_type": "yarns" }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let searches = client
+        .saved_searches()
+        .list("testuser")
+        .await
+        .unwrap()
+        .saved_searches;
+
+    assert_eq!(searches.len(), 2);
+    assert_eq!(searches[0].name.as_deref(), Some("DK cardigans"));
+    assert_eq!(
+        searches[0].query_string.as_deref(),
+        Some("pc=cardigan&weight=dk")
+    );
+    assert_eq!(searches[1].search_type.as_deref(), Some("yarns"));
+    assert!(searches[1].query_string.is_none());
+}
+
+#[tokio::test]
+async fn test_create_and_delete_saved_search() {
+    let server = MockServer::start().await;
+
+    let saved = serde_json::json!({
+        "saved_search": {
+            "id": 5,
+            "name": "Lace shawls",
+            "query_string": "pc=shawl&weight=lace",
+            "search_type": "patterns"
+        }
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/people/testuser/saved_searches/create.json"))
+        .and(body_json(serde_json::json!({
+            "data": {
+                "name": "Lace shawls",
+                "query_string": "pc=shawl&weight=lace",
+                "search_type": "patterns"
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(saved.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/people/testuser/saved_searches/5.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(saved))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let post = SavedSearchPost::new()
+        .name("Lace shawls")
+        .search_type("patterns")
+        .query_string("pc=shawl&weight=lace");
+
+    let created = client
+        .saved_searches()
+        .create("testuser", &post)
+        .await
+        .unwrap()
+        .saved_search;
+    assert_eq!(created.id, 5);
+
+    let deleted = client
+        .saved_searches()
+        .delete("testuser", created.id)
+        .await
+        .unwrap()
+        .saved_search;
+    assert_eq!(deleted.name.as_deref(), Some("Lace shawls"));
+}
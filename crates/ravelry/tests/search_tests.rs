@@ -0,0 +1,250 @@
+//! Integration tests for streaming and merged search.
+
+mod common;
+
+use futures::StreamExt;
+use ravelry::api::patterns::PatternSearchParams;
+use ravelry::api::root::SearchHit;
+use ravelry::api::yarns::YarnSearchParams;
+use ravelry::types::Craft;
+use ravelry::Direction;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn paginator(page: u32, last_page: u32) -> serde_json::Value {
+    serde_json::json!({
+        "page": page,
+        "page_count": last_page,
+        "page_size": 1,
+        "results": last_page,
+        "last_page": last_page
+    })
+}
+
+#[tokio::test]
+async fn test_search_stream_merges_patterns_and_yarns() {
+    let server = MockServer::start().await;
+
+    for page in 1..=2u32 {
+        Mock::given(method("GET"))
+            .and(path("/patterns/search.json"))
+            .and(query_param("query", "merino"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "patterns": [
+                    { "id": page, "name": format!("Pattern {page}"), "permalink": format!("pattern-{page}") }
+                ],
+                "paginator": paginator(page, 2)
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/yarns/search.json"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "yarns": [
+                { "id": 10, "name": "Merino Worsted", "permalink": "merino-worsted" }
+            ],
+            "paginator": paginator(1, 1)
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let mut pattern_params = PatternSearchParams::new().query("merino");
+    pattern_params.page.page_size = Some(1);
+    let yarn_params = YarnSearchParams::new().query("merino");
+
+    let hits: Vec<_> = client
+        .root()
+        .search_stream(&pattern_params, &yarn_params)
+        .collect()
+        .await;
+
+    let hits: Vec<SearchHit> = hits.into_iter().map(Result::unwrap).collect();
+    assert_eq!(hits.len(), 3);
+
+    let pattern_ids: Vec<_> = hits
+        .iter()
+        .filter_map(|hit| match hit {
+            SearchHit::Pattern(p) => Some(p.id),
+            SearchHit::Yarn(_) => None,
+        })
+        .collect();
+    assert_eq!(pattern_ids, vec![1, 2]);
+    assert!(hits
+        .iter()
+        .any(|hit| matches!(hit, SearchHit::Yarn(y) if y.id == 10)));
+}
+
+#[tokio::test]
+async fn test_search_stream_yields_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/search.json"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/yarns/search.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "yarns": [],
+            "paginator": paginator(1, 1)
+        })))
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let hits: Vec<_> = client
+        .root()
+        .search_stream(&PatternSearchParams::new(), &YarnSearchParams::new())
+        .collect()
+        .await;
+
+    assert_eq!(hits.len(), 1);
+    assert!(hits[0].is_err());
+}
+
+#[tokio::test]
+async fn test_search_page_next_and_prev() {
+    let server = MockServer::start().await;
+
+    for page in 1..=2u32 {
+        Mock::given(method("GET"))
+            .and(path("/patterns/search.json"))
+            .and(query_param("query", "hat"))
+            .and(query_param("page_size", "1"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "patterns": [
+                    { "id": page, "name": format!("Hat {page}"), "permalink": format!("hat-{page}") }
+                ],
+                "paginator": paginator(page, 2)
+            })))
+            .mount(&server)
+            .await;
+    }
+
+    let client = common::test_client(&server);
+    let params = PatternSearchParams::new().query("hat").page_size(1);
+
+    let first = client.patterns().search_page(&params).await.unwrap();
+    assert_eq!(first.paginator.page, 1);
+    assert_eq!(first.items[0].id, 1);
+    assert!(first.prev(&client).await.unwrap().is_none());
+
+    let second = first.next(&client).await.unwrap().expect("second page");
+    assert_eq!(second.paginator.page, 2);
+    assert_eq!(second.items[0].id, 2);
+    assert!(second.next(&client).await.unwrap().is_none());
+
+    let back = second.prev(&client).await.unwrap().expect("first page");
+    assert_eq!(back.items[0].id, 1);
+}
+
+#[tokio::test]
+async fn test_search_relative_forward_and_backward() {
+    let server = MockServer::start().await;
+
+    for page in 1..=3u32 {
+        Mock::given(method("GET"))
+            .and(path("/patterns/search.json"))
+            .and(query_param("query", "sock"))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "patterns": [
+                    { "id": page, "name": format!("Sock {page}"), "permalink": format!("sock-{page}") }
+                ],
+                "paginator": paginator(page, 3)
+            })))
+            .mount(&server)
+            .await;
+    }
+
+    let client = common::test_client(&server);
+    let params = PatternSearchParams::new().query("sock").page(2);
+    let response = client.patterns().search(&params).await.unwrap();
+    assert_eq!(response.paginator.page, 2);
+
+    let next = client
+        .patterns()
+        .search_relative(&params, &response.paginator, Direction::Next)
+        .await
+        .unwrap()
+        .expect("page 3");
+    assert_eq!(next.items[0].id, 3);
+    assert!(next
+        .relative(&client, Direction::Next)
+        .await
+        .unwrap()
+        .is_none());
+
+    let prev = client
+        .patterns()
+        .search_relative(&params, &response.paginator, Direction::Prev)
+        .await
+        .unwrap()
+        .expect("page 1");
+    assert_eq!(prev.items[0].id, 1);
+    assert!(client
+        .patterns()
+        .search_relative(&params, &prev.paginator, Direction::Prev)
+        .await
+        .unwrap()
+        .is_none());
+
+    let last = prev
+        .relative(&client, Direction::Last)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(last.paginator.page, 3);
+}
+
+#[tokio::test]
+async fn test_personal_attributes_sent_as_int() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/search.json"))
+        .and(query_param("personal_attributes", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "patterns": [],
+            "paginator": paginator(1, 1)
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = PatternSearchParams::new().personal_attributes(false);
+    let response = client.patterns().search(&params).await.unwrap();
+
+    assert!(response.patterns.is_empty());
+}
+
+#[tokio::test]
+async fn test_pattern_search_craft_enum_sends_permalink() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/patterns/search.json"))
+        .and(query_param("craft", "machine-knitting"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "patterns": [],
+            "paginator": paginator(1, 1)
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = PatternSearchParams::new().craft_enum(Craft::MachineKnitting);
+    client.patterns().search(&params).await.unwrap();
+}
@@ -0,0 +1,84 @@
+//! Integration tests for request tracing.
+
+#![cfg(feature = "tracing")]
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use ravelry::auth::BasicAuth;
+use ravelry::retry::RetryPolicy;
+use ravelry::RavelryClient;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::util::SubscriberInitExt;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Collects formatted log output in memory.
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for Captured {
+    type Writer = Captured;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[tokio::test]
+async fn test_requests_and_retries_are_traced_without_credentials() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/current_user.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "user": { "id": 1, "username": "knitter" }
+        })))
+        .mount(&server)
+        .await;
+
+    let logs = Captured::default();
+    let _guard = tracing_subscriber::fmt()
+        .with_max_level(LevelFilter::DEBUG)
+        .with_ansi(false)
+        .with_writer(logs.clone())
+        .finish()
+        .set_default();
+
+    let client = RavelryClient::builder(BasicAuth::new("test_user", "secret_key"))
+        .base_url(server.uri().parse().unwrap())
+        .retry(RetryPolicy::new().base_delay(Duration::from_millis(1)))
+        .build()
+        .unwrap();
+    client.root().current_user().await.unwrap();
+
+    let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(output.contains("ravelry_request"), "{output}");
+    assert!(output.contains("method=GET"), "{output}");
+    assert!(output.contains("path=\"/current_user.json\""), "{output}");
+    assert!(output.contains("status=503"), "{output}");
+    assert!(output.contains("retrying request"), "{output}");
+    assert!(output.contains("status=200"), "{output}");
+    assert!(!output.contains("Basic"), "{output}");
+    assert!(!output.contains("secret_key"), "{output}");
+}
@@ -4,8 +4,8 @@
 
 mod common;
 
-use ravelry::types::UploadFile;
-use wiremock::matchers::{header_exists, method, path};
+use ravelry::types::{UploadFile, UploadSession, UploadSessionFile};
+use wiremock::matchers::{header_exists, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -112,3 +112,78 @@ async fn test_image_status_no_auth() {
 
     assert_eq!(response.uploads.len(), 1);
 }
+
+#[tokio::test]
+async fn test_resume_session_uploads_only_missing_files() {
+    let server = MockServer::start().await;
+
+    let dir = std::env::temp_dir().join(format!("ravelry-upload-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for name in ["done.jpg", "sent.jpg", "new.jpg"] {
+        std::fs::write(dir.join(name), name.as_bytes()).unwrap();
+    }
+
+    // "sent.jpg" went out as file1 of the first request, but the crash
+    // happened before its result was saved.
+    let mut done = UploadSessionFile::new(dir.join("done.jpg"));
+    done.sent_as = Some((0, "file0".to_string()));
+    done.image_id = Some(1);
+    let mut sent = UploadSessionFile::new(dir.join("sent.jpg"));
+    sent.sent_as = Some((0, "file1".to_string()));
+    let fresh = UploadSessionFile::new(dir.join("new.jpg")).content_type("image/jpeg");
+
+    let mut session = UploadSession::new("tok", [done, sent, fresh]);
+    session.batches_sent = 1;
+    let saved = serde_json::to_string(&session).unwrap();
+    let mut session: UploadSession = serde_json::from_str(&saved).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/upload/image/status.json"))
+        .and(query_param("upload_token", "tok"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "uploads": [
+                {"file0": {"image_id": 1}, "file1": {"image_id": 2}}
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/upload/image.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "uploads": [
+                {"file0": {"image_id": 3}}
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let mut checkpoints = 0;
+    session
+        .resume_with(&client, |_| {
+            checkpoints += 1;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    assert!(session.is_complete());
+    assert_eq!(session.image_ids(), vec![1, 2, 3]);
+    assert_eq!(session.files[2].sent_as, Some((1, "file0".to_string())));
+    assert_eq!(checkpoints, 3);
+
+    let requests = server.received_requests().await.unwrap();
+    let upload = requests
+        .iter()
+        .find(|r| r.method.as_str() == "POST")
+        .unwrap();
+    let body = String::from_utf8_lossy(&upload.body);
+    assert!(body.contains("new.jpg"));
+    assert!(!body.contains("sent.jpg"));
+    assert!(!body.contains("done.jpg"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,51 @@
+//! Integration tests for the volumes API.
+
+mod common;
+
+use ravelry::types::Money;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_show_multi_pattern_ebook() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/volumes/321.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "volume": {
+                "id": 321,
+                "title": "Winter Accessories",
+                "author_name": "Jane Designer",
+                "price": { "amount": 18.0, "currency": "USD" },
+                "patterns": [
+                    { "id": 11, "name": "Snowdrift Hat", "permalink": "snowdrift-hat" },
+                    { "id": 12, "name": "Snowdrift Mitts", "permalink": "snowdrift-mitts" },
+                    { "id": 13, "name": "Snowdrift Cowl", "permalink": "snowdrift-cowl" }
+                ],
+                "volume_type": "ebook"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let volume = client.volumes().show(321).await.unwrap().volume;
+
+    assert_eq!(volume.title.as_deref(), Some("Winter Accessories"));
+    assert_eq!(volume.author_name.as_deref(), Some("Jane Designer"));
+    assert_eq!(
+        volume.price,
+        Some(Money {
+            amount: 18.0,
+            currency: Some("USD".to_string())
+        })
+    );
+    let names: Vec<_> = volume.patterns.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["Snowdrift Hat", "Snowdrift Mitts", "Snowdrift Cowl"]
+    );
+    assert_eq!(volume.extra["volume_type"], "ebook");
+}
@@ -0,0 +1,178 @@
+//! Integration tests for the yarns API.
+
+mod common;
+
+use ravelry::api::yarns::{YarnSearchParams, YarnShowParams};
+use ravelry::types::{Money, YarnSort, YarnWeight};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn test_show_yarn_with_availability() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/yarns/2.json"))
+        .and(query_param("include", "availability"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "yarn": {
+                "id": 2,
+                "name": "Merino Worsted",
+                "permalink": "merino-worsted",
+                "yarn_weight_name": "Worsted",
+                "availability": [
+                    {
+                        "shop_name": "Local Yarn Store",
+                        "url": "https://example.com/merino-worsted",
+                        "in_stock": true,
+                        "price": { "amount": 12.5, "currency": "USD" }
+                    },
+                    { "shop_name": "Online Only", "in_stock": false }
+                ]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = YarnShowParams::new().include("availability");
+    let yarn = client.yarns().show(2, &params).await.unwrap().yarn;
+
+    assert_eq!(yarn.weight(), Some(YarnWeight::Worsted));
+
+    let availability = yarn.availability.expect("availability included");
+    assert_eq!(availability.len(), 2);
+    assert_eq!(
+        availability[0].shop_name.as_deref(),
+        Some("Local Yarn Store")
+    );
+    assert_eq!(availability[0].in_stock, Some(true));
+    assert_eq!(
+        availability[0].price,
+        Some(Money {
+            amount: 12.5,
+            currency: Some("USD".to_string())
+        })
+    );
+    assert_eq!(availability[1].in_stock, Some(false));
+    assert!(availability[1].price.is_none());
+}
+
+#[tokio::test]
+async fn test_show_yarn_with_typed_includes() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/yarns/3.json"))
+        .and(query_param("include", "colorways availability stash"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "yarn": {
+                "id": 3,
+                "name": "Sock Yarn",
+                "permalink": "sock-yarn",
+                "colorways": [
+                    {
+                        "id": 10,
+                        "name": "Storm",
+                        "photos": [{ "id": 5, "small_url": "https://example.com/storm.jpg" }]
+                    },
+                    { "id": 11, "name": "Meadow" }
+                ],
+                "availability": []
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    // Repeating a part doesn't add it twice.
+    let params = YarnShowParams::new()
+        .colorways()
+        .availability()
+        .colorways()
+        .stash();
+    let yarn = client.yarns().show(3, &params).await.unwrap().yarn;
+
+    let colorways = yarn.colorways.expect("colorways included");
+    assert_eq!(colorways.len(), 2);
+    assert_eq!(colorways[0].name.as_deref(), Some("Storm"));
+    assert_eq!(colorways[0].photos.as_ref().map(Vec::len), Some(1));
+    assert!(colorways[1].photos.is_none());
+    assert!(!yarn.extra.contains_key("colorways"));
+}
+
+#[tokio::test]
+async fn test_search_with_typed_sort() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/yarns/search.json"))
+        .and(query_param("sort", "rating"))
+        .and(query_param("query", "merino"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "yarns": [],
+            "paginator": {
+                "page_count": 0,
+                "page": 1,
+                "page_size": 20,
+                "results": 0,
+                "last_page": 0
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let params = YarnSearchParams::new()
+        .query("merino")
+        .sort_by(YarnSort::Rating);
+    client.yarns().search(&params).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_yarn_shops_from_availability() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/yarns/4.json"))
+        .and(query_param("include", "availability"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "yarn": {
+                "id": 4,
+                "name": "Sport Merino",
+                "permalink": "sport-merino",
+                "availability": [
+                    {
+                        "shop_name": "Knit Shop",
+                        "in_stock": true,
+                        "shop": {
+                            "id": 77,
+                            "name": "Knit Shop",
+                            "permalink": "knit-shop",
+                            "url": "https://knitshop.example.com",
+                            "location": "Portland, OR"
+                        }
+                    },
+                    { "shop_name": "Online Only", "url": "https://online.example.com" },
+                    { "in_stock": false }
+                ]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = common::test_client(&server);
+    let shops = client.yarns().shops(4).await.unwrap();
+
+    assert_eq!(shops.len(), 2);
+    assert_eq!(shops[0].id, Some(77));
+    assert_eq!(shops[0].permalink.as_deref(), Some("knit-shop"));
+    assert_eq!(shops[0].location.as_deref(), Some("Portland, OR"));
+    assert_eq!(shops[1].name.as_deref(), Some("Online Only"));
+    assert_eq!(shops[1].url.as_deref(), Some("https://online.example.com"));
+    assert!(shops[1].location.is_none());
+}